@@ -7,7 +7,7 @@ use futures_signals_component_macro::component;
 #[component(render_fn = cool_button)]
 struct CoolButton<FOnClick: (FnMut() -> ()) + Send + Sync = fn() -> ()> {
     /// A label field which can be used as either a signal or a constant value
-    /// This will cause the cool_button! macro to have both .label() and .label_signal() methods
+    /// This will cause the cool_button! macro to have .label(), .label_signal() and .label_mutable() methods
     /// for providing a label value
     #[signal]
     #[send] // We need to explicitly declare the label signal as Send, since we don't know that String is send compile time when generating the code
@@ -72,7 +72,7 @@ fn ui_root(world: &mut World) {
         .on_click(clone!((label) move || {
             label.set("Yay, I am clicked!".to_string())
         }))
-        .label_signal(label.signal_cloned())
+        .label_mutable(&label)
     });
 
     let inert_button = cool_button!({
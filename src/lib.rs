@@ -1,76 +1,2069 @@
 mod parse;
 mod render;
 
+use crate::parse::parse_events::expand_events;
 use crate::parse::parse_field::parse_field;
 use crate::parse::AttributeArgument;
-use crate::parse::{Component, PropGenerics};
+use crate::parse::{Component, ComponentBase, PropGenerics};
 use crate::render::render_props;
+use convert_case::{Case, Casing};
 use proc_macro::TokenStream;
-use syn::{GenericArgument, Meta, PathArguments, Type};
+use proc_macro2::Ident;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{GenericArgument, Meta, PathArguments, Token, Type};
 
 /// This attribute macro is meant to simplify making components using `futures-signals` for their properties.
 /// It lets you declare your components inputs in form of a normal, attribute annotated rust struct.
 ///
 /// It generates a component macro, a props struct and the prop structs builder + trait for the annotated struct.
+/// Like the impls of derive macros, all generated impls are marked `#[automatically_derived]`, so coverage tools and lints can skip them.
 ///
 /// ## Field attributes
-/// The fields of the component struct can be annotated with the following attributes:
+/// The fields of the component struct can be annotated with the following attributes.
+/// Apart from `#[signal]` and `#[signal_vec]`, whose options are combined, each can be given once per field, and conflicting attributes, such as `#[signal]` with `#[signal_vec]`, or `#[skip]` with an attribute configuring the setters, are rejected with an error pointing at the attribute:
 ///
 /// ### `#[signal]`
 /// Fields annotated with this attribute will have to setter functions created on the builder: `field_name()` and `field_name_signal()`.
 ///
+/// Unless the field has a generic type, a `field_name_from(source, f)` setter is also generated, which sets the field to the signal `source.map(f)`.
+/// It is left out when another field has a setter of the same name, i.e. a `field_name_from` field.
+/// This binds a field to a part of a larger state signal inline in the component macro:
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::{Mutable, Signal};
+/// #[derive(Clone)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// #[component(render_fn = my_greeting_cmp)]
+/// struct MyGreetingCmp {
+///     #[signal]
+///     #[default("".to_string())]
+///     name: String,
+/// }
+///
+/// fn my_greeting_cmp(props: impl MyGreetingCmpPropsTrait) -> impl Signal<Item = String> {
+///     props.take().name
+/// }
+///
+/// let user = Mutable::new(User { name: "alice".to_string() });
+/// let greeting = my_greeting_cmp!({ .name_from(user.signal_cloned(), |user| user.name) });
+/// ```
+///
+/// A `field_name_mutable(&mutable)` setter is generated for these fields as well, setting the field to `mutable.signal_cloned()`.
+/// Like `field_name_from`, it is left out when another field has a setter of the same name.
+/// It is usable for `Clone` field types only, which cover most state kept in a `Mutable`, while fields of other types keep the setter without being able to call it:
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::{Mutable, Signal};
+/// #[component(render_fn = my_title_cmp)]
+/// struct MyTitleCmp {
+///     #[signal]
+///     #[default("".to_string())]
+///     title: String,
+/// }
+///
+/// fn my_title_cmp(props: impl MyTitleCmpPropsTrait) -> impl Signal<Item = String> {
+///     props.take().title
+/// }
+///
+/// let title = Mutable::new("hello".to_string());
+/// let title_signal = my_title_cmp!({ .title_mutable(&title) });
+/// ```
+///
 /// ### `#[signal_vec]`
 /// This behaves much like the `#[signal]` attribute, but will make the field a `SignalVec` rather than a `Signal`
 ///
-/// ### `#[default({expr})]`
-/// Lets you chose a default value for the field, in terms of an expression.
-/// This means you can use both literals and more complex blocks to choose a default value:
+/// ### `#[signal_ref]`
+/// Makes the field a signal like `#[signal]`, for large values the render function only needs to borrow.
+/// The signal setter is named `{field_name}_signal_ref()`, and the signal is stored in a `futures_signals::signal::Broadcaster` rather than as it is.
+/// The render function then reads the values by reference with `signal_ref(|value| ...)`, so neither the value nor signals mapped with `map_ref!` have to be cloned.
+/// It can be read several times with `signal_ref()`, `signal()` or `signal_cloned()` as well.
+/// `#[signal_ref]` fields can not be used with `dyn_trait`.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::{Mutable, Signal, SignalExt};
+/// #[component(render_fn = my_list_summary)]
+/// struct MyListSummary {
+///     #[signal_ref]
+///     #[default(Vec::new())]
+///     items: Vec<String>,
+/// }
+///
+/// fn my_list_summary(props: impl MyListSummaryPropsTrait) -> impl Signal<Item = usize> {
+///     let MyListSummaryProps { items, .. } = props.take();
+///     items.signal_ref(|items| items.len())
+/// }
+///
+/// let items = Mutable::new(vec!["a".to_string()]);
+/// let _len = my_list_summary!({ .items_signal_ref(items.signal_cloned()) });
+/// ```
+///
+/// ### `#[signal(as_stream)]`
+/// Converts the received signal into a `futures::Stream` (with `.to_stream()`) as it is set, so the render function receives a `SignalStream` from `take()` instead of a signal.
+/// This also works for `#[signal_vec(as_stream)]`, which yields a `SignalVecStream` of `VecDiff`s.
+///
+/// ### `#[cow]`
+/// Stores a `String` field as a `Cow<'static, str>`, and makes its setter accept `impl Into<Cow<'static, str>>`.
+/// This lets static strings be passed without allocating, and `take()` hands the `Cow` to the render function.
+/// Works for both plain and `#[signal]` fields (the signal setter then expects a signal of `Cow<'static, str>`).
+///
+/// ### `#[into]`
+/// Makes the setter of a plain field accept `impl Into<T>`, so i.e. a `String` field can be set with `.label("hi")`.
+/// The `#[default]` of the field is used as written.
+/// It has no effect on `#[signal]` fields, as the items of the signal passed to their setter are not converted.
+/// On `#[signal_vec]` fields, the value setter accepts `impl IntoIterator<Item = impl Into<T>>` rather than `impl Into<Vec<T>>`, converting each item, while the `_signal_vec` setter is unchanged.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use std::path::PathBuf;
+/// #[component(render_fn = my_file_cmp)]
+/// struct MyFileCmp {
+///     #[into]
+///     label: String,
+///     #[into]
+///     #[default(PathBuf::from("/tmp"))]
+///     path: PathBuf,
+/// }
+///
+/// fn my_file_cmp(props: impl MyFileCmpPropsTrait) -> (Option<String>, PathBuf) {
+///     let MyFileCmpProps { label, path, .. } = props.take();
+///     (label, path)
+/// }
+///
+/// assert_eq!(my_file_cmp!({.label("hi").path("/etc")}), (Some("hi".to_string()), PathBuf::from("/etc")));
+/// ```
+///
+/// ### `#[to_owned]`
+/// Makes the setter of a plain field accept `impl AsRef<B>`, where `B` is the borrowed form of the field type, and store `B::to_owned()` of the value.
+/// `B` is known for `String`, `PathBuf`, `OsString`, `CString` and `Vec<T>` fields, and is passed explicitly for other types, i.e. `#[to_owned(MyBorrowed)]`.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use std::path::{Path, PathBuf};
+/// #[component(render_fn = my_path_cmp)]
+/// struct MyPathCmp {
+///     #[to_owned]
+///     #[default(PathBuf::from("/tmp"))]
+///     path: PathBuf,
+///     #[to_owned(str)]
+///     #[default(String::new())]
+///     name: String,
+/// }
+///
+/// fn my_path_cmp(props: impl MyPathCmpPropsTrait) -> (PathBuf, String) {
+///     let MyPathCmpProps { path, name, .. } = props.take();
+///     (path, name)
+/// }
+///
+/// assert_eq!(my_path_cmp!({.path("/etc").name("etc")}), (PathBuf::from("/etc"), "etc".to_string()));
+/// assert_eq!(my_path_cmp!({.path(Path::new("/usr"))}).0, PathBuf::from("/usr"));
+/// ```
+///
+/// ### `#[rename = "name"]`
+/// Names the setters of a field, including its `_signal`, `_signal_vec`, `_from` and `_ms` setters, after `name` rather than the field, i.e. for fields named to avoid a keyword.
+/// The field keeps its name on the props struct.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_typed_cmp)]
+/// struct MyTypedCmp {
+///     #[rename = "ty"]
+///     #[signal]
+///     #[default("text".to_string())]
+///     type_: String,
+/// }
+///
+/// fn my_typed_cmp(props: impl MyTypedCmpPropsTrait) -> impl futures_signals::signal::Signal<Item = String> {
+///     props.take().type_
+/// }
+///
+/// let _ = my_typed_cmp!({.ty("number".to_string())});
+/// ```
+///
+/// ### `#[signal(owned)]`
+/// Adds a `'static` bound to the signal of the field, so it can be retained beyond the current frame without borrowing anything.
+/// For generic fields, values passed to the value setter must then be `'static` as well.
+/// This also works for `#[signal_vec(owned)]`, and can be combined with other options, i.e. `#[signal(owned, as_stream)]`.
+///
+/// ### `#[send]` and `#[sync]`
+/// Add a `Send` or a `Sync` bound to the signal of a `#[signal]` or `#[signal_vec]` field, for renderers which move or share signals between threads.
+/// Together, `#[send] #[sync]` bound the signal by `Send + Sync`.
+/// `#[sync]` fields can not be used with `dyn_trait`, as boxed signals are not `Sync`.
+/// For fields with a generic type, the generic param must be bound by `Send` or `Sync` as well, which is checked by the macro, and the same goes for the signal fields of `thread_safe` components.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::Mutable;
+/// #[component(render_fn = my_shared_cmp)]
+/// struct MySharedCmp {
+///     #[signal]
+///     #[send]
+///     #[sync]
+///     #[default(0)]
+///     count: i32,
+/// }
+///
+/// fn my_shared_cmp(props: impl MySharedCmpPropsTrait) {
+///     fn shared<T: Send + Sync>(_: &T) {}
+///
+///     shared(&props.take().count);
+/// }
+///
+/// let count = Mutable::new(1);
+/// my_shared_cmp!({.count_signal(count.signal())});
+/// ```
+///
+/// ### `#[signal(bounds = "...")]`
+/// Adds arbitrary bounds to the signal of a `#[signal]` or `#[signal_vec]` field, i.e. `#[signal(bounds = "Unpin + MyMarker")]`, for when the render fn needs more of the signal than `owned`, `#[send]` and `#[sync]` provide.
+/// The bounds apply to the signal setter and the stored signal alike, and the signal of the default of the field must satisfy them too.
+/// Fields with bounds can not be used with `dyn_trait`, as boxed signals only have the bounds of trait objects.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::{Mutable, Signal, SignalExt};
+/// # use std::task::{Context, Poll};
+/// #[component(render_fn = my_polled_cmp)]
+/// struct MyPolledCmp {
+///     #[signal(bounds = "Unpin")]
+///     #[default(0)]
+///     count: i32,
+/// }
+///
+/// fn my_polled_cmp(props: impl MyPolledCmpPropsTrait) -> Poll<Option<i32>> {
+///     let mut count = props.take().count;
+///     let mut cx = Context::from_waker(std::task::Waker::noop());
+///
+///     // polling without pinning requires the signal to be `Unpin`
+///     count.poll_change_unpin(&mut cx)
+/// }
+///
+/// let count = Mutable::new(1);
+/// assert_eq!(my_polled_cmp!({.count_signal(count.signal())}), Poll::Ready(Some(1)));
+/// ```
+///
+/// ### `#[signal(default_none)]`
+/// Like other fields without a `#[default]`, the signal of a `#[signal]` field of an `Option<T>` type is optional, so the render function receives an `Option` of a signal of `Option<T>`.
+/// The `default_none` option defaults it to a signal of `None` instead, so the render function receives a signal of `Option<T>`.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::{Signal, SignalExt};
+/// # use futures_util::FutureExt;
+/// #[component(render_fn = my_hint_cmp)]
+/// struct MyHintCmp {
+///     #[signal(default_none)]
+///     hint: Option<String>,
+///     #[signal]
+///     title: Option<String>,
+/// }
+///
+/// fn my_hint_cmp(props: impl MyHintCmpPropsTrait) -> (Option<String>, bool) {
+///     let props = props.take();
+///     let hint = props.hint.first().to_future().now_or_never().unwrap();
+///
+///     (hint, props.title.is_some())
+/// }
+///
+/// assert_eq!(my_hint_cmp!({}), (None, false));
+/// assert_eq!(my_hint_cmp!({.hint(Some("hi".to_string()))}), (Some("hi".to_string()), false));
+/// ```
+///
+/// ### `#[signal(fallback = expr)]`
+/// Makes the signal setter accept a signal of `Option<T>`, such as the one `from_future()` returns for an async-loaded value.
+/// While the signal is `None`, i.e. before it has produced a value, the fallback value is used in its place, so the render function always receives a signal of `T`.
+/// The value setter still accepts a plain `T`, and fields without a `#[default]` default to the fallback value.
+/// Fallback fields can not have a generic type, and can not be used with `dyn_trait`.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::{from_future, Signal};
+/// #[component(render_fn = my_user_cmp)]
+/// struct MyUserCmp {
+///     #[signal(fallback = "loading...".to_string())]
+///     user_name: String,
+/// }
+///
+/// fn my_user_cmp(props: impl MyUserCmpPropsTrait) -> impl Signal<Item = String> {
+///     props.take().user_name
+/// }
+///
+/// let user_name = my_user_cmp(MyUserCmpProps::new().user_name_signal(from_future(Box::pin(async { "alice".to_string() }))));
+/// ```
+///
+/// ### `#[signal(map = |v: In| expr)]`
+/// Maps the signals and values passed to the setters of the field with a closure, so the transformation is written once rather than at every call site.
+/// The setters accept signals and values of the type of the closure argument, which must be spelled out, while the render function receives a signal of the field type.
+/// The default of the field is of the argument type too, and is mapped like any other value.
+/// The closure must not capture anything, and fields with a `map` can not have a generic type, and can not be used with `dyn_trait`.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::{Mutable, Signal, SignalExt};
+/// # use futures_util::FutureExt;
+/// #[component(render_fn = my_toggle_cmp)]
+/// struct MyToggleCmp {
+///     #[signal(map = |on: bool| if on { "on".to_string() } else { "off".to_string() })]
+///     #[default(false)]
+///     state: String,
+/// }
+///
+/// fn my_toggle_cmp(props: impl MyToggleCmpPropsTrait) -> String {
+///     props.take().state.first().to_future().now_or_never().unwrap()
+/// }
+///
+/// let enabled = Mutable::new(true);
+/// assert_eq!(my_toggle_cmp!({}), "off");
+/// assert_eq!(my_toggle_cmp!({.state_signal(enabled.signal())}), "on");
+/// ```
+///
+/// ### `#[signal(shared)]`
+/// For fields of a reference counted `Arc<T>` or `Rc<T>` type, which are cheap to clone for every item of the signal.
+/// The value setter accepts anything convertible into the pointer, so `.data(t)` takes a plain `T` and wraps it in a new `Arc`, while `.data_signal(s)` still takes a signal of `Arc<T>`.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::{always, Signal, SignalExt};
+/// # use futures_util::FutureExt;
+/// # use std::sync::Arc;
+/// #[component(render_fn = my_doc_cmp)]
+/// struct MyDocCmp {
+///     #[signal(shared)]
+///     #[default(Arc::new(String::new()))]
+///     text: Arc<String>,
+/// }
+///
+/// fn my_doc_cmp(props: impl MyDocCmpPropsTrait) -> Arc<String> {
+///     props.take().text.first().to_future().now_or_never().unwrap()
+/// }
+///
+/// assert_eq!(*my_doc_cmp!({.text("hi".to_string())}), "hi");
+/// assert_eq!(*my_doc_cmp!({.text_signal(always(Arc::new("hey".to_string())))}), "hey");
+/// ```
+///
+/// ### `#[signal(on_change = callback)]`
+/// Calls `callback` with a reference to every value of the signal, i.e. for analytics, or for syncing derived state.
+/// The callback runs as the signal is polled by the render function, so it needs no spawner, and sees the same values the component does.
+/// It must not capture anything, as it is stored as a `fn` pointer, so it is usually the path of a function taking `&T`, or a deref target of it such as `&str`.
+/// This only works for `#[signal]` fields without a generic type, and can not be used with `dyn_trait`.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::Signal;
+/// fn log_label(label: &str) {
+///     println!("label changed to {label}");
+/// }
+///
+/// #[component(render_fn = my_logged_cmp)]
+/// struct MyLoggedCmp {
+///     #[signal(on_change = log_label)]
+///     #[default("label".to_string())]
+///     label: String,
+/// }
+///
+/// fn my_logged_cmp(props: impl MyLoggedCmpPropsTrait) -> impl Signal<Item = String> {
+///     props.take().label
+/// }
+/// ```
+///
+/// ### `#[deprecated]`
+/// The standard `#[deprecated]` attribute of a field, with or without a note, is put on all of its setters, i.e. both `label()` and `label_signal()`.
+/// Callers setting the field get the usual deprecation warning, while the field keeps working and is still handed to the render function.
+///
+/// ### `#[deprecated_runtime = "message"]`
+/// Requires the `log` feature.
+/// Logs a warning with `log::warn!` the first time a setter of the field is called, i.e. `MyComponentProps::label_signal is deprecated: message`.
+/// The setters keep working as usual, so the field can be phased out over a migration window, while finding callers that compile time deprecation warnings miss.
+///
+/// The calling crate must depend on `log`.
+///
+/// ### `#[intern]`
+/// Requires the `intern` feature.
+/// Stores a `String` field as an `Arc<str>` from a string pool of the component, so props with equal strings share a single allocation.
+/// This reduces the memory used by large lists of similar components, such as ones with repeated labels.
+/// The setter accepts `impl Into<String>`, and `take()` hands the interned `Arc<str>` to the render function.
+/// Interned strings are kept in the pool for the lifetime of the program.
+///
+/// ### `#[emits(Event)]`
+/// Declares a typed event sink, which is how a component reports events upward.
+/// The field must have the type `_`; it is stored as an `Rc<dyn Fn(Event)>`, and its setter accepts any `impl Fn(Event) + 'static`.
+/// The render function emits events by calling the sink, if one was set:
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// pub enum CounterEvent {
+///     Incremented(i32),
+/// }
+///
+/// #[component(render_fn = counter)]
+/// struct Counter {
+///     #[emits(CounterEvent)]
+///     on_event: _,
+/// }
+///
+/// fn counter(props: impl CounterPropsTrait) {
+///     let CounterProps { on_event, .. } = props.take();
+///
+///     if let Some(emit) = &on_event {
+///         emit(CounterEvent::Incremented(1));
+///     }
+/// }
+///
+/// counter(CounterProps::new().on_event(|event: CounterEvent| { /* handle the event */ }));
+/// ```
+///
+/// ### `#[events(name: Event, ..)]`
+/// Requires the `dominator` feature.
+/// Declares a family of dominator event handlers: the field, which must have the type `_`, is replaced with an `#[emits(dominator::events::Event)] on_{name}: _` field per event.
+/// Each name must be the snake case name of its event type, i.e. `click: Click` or `mouse_enter: MouseEnter`, so typos in either fail to compile.
+/// `take()` hands the handlers to the render function as `Option`s, to attach to the element of the component:
+///
+/// ```rust,ignore
+/// #[component(render_fn = clickable)]
+/// struct Clickable {
+///     #[events(click: Click, mouse_enter: MouseEnter)]
+///     events: _,
+/// }
+///
+/// fn clickable(props: impl ClickablePropsTrait) -> Dom {
+///     let ClickableProps { on_click, .. } = props.take();
+///
+///     html!("button", {
+///         .apply_if(on_click.is_some(), |b| {
+///             b.event(move |event: events::Click| on_click.as_ref().unwrap()(event))
+///         })
+///     })
+/// }
+///
+/// clickable!({ .on_click(|_: events::Click| { /* handle the click */ }) });
+/// ```
+///
+/// ### `#[try_into]`
+/// Makes the value setter of the field fallible: it accepts any `impl TryInto<T>`, and returns a `Result` with the conversion error.
+/// This is useful for components configured from untrusted input.
+/// It can be used on plain and `#[signal]` fields which don't have a generic type; the signal setter of a `#[signal]` field is unchanged.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_port_cmp)]
+/// struct MyPortCmp {
+///     #[try_into]
+///     #[default(80)]
+///     port: u16,
+/// }
+///
+/// fn my_port_cmp(props: impl MyPortCmpPropsTrait) -> u16 {
+///     props.take().port
+/// }
+///
+/// fn configure(port: i64) -> Result<u16, std::num::TryFromIntError> {
+///     Ok(my_port_cmp!({ .port(port)? }))
+/// }
+///
+/// assert_eq!(configure(8080), Ok(8080));
+/// assert!(configure(-1).is_err());
+/// ```
+///
+/// ### `#[validate(validator)]`
+/// Passes a reference to every value given to the value setter of the field to `validator`, a function returning a `Result<(), E>`.
+/// In debug builds, the setter panics with the `Debug` formatted `E` on `Err`, so invalid values are caught where they are set; release builds skip the check.
+/// It can be used on plain fields, including `#[try_into]` ones, whose value is validated after the conversion.
+///
+/// ```rust,should_panic
+/// # use futures_signals_component_macro::component;
+/// fn not_empty(label: &String) -> Result<(), &'static str> {
+///     if label.is_empty() {
+///         Err("the label can not be empty")
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// #[component(render_fn = my_label_cmp)]
+/// struct MyLabelCmp {
+///     #[validate(not_empty)]
+///     #[default("label".to_string())]
+///     label: String,
+/// }
+///
+/// fn my_label_cmp(props: impl MyLabelCmpPropsTrait) -> String {
+///     props.take().label
+/// }
+///
+/// assert_eq!(my_label_cmp!({.label("hi".to_string())}), "hi");
+/// my_label_cmp!({.label("".to_string())});
+/// ```
+///
+/// ### `#[binding]`
+/// Makes the field a two-way binding: the setter accepts a `Mutable<T>`, and `take()` hands the same `Mutable` to the render function.
+/// The render function can then both read it (i.e. with `.signal_cloned()`) and write it (with `.set()`), which is what editable components such as form inputs need.
+/// A `#[default]` is the initial value of a `Mutable` created for the field, when none is passed.
+/// Bindings can not be combined with `#[signal]`, `#[signal_vec]`, `#[cow]`, `#[try_into]` or `#[emits]`, and can not have a generic type.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::Mutable;
+/// #[component(render_fn = my_input_cmp)]
+/// struct MyInputCmp {
+///     #[binding]
+///     #[default(String::new())]
+///     value: String,
+/// }
+///
+/// fn my_input_cmp(props: impl MyInputCmpPropsTrait) {
+///     let MyInputCmpProps { value, .. } = props.take();
+///     value.set(format!("{} edited", value.get_cloned()));
+/// }
+///
+/// let value = Mutable::new("text".to_string());
+/// my_input_cmp!({ .value(value.clone()) });
+/// assert_eq!(value.get_cloned(), "text edited");
+/// ```
+///
+/// ### `#[observable]`
+/// Makes the field state of the component: the setter accepts a plain value, and `take()` hands a `Mutable<T>` created from it to the render function.
+/// The render function can then both observe the field (i.e. with `.signal_cloned()`) and update it (with `.set()`), without the caller having to create the `Mutable`.
+/// Unlike a `#[binding]`, the `Mutable` is owned by the props, so the caller does not see the updates.
+/// A `#[default]` is the initial value of the field, and fields without one are stored as an `Option<Mutable<T>>`, like other fields.
+/// Observables can not be combined with `#[signal]`, `#[signal_vec]`, `#[binding]` or the attributes converting setter values, and can not have a generic type.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::Mutable;
+/// #[component(render_fn = my_counter_cmp)]
+/// struct MyCounterCmp {
+///     #[observable]
+///     #[default(0)]
+///     count: i32,
+/// }
+///
+/// fn my_counter_cmp(props: impl MyCounterCmpPropsTrait) -> i32 {
+///     let MyCounterCmpProps { count, .. } = props.take();
+///     let count: Mutable<i32> = count;
+///
+///     count.replace_with(|count| *count + 1);
+///     count.get()
+/// }
+///
+/// assert_eq!(my_counter_cmp!({}), 1);
+/// assert_eq!(my_counter_cmp!({ .count(41) }), 42);
+/// ```
+///
+/// ### `#[slot]`
+/// Requires the `dominator` feature.
+/// Declares a named slot, which layout components use to place elements given by the caller, beyond a single list of children.
+/// A slot field of type `_` holds a `dominator::Dom`, while other element types can be given explicitly.
+/// Unlike generic fields, slots have a fixed framework element type, so a component can have many of them without adding generic params.
+/// The scaffold macro fills slots without a default with `Dom::empty()`.
+///
+/// ```rust,ignore
+/// #[component(render_fn = card)]
+/// struct Card {
+///     #[slot]
+///     header: _,
+///     #[slot]
+///     #[default(Dom::empty())]
+///     footer: _,
+/// }
+///
+/// fn card(props: impl CardPropsTrait) -> Dom {
+///     let CardProps { header, footer, .. } = props.take();
+///
+///     html!("div", {
+///         .children(header)
+///         .child(footer)
+///     })
+/// }
+///
+/// card!({ .header(html!("h1", { .text("title") })) });
+/// ```
+///
+/// ### `#[theme]`
+/// Requires the `dominator` feature.
+/// Marks a `#[signal]` field as the theme of the component, i.e. for switching between a light and a dark mode.
+/// The props struct gets an `apply_{field_name}(signal, builder)` helper, which sets the `data-theme` attribute of the element to the values of the signal, so stylesheets can style each theme with `[data-theme="dark"]` selectors.
+/// The item type of the signal must be usable as a dominator attribute value, such as `&'static str`, `String` or an `Option` of either.
+/// Theme fields must have a `#[default]`, so the element always has a theme.
+///
+/// ```rust,ignore
+/// #[component(render_fn = panel)]
+/// struct Panel {
+///     #[signal]
+///     #[theme]
+///     #[default("light")]
+///     theme: &'static str,
+/// }
+///
+/// fn panel(props: impl PanelPropsTrait) -> Dom {
+///     let PanelProps { theme, .. } = props.take();
+///
+///     html!("div", {
+///         .apply(|b| PanelProps::apply_theme(theme, b))
+///     })
+/// }
+///
+/// let dark_mode = Mutable::new(false);
+/// panel!({ .theme_signal(dark_mode.signal().map(|dark| if dark { "dark" } else { "light" })) });
+/// ```
+///
+/// ### `#[collect]`
+/// Makes the setter of a `Vec<T>` field accept any `impl IntoIterator<Item = impl Into<T>>`, which it collects into the `Vec`.
+/// This saves building the `Vec` at the call site.
+/// It can't be combined with signals, `#[try_into]`, `#[emits]` or `#[binding]`, or used on fields with a generic type.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_tags_cmp)]
+/// struct MyTagsCmp {
+///     #[collect]
+///     #[default(vec![])]
+///     tags: Vec<String>,
+/// }
+///
+/// fn my_tags_cmp(props: impl MyTagsCmpPropsTrait) -> Vec<String> {
+///     props.take().tags
+/// }
+///
+/// assert_eq!(my_tags_cmp!({.tags(["a", "b"])}), vec!["a".to_string(), "b".to_string()]);
+/// ```
+///
+/// ### `#[required]`
+/// Marks a field without a default as one the caller has to set, rather than an optional field.
+/// The props struct gets a `T{field_name}Required` generic param for it, which is `()` when created by `new()`, and becomes the field type once its setter is called.
+/// The props only implement `MyComponentPropsTrait` once every required field is set, so leaving one out fails to compile, with an error naming the required fields.
+/// Since the field is always set by the time the render fn takes the props, it is not wrapped in an `Option`:
+///
+/// ```
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_labeled_cmp)]
+/// struct MyLabeledCmp {
+///     #[required]
+///     label: String,
+///     #[default(1)]
+///     count: i32,
+/// }
+///
+/// fn my_labeled_cmp(props: impl MyLabeledCmpPropsTrait) -> String {
+///     let MyLabeledCmpProps { label, count, .. } = props.take();
+///     format!("{label} x{count}")
+/// }
+///
+/// assert_eq!(my_labeled_cmp!({.label("hi".to_string())}), "hi x1");
+/// ```
+///
+/// Leaving out `.label(...)`, i.e. with `my_labeled_cmp!({.count(2)})`, does not compile.
+/// Required fields can not be signals, bindings or of a generic type, and can not be combined with `wasm_export`, `arbitrary`, `dynamic` or `from_json`, which build the props at runtime.
+///
+/// `MyLabeledCmpProps::with(...)` creates the props with every required field set, taking them positionally in the order they are declared, i.e. `my_labeled_cmp(MyLabeledCmpProps::with("hi".to_string()))`.
+/// The other fields can be set with their setters afterwards.
+///
+/// ### `#[skip]`
+/// Leaves a field out of the builder, for internal bookkeeping the caller should not set: it gets no setters, but is still handed to the render function with its default.
+/// Skipped fields must have a `#[default]`.
+///
+/// ```
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_counted_cmp)]
+/// struct MyCountedCmp {
+///     #[skip]
+///     #[default(Vec::new())]
+///     history: Vec<i32>,
+///     #[default(1)]
+///     count: i32,
+/// }
+///
+/// fn my_counted_cmp(props: impl MyCountedCmpPropsTrait) -> usize {
+///     let MyCountedCmpProps { mut history, count, .. } = props.take();
+///     history.push(count);
+///     history.len()
+/// }
+///
+/// assert_eq!(my_counted_cmp!({.count(2)}), 1);
+/// ```
+///
+/// ### `#[duration_ms]`
+/// Adds a `{field_name}_ms(ms: u64)` setter to a `Duration` field, which stores `Duration::from_millis(ms)`, alongside its usual setter.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use std::time::Duration;
+/// #[component(render_fn = my_tooltip_cmp)]
+/// struct MyTooltipCmp {
+///     #[duration_ms]
+///     #[default(Duration::from_secs(1))]
+///     delay: Duration,
+/// }
+///
+/// fn my_tooltip_cmp(props: impl MyTooltipCmpPropsTrait) -> Duration {
+///     props.take().delay
+/// }
+///
+/// assert_eq!(my_tooltip_cmp!({.delay_ms(200)}), Duration::from_millis(200));
+/// ```
+///
+/// ### `#[default({expr})]`
+/// Lets you chose a default value for the field, in terms of an expression.
+/// This means you can use both literals and more complex blocks to choose a default value:
+///
+/// Fields that are not annotated with the `#[default]` attribute are optional, and their type is wrapped in the `Option` enum.
+///
+/// ```
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_cmp)]
+/// struct MyCmp {
+///     #[default(42)]
+///     my_int: i32,
+///     #[default({ let foo = 32; foo.to_string() })]
+///     my_string: String
+/// }
+/// ```
+///
+/// Defaults are evaluated once, when the props are created with `MyComponentProps::new()` (which the component macro does), and `take()` hands them over without evaluating anything.
+/// As `take()` returns the props as they are, defaults are not evaluated lazily; a setter replaces a default which has already been evaluated.
+/// A default that is expensive to compute can be shared between all props of the component with `#[default_once]`, or computed once in a static and borrowed through a `&'static` field:
+///
+/// ```
+/// # use futures_signals_component_macro::component;
+/// use std::sync::OnceLock;
+///
+/// static PALETTE: OnceLock<Vec<String>> = OnceLock::new();
+///
+/// #[component(render_fn = my_palette_cmp)]
+/// struct MyPaletteCmp {
+///     #[default(PALETTE.get_or_init(|| (0..16).map(|i| format!("#{i:02x}0000")).collect()))]
+///     palette: &'static [String],
+/// }
+///
+/// fn my_palette_cmp(props: impl MyPaletteCmpPropsTrait) -> usize {
+///     props.take().palette.len()
+/// }
+///
+/// assert_eq!(my_palette_cmp!({}), 16);
+/// ```
+///
+/// Defaults can name the generic params of the component, i.e. `T::default()` for a field of type `T`.
+/// They are evaluated by a function generic over the params of the component, which `new()` calls with their default types, so the expression can only use what the bounds of the params provide.
+/// `T::default()` thereby requires `T: Default`, and leaving the bound out is reported at the default expression.
+/// Defaults using generic params can not be combined with `#[default_once]`, `#[cow]` or `#[intern]`.
+///
+/// ```
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_generic_cmp)]
+/// struct MyGenericCmp<T: Default + Into<i64> = u8> {
+///     #[default(T::default())]
+///     value: T,
+///     #[default(std::mem::size_of::<T>())]
+///     size: usize,
+/// }
+///
+/// fn my_generic_cmp(props: impl MyGenericCmpPropsTrait) -> (i64, usize) {
+///     let MyGenericCmpProps { value, size, .. } = props.take();
+///     (value.into(), size)
+/// }
+///
+/// assert_eq!(my_generic_cmp!({}), (0, 1));
+/// assert_eq!(my_generic_cmp!({.value(5i32)}), (5, 1));
+/// ```
+///
+/// ### `#[default_once({expr})]`
+/// Like `#[default]`, but the expression is evaluated a single time per process, in a `OnceLock`, and cloned for every props value.
+/// This is the shorthand for sharing an expensive default, such as a parsed config, so the field type must be `Clone`, `Send` and `Sync`.
+/// It can not be used on fields with a generic type, or combined with `#[cow]`, `#[intern]`, `#[binding]` or `#[emits]`.
+///
+/// ```
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_config_cmp)]
+/// struct MyConfigCmp {
+///     #[default_once((0..16).map(|i| format!("#{i:02x}0000")).collect())]
+///     palette: Vec<String>,
+/// }
+///
+/// fn my_config_cmp(props: impl MyConfigCmpPropsTrait) -> usize {
+///     props.take().palette.len()
+/// }
+///
+/// assert_eq!(my_config_cmp!({}), 16);
+/// ```
+///
+/// ### `#[default_signal({expr})]`
+/// Gives a `#[signal]` field a default signal, rather than a default value which is wrapped in `always()`, i.e. the signal of a shared `Mutable`.
+/// As the type of the signal can't be named, it is boxed, and the default type of the signal param of the props is `Pin<Box<dyn Signal<Item = T>>>`.
+/// It can not be combined with `#[default]`, `#[default_once]` or a fallback, or used on fields with a generic type.
+///
+/// ```
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::{Mutable, Signal, SignalExt};
+/// use std::sync::OnceLock;
+///
+/// static DARK_MODE: OnceLock<Mutable<bool>> = OnceLock::new();
+///
+/// #[component(render_fn = my_themed_cmp)]
+/// struct MyThemedCmp {
+///     #[signal]
+///     #[default_signal(DARK_MODE.get_or_init(|| Mutable::new(false)).signal())]
+///     dark: bool,
+/// }
+///
+/// fn my_themed_cmp(props: impl MyThemedCmpPropsTrait) -> impl Signal<Item = &'static str> {
+///     props.take().dark.map(|dark| if dark { "dark" } else { "light" })
+/// }
+///
+/// let _follows_app = my_themed_cmp!({});
+/// let _always_dark = my_themed_cmp!({ .dark(true) });
+/// ```
+///
+/// ### `#[default_async({expr})]`
+/// Gives a `#[signal]` field with a fallback an async default, i.e. a value fetched from a server, with the fallback shown until the future resolves.
+/// Like a `#[default_signal]`, the signal of the future is boxed, and the default type of the signal param of the props is `Pin<Box<dyn Signal<Item = Option<T>>>>`.
+/// Components with async defaults get a `loading_signal()` method on the props struct, which is `true` until all async defaults have resolved.
+/// Async defaults are only polled along with their signal, and no longer count as pending once they are dropped, i.e. replaced by a setter.
+/// The props struct gets a hidden field counting the pending async defaults, so destructuring it needs a `..`.
+/// It can not be combined with `#[default]`, `#[default_once]` or `#[default_signal]`, or used on fields with a generic type.
+///
+/// ```
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::map_ref;
+/// # use futures_signals::signal::Signal;
+/// #[component(render_fn = my_profile_cmp)]
+/// struct MyProfileCmp {
+///     #[signal(fallback = "...".to_string())]
+///     #[default_async(async { "alice".to_string() })]
+///     user_name: String,
+/// }
+///
+/// fn my_profile_cmp(props: impl MyProfileCmpPropsTrait) -> impl Signal<Item = String> {
+///     let props = props.take();
+///     let loading = props.loading_signal();
+///     let MyProfileCmpProps { user_name, .. } = props;
+///
+///     map_ref! {
+///         let loading = loading,
+///         let user_name = user_name => if *loading { "loading".to_string() } else { user_name.clone() }
+///     }
+/// }
+///
+/// let _loaded_name = my_profile_cmp!({});
+/// let _given_name = my_profile_cmp!({ .user_name("bob".to_string()) });
+/// ```
+///
+/// ### `#[cfg_native(default = expr)]` and `#[cfg_wasm(default = expr)]`
+/// Gives a field a different default when compiling for `wasm32` than when compiling natively, i.e. a URL in the browser and a file path on the desktop.
+/// A target without its own attribute uses the `#[default]` of the field, which is required unless both attributes are given.
+/// They can not be combined with `#[default_once]`, `#[default_signal]` or a fallback.
+///
+/// ```
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_source_cmp)]
+/// struct MySourceCmp {
+///     #[cfg_native(default = "./assets/logo.png".to_string())]
+///     #[cfg_wasm(default = "/assets/logo.png".to_string())]
+///     source: String,
+/// }
+///
+/// fn my_source_cmp(props: impl MySourceCmpPropsTrait) -> String {
+///     props.take().source
+/// }
+///
+/// # #[cfg(not(target_arch = "wasm32"))]
+/// assert_eq!(my_source_cmp!({}), "./assets/logo.png");
+/// ```
+///
+/// If the default is a literal with an unambiguous type, the type of the field can be left out as `_`, and is inferred from the literal.
+/// Integer and float literals need a suffix, while `bool`, `char` and string literals (`&'static str`) infer their type as is:
+///
+/// ```
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::Signal;
+/// #[component(render_fn = my_inferred_cmp)]
+/// struct MyInferredCmp {
+///     #[signal]
+///     #[default(0i32)]
+///     my_int: _,
+///     #[default(true)]
+///     my_flag: _,
+/// }
+///
+/// fn my_inferred_cmp(props: impl MyInferredCmpPropsTrait) -> (impl Signal<Item = i32>, bool) {
+///     let MyInferredCmpProps { my_int, my_flag, .. } = props.take();
+///     (my_int, my_flag)
+/// }
+/// ```
+///
+/// ## Component arguments
+/// Besides the mandatory `render_fn = my_render_fn`, the `#[component]` attribute accepts the following arguments:
+///
+/// ### `async_take`
+/// Makes `take()` on the generated props trait an `async fn`, for use in async render functions and component pipelines.
+/// The props are then unpacked with `props.take().await`.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_async_cmp, async_take)]
+/// struct MyAsyncCmp {
+///     #[default(42)]
+///     my_int: i32,
+/// }
+///
+/// async fn my_async_cmp(props: impl MyAsyncCmpPropsTrait) -> i32 {
+///     let MyAsyncCmpProps { my_int, .. } = props.take().await;
+///     my_int
+/// }
+/// ```
+///
+/// ### `delta`
+/// Generates a `MyComponentPropsDelta` struct with a `bool` per non-signal field, and a `diff(&self, other: &Self)` method on the props struct which reports the fields that differ between two props values.
+/// Signal and `#[binding]` fields are left out, since they carry their own change notifications, and so are `#[slot]` fields.
+/// `diff()` is only available when every non-signal field implements `PartialEq`.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_delta_cmp, delta)]
+/// struct MyDeltaCmp {
+///     #[default(42)]
+///     my_int: i32,
+///     my_string: String,
+/// }
+/// # fn my_delta_cmp(_props: impl MyDeltaCmpPropsTrait) {}
+///
+/// let delta = MyDeltaCmpProps::new().diff(&MyDeltaCmpProps::new().my_int(666));
+/// assert!(delta.my_int && !delta.my_string);
+/// assert!(delta.has_changes());
+/// ```
+///
+/// For retained mode frameworks, `changes(&self, new: Self)` lists the fields of `new` which differ, as a `MyComponentPropsChange` variant per field holding its new value.
+/// An element rendered from the old props can then be updated in place with just the changed fields, rather than being recreated:
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_retained_cmp, delta)]
+/// struct MyRetainedCmp {
+///     #[default(42)]
+///     my_int: i32,
+///     my_string: String,
+/// }
+/// # fn my_retained_cmp(_props: impl MyRetainedCmpPropsTrait) {}
+///
+/// struct RetainedElement {
+///     text: String,
+/// }
+///
+/// let mut element = RetainedElement { text: "42".to_string() };
+/// let old = MyRetainedCmpProps::new();
+///
+/// for change in old.changes(MyRetainedCmpProps::new().my_int(666)) {
+///     match change {
+///         MyRetainedCmpPropsChange::MyInt(my_int) => element.text = my_int.to_string(),
+///         _ => unreachable!("only my_int changed"),
+///     }
+/// }
+///
+/// assert_eq!(element.text, "666");
+/// ```
+///
+/// ### `signal_suffix = "..."`
+/// Changes the suffix of the generated signal setters from the default `_signal`.
+/// `#[signal_vec]` setters use the suffix followed by `_vec`.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::always;
+/// #[component(render_fn = my_suffixed_cmp, signal_suffix = "_sig")]
+/// struct MySuffixedCmp {
+///     #[signal]
+///     my_string: String,
+///     #[signal_vec]
+///     my_ints: i32,
+/// }
+/// # fn my_suffixed_cmp(_props: impl MySuffixedCmpPropsTrait) {}
+///
+/// let _props = MySuffixedCmpProps::new()
+///     .my_string_sig(always("hi".to_string()))
+///     .my_ints_sig_vec(futures_signals::signal_vec::always(vec![42]));
+/// ```
+///
+/// ### `extends = Component` and `override(field = expr, ...)`
+/// Makes a new component reusing the generics and fields of `Component`, along with the fields of the annotated struct.
+/// Fields of the annotated struct replace same-named fields of `Component`.
+/// `override(...)` sets new defaults for any of the fields, which lets you build presets with a render function of their own.
+///
+/// This works through a hidden `__component_extend!` macro, which is only generated for components declared with the `extendable` argument.
+/// `Component` is a name rather than a path, and its `__component_extend!` macro is looked up by that name, so `Component` must be declared in the same crate, with its macros in textual scope where the extending component is declared.
+/// That is, above it in the same module, or in a parent module with `#[macro_use]` on the modules in between.
+///
+/// The extend macro calls back into `#[component]` through `::futures_signals_component_macro::component`.
+/// When this crate is available under another name, pass its path with `component_macro_path = path` next to `extends`.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_cmp, extendable)]
+/// struct MyCmp {
+///     #[default(42)]
+///     my_int: i32,
+///     my_string: String,
+/// }
+/// # fn my_cmp(_props: impl MyCmpPropsTrait) {}
+///
+/// #[component(render_fn = my_preset, extends = MyCmp, override(my_string = "preset".to_string()))]
+/// struct MyPreset {
+///     #[default(true)]
+///     my_flag: bool,
+/// }
+///
+/// fn my_preset(props: impl MyPresetPropsTrait) -> String {
+///     let MyPresetProps { my_int, my_string, my_flag, .. } = props.take();
+///     format!("{my_int} {my_string} {my_flag}")
+/// }
+///
+/// assert_eq!(my_preset!({}), "42 preset true");
+/// ```
+///
+/// ### `wasm_export = ReturnType`
+/// Requires the `wasm` feature.
+/// Generates a `#[wasm_bindgen]` factory function, `my_component_js()`, which lets JS callers build and render the component.
+/// It takes an optional argument for each field with a JS friendly type (`String`, `bool` and the numeric primitives), including the value of `#[signal]` fields.
+/// Other fields, generic fields and `#[signal_vec]` fields are left at their defaults.
+/// The output of the render function is converted into `ReturnType` with `Into`.
+///
+/// The calling crate must depend on `wasm-bindgen`.
+///
+/// ### `arbitrary`
+/// Requires the `proptest` feature.
+/// Implements `proptest::arbitrary::Arbitrary` for the props struct with its default generic params, so component configurations can be fuzzed with `any::<MyComponentProps>()`.
+/// Every plain field is generated with `any()`, so its type must implement `Arbitrary`.
+/// `#[signal]`, `#[signal_vec]`, `#[emits]`, `#[binding]`, `#[intern]`, `#[slot]`, `#[skip]` and generic fields are left at their defaults.
+/// As proptest requires the props to be `Debug`, the types of generic fields must be `Debug` as well.
+///
+/// The calling crate must depend on `proptest`.
+///
+/// ### `apply_timing = before | after`
+/// Requires the `dominator` feature, which adds an `apply` prop to every component, letting callers apply a closure to the `DomBuilder` of the component.
+/// The props struct has `MyComponentProps::apply_before(&mut apply, builder)` and `MyComponentProps::apply_after(&mut apply, builder)` helpers,
+/// which the render function calls before and after its own builder operations.
+/// Only the one matching the `apply_timing` of the component applies the closure, so the component decides whether the caller can override its attributes (`after`), or not (`before`, the default).
+///
+/// ```rust,ignore
+/// fn my_cmp(props: impl MyCmpPropsTrait + 'static) -> Dom {
+///     let MyCmpProps { mut apply, .. } = props.take();
+///
+///     html!("div", {
+///         .apply(|b| MyCmpProps::apply_before(&mut apply, b))
+///         .attr("title", "my component")
+///         .apply(|b| MyCmpProps::apply_after(&mut apply, b))
+///     })
+/// }
+/// ```
+///
+/// ### `apply = false` and `apply_element = Type`
+/// Also requires the `dominator` feature.
+/// `apply = false` leaves the `apply` prop out of the component, i.e. for components which do not render a `DomBuilder`.
+/// `apply_element = web_sys::SvgElement` makes the `apply` prop, and the `apply_before()`/`apply_after()` helpers, take a `DomBuilder` of another element type than `HtmlElement`.
+///
+/// ```rust,ignore
+/// #[component(render_fn = my_icon, apply_element = web_sys::SvgElement)]
+/// struct MyIcon {}
+///
+/// fn my_icon(props: impl MyIconPropsTrait + 'static) -> Dom {
+///     let MyIconProps { mut apply, .. } = props.take();
+///
+///     svg!("svg", {
+///         .apply(|b| MyIconProps::apply_before(&mut apply, b))
+///     })
+/// }
+/// ```
+///
+/// ### `dynamic`
+/// Generates `MyComponentProps::from_dynamic()`, which builds the props from a `HashMap<String, MyComponentDynValue>`, i.e. for components configured by a CMS.
+/// `MyComponentDynValue` has a variant per distinct type of the plain fields of the component, named after the type (`U32` for `u32`, `VecString` for `Vec<String>`), and implements `From` for each of them.
+/// Keys are matched to the fields by name, and fields missing from the map keep their defaults.
+/// Signal, `#[emits]`, `#[binding]`, `#[intern]`, `#[slot]`, `#[skip]` and generic fields can not be set this way; `from_dynamic()` returns a `MyComponentDynError` for them, as well as for unknown keys and mismatched value types.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use std::collections::HashMap;
+/// #[component(render_fn = my_dynamic_cmp, dynamic)]
+/// struct MyDynamicCmp {
+///     #[default(1)]
+///     count: u32,
+///     title: String,
+///     #[signal]
+///     label: String,
+/// }
+/// # fn my_dynamic_cmp(_props: impl MyDynamicCmpPropsTrait) {}
+///
+/// let props = MyDynamicCmpProps::from_dynamic(HashMap::from([
+///     ("count".to_string(), MyDynamicCmpDynValue::U32(2)),
+///     ("title".to_string(), "a title".to_string().into()),
+/// ]))
+/// .unwrap();
+/// assert_eq!((props.count, props.title), (2, Some("a title".to_string())));
+///
+/// let err = MyDynamicCmpProps::from_dynamic(HashMap::from([("label".to_string(), 3u32.into())]));
+/// assert!(matches!(err, Err(MyDynamicCmpDynError::UnsupportedProp(_))));
+/// ```
+///
+/// ### `from_json`
+/// Requires the `serde` feature.
+/// Generates `MyComponentProps::from_json(&str) -> Result<MyComponentProps, serde_json::Error>`, which is handy for test fixtures and previews.
+/// It starts from the defaults of `MyComponentProps::new()`, and deserializes each plain field found in the JSON object, so their types must implement `serde::de::DeserializeOwned`.
+/// `#[signal]`, `#[signal_vec]`, `#[emits]`, `#[binding]`, `#[intern]`, `#[slot]`, `#[skip]` and generic fields are left at their defaults, and unknown keys are ignored.
+///
+/// The calling crate must depend on `serde_json`.
+///
+/// ### `metadata = module`
+/// Generates a `MyComponentProps::METADATA` const describing the component for documentation tooling, such as a generated component gallery.
+/// It holds the name and docs of the component, and the name, type, signal-ness, default and docs of each field, as strings.
+/// Props the macro adds, such as the `apply` prop of the `dominator` feature, are left out, as they are the same for every component.
+///
+/// A proc macro crate can't export types, so the `ComponentMeta` and `PropInfo` types are defined by the calling crate, in the given module.
+/// This lets all the components of the crate share them, and must match the following definitions:
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// mod meta {
+///     pub struct ComponentMeta {
+///         pub name: &'static str,
+///         pub docs: &'static str,
+///         pub props: &'static [PropInfo],
+///     }
+///
+///     pub struct PropInfo {
+///         pub name: &'static str,
+///         pub type_name: &'static str,
+///         pub is_signal: bool,
+///         pub is_signal_vec: bool,
+///         pub default: Option<&'static str>,
+///         pub docs: &'static str,
+///     }
+/// #
+/// #   #[cfg(feature = "inventory")]
+/// #   inventory::collect!(ComponentMeta);
+/// }
+///
+/// /// A labelled counter
+/// #[component(render_fn = my_meta_cmp, metadata = meta)]
+/// struct MyMetaCmp {
+///     /// The label of the counter
+///     #[signal]
+///     label: String,
+///     #[default(1)]
+///     count: u32,
+/// }
+/// # fn my_meta_cmp(_props: impl MyMetaCmpPropsTrait) {}
+///
+/// let meta = MyMetaCmpProps::METADATA;
+/// assert_eq!((meta.name, meta.docs, meta.props.len()), ("MyMetaCmp", "A labelled counter", 2));
+/// assert_eq!((meta.props[0].name, meta.props[0].is_signal), ("label", true));
+/// assert_eq!((meta.props[1].type_name, meta.props[1].default), ("u32", Some("1")));
+/// ```
+///
+/// With the `inventory` feature, `METADATA` is also submitted to an `inventory` registry, so a catalog of every component can be listed with `inventory::iter::<meta::ComponentMeta>()`.
+/// The calling crate must then depend on `inventory`, and invoke `inventory::collect!(meta::ComponentMeta)`.
+///
+/// ### `max_size = bytes`
+/// Asserts at compile time that `MyComponentProps`, instantiated with its default generics, is at most `bytes` large.
+/// This guards performance sensitive components against the props struct accidentally growing as fields are added.
+/// Note that signal and generic fields are measured with their default types, so the size of a props value with other types set can differ.
+/// With the `dominator` feature, the injected `apply` prop is measured as well.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_small_cmp, max_size = 24)]
+/// struct MySmallCmp {
+///     #[default(0)]
+///     count: u64,
+///     enabled: bool,
+/// }
+/// # fn my_small_cmp(_props: impl MySmallCmpPropsTrait) {}
+/// ```
+///
+/// ### `dyn_trait`
+/// Generates the object safe `DynMyComponentPropsTrait`, so built props can be stored as `Box<dyn DynMyComponentPropsTrait>`, i.e. in heterogeneous collections.
+/// Its `take_boxed()` hands out the props with all signals boxed (`LocalBoxSignal<'static, T>`, or `BoxSignal` for `Send` signals).
+/// The trait is generic over the generic params of the component, and implemented for all props with `'static` signals.
+///
+/// `MyComponentPropsTrait` is implemented for `Box<dyn DynMyComponentPropsTrait>`, so the boxed props can be passed directly to the render function.
+/// `dyn_trait` can not be combined with `as_stream` fields.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_dyn_cmp, dyn_trait)]
+/// struct MyDynCmp {
+///     #[signal]
+///     #[default(42)]
+///     my_int: i32,
+/// }
+/// # fn my_dyn_cmp(_props: impl MyDynCmpPropsTrait) {}
+///
+/// let stored: Vec<Box<dyn DynMyDynCmpPropsTrait>> = vec![
+///     Box::new(MyDynCmpProps::new()),
+///     Box::new(MyDynCmpProps::new().my_int(666)),
+/// ];
+///
+/// for props in stored {
+///     my_dyn_cmp(props);
+/// }
+/// ```
+///
+/// ### `boxed`
+/// Generates the `DynMyComponentProps` newtype, holding the props with all signals boxed like `take_boxed()` of `dyn_trait` does.
+/// Every props value with `'static` signals converts into it with `From`, so props built in different ways share a concrete type, i.e. for a `Vec<DynMyComponentProps>`.
+/// `MyComponentPropsTrait` is implemented for it, so it can be passed directly to the render function.
+/// `boxed = true` is accepted as well, and the same restrictions as for `dyn_trait` apply.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::{Mutable, SignalExt};
+/// #[component(render_fn = my_boxed_cmp, boxed)]
+/// struct MyBoxedCmp {
+///     #[signal]
+///     #[default(0)]
+///     count: i32,
+/// }
+/// # fn my_boxed_cmp(_props: impl MyBoxedCmpPropsTrait) {}
+///
+/// let count = Mutable::new(1);
+/// let stored: Vec<DynMyBoxedCmpProps> = vec![
+///     MyBoxedCmpProps::new().into(),
+///     MyBoxedCmpProps::new().count(2).into(),
+///     MyBoxedCmpProps::new().count_signal(count.signal().map(|v| v + 1)).into(),
+/// ];
+///
+/// for props in stored {
+///     my_boxed_cmp(props);
+/// }
+/// ```
+///
+/// ### `clone_signals`
+/// Stores the signals of `#[signal]` fields in a `futures_signals::signal::Broadcaster`, and implements `Clone` for the props struct.
+/// This lets a configured props value be reused across several render sites.
+/// The render function receives the `Broadcaster`, and gets its signals with `signal()`, `signal_cloned()` or `signal_ref()`.
+///
+/// Cloning the props requires all non-signal fields, and any `#[signal_vec]` signals, to be `Clone`.
+/// Note that `futures_signals::signal_vec::always()` is not `Clone`, so components with `#[signal_vec]` fields are usually not clonable.
+/// `clone_signals` can not be combined with `dyn_trait` or `as_stream` fields.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::SignalExt;
+/// #[component(render_fn = my_clone_cmp, clone_signals)]
+/// struct MyCloneCmp {
+///     #[signal]
+///     #[default("hi".to_string())]
+///     my_string: String,
+/// }
+///
+/// fn my_clone_cmp(props: impl MyCloneCmpPropsTrait) {
+///     let MyCloneCmpProps { my_string, .. } = props.take();
+///     let _signal = my_string.signal_cloned().map(|s| s.len());
+/// }
+///
+/// let props = MyCloneCmpProps::new().my_string("hello".to_string());
+/// my_clone_cmp(props.clone());
+/// my_clone_cmp(props);
+/// ```
+///
+/// ### `with_helpers`
+/// Generates a `with_field_name(&mut self, f)` helper on the props struct for each field without a default.
+/// It takes the value out of the field and runs `f` with it only if it was set, returning the result of `f` as an `Option`.
+/// This replaces the `is_some()`/`unwrap()` pairing in render functions.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_label, with_helpers)]
+/// struct MyLabel {
+///     label: String,
+/// }
+///
+/// fn my_label(props: impl MyLabelPropsTrait) -> String {
+///     let mut props = props.take();
+///
+///     props.with_label(|label| format!("label: {label}")).unwrap_or_default()
+/// }
+///
+/// assert_eq!(my_label!({ .label("hi".to_string()) }), "label: hi");
+/// assert_eq!(my_label!({}), "");
+/// ```
+///
+/// ### `snapshot`
+/// Generates a `MyComponentPropsSnapshot` struct and an async `snapshot(self)` method on the props struct, which reads the current value of every signal into it.
+/// `#[signal]` fields hold the item of the signal, `#[signal_vec]` fields hold a `Vec` of the items (which must be `Clone`), and other fields are moved over as they are.
+/// This lets tests assert on the initial state of a component without a rendering framework.
+/// `snapshot` can not be combined with `as_stream` fields.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::always;
+/// #[component(render_fn = my_snapshot_cmp, snapshot)]
+/// struct MySnapshotCmp {
+///     #[signal]
+///     label: String,
+///     #[signal_vec]
+///     #[default(vec![1, 2])]
+///     items: i32,
+/// }
+/// # fn my_snapshot_cmp(_props: impl MySnapshotCmpPropsTrait) {}
+///
+/// async fn check() {
+///     let snapshot = MySnapshotCmpProps::new().label_signal(always("hi".to_string())).snapshot().await;
+///
+///     assert_eq!(snapshot.label, Some("hi".to_string()));
+///     assert_eq!(snapshot.items, vec![1, 2]);
+/// }
+/// ```
+///
+/// ### `impl_trait(Trait, fn method(self) -> Output)`
+/// Implements a framework trait, i.e. an `Element` or `View` trait, for the props struct.
+/// The trait must have a single method taking `self` by value, whose signature is given after the trait path.
+/// The generated method passes the props on to the `render_fn`, so the props value itself can be used wherever the framework expects the trait.
+/// The argument can be repeated to implement several traits.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// pub trait View {
+///     fn into_view(self) -> String;
+/// }
+///
+/// #[component(render_fn = my_view_cmp, impl_trait(View, fn into_view(self) -> String))]
+/// struct MyViewCmp {
+///     #[default("hello".to_string())]
+///     text: String,
+/// }
+///
+/// fn my_view_cmp(props: impl MyViewCmpPropsTrait) -> String {
+///     props.take().text
+/// }
+///
+/// fn mount(view: impl View) -> String {
+///     view.into_view()
+/// }
+///
+/// assert_eq!(mount(MyViewCmpProps::new()), "hello");
+/// ```
+///
+/// ### `macro_name = name`
+/// Names the component macro `name!`, rather than after the snake cased component name.
+/// The scaffold and prop names macros follow it, as `name_scaffold!` and `name_prop_names!`.
+/// This decouples the names of the component struct, its render fn and its macro:
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = render_cool_button, macro_name = cool_button)]
+/// struct CoolButtonComponent {
+///     #[default("click".to_string())]
+///     label: String,
+/// }
+///
+/// fn render_cool_button(props: impl CoolButtonComponentPropsTrait) -> String {
+///     props.take().label
+/// }
+///
+/// assert_eq!(cool_button!({}), "click");
+/// ```
+///
+/// ### `props_name = Name` and `trait_name = NameTrait`
+/// Names the props struct and the props trait, rather than `MyComponentProps` and `MyComponentPropsTrait`, i.e. to avoid collisions when re-exporting components of several modules.
+/// Items named after the props struct or trait follow them, such as the `NameDelta` struct of `delta` components and the `DynNameTrait` of `dyn_trait` components.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_named_cmp, props_name = NamedProps, trait_name = Named)]
+/// struct MyNamedCmp {
+///     #[default(1)]
+///     count: i32,
+/// }
+///
+/// fn my_named_cmp(props: impl Named) -> i32 {
+///     let NamedProps { count, .. } = props.take();
+///     count
+/// }
+///
+/// assert_eq!(my_named_cmp!({.count(2)}), 2);
+/// assert_eq!(my_named_cmp(NamedProps::new()), 1);
+/// ```
+///
+/// ### `macro_module = module_name`
+/// Places the component macro, along with its scaffold and prop names macros, in a generated `mod module_name`, rather than the enclosing scope.
+/// They are invoked by path, i.e. `module_name::my_component!({ ... })`.
+/// As `macro_rules!` macros can only be exported from the crate root, macros in a module are only re-exported within the crate, even for `pub` components.
+/// Every component needs a module of its own, since a module can only be declared once in the same scope.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_modular_cmp, macro_module = my_modular_macros)]
+/// struct MyModularCmp {
+///     #[default(1)]
+///     count: i32,
+/// }
+///
+/// fn my_modular_cmp(props: impl MyModularCmpPropsTrait) -> i32 {
+///     props.take().count
+/// }
+///
+/// assert_eq!(my_modular_macros::my_modular_cmp!({.count(2)}), 2);
+/// ```
+///
+/// ### `signal_or_value`
+/// Makes the value setter of `#[signal]` fields accept either a value or a signal, so `.label("hi".to_string())` and `.label(state.signal_cloned())` both work.
+/// The setter is generic over a generated `IntoSignalOrValue` trait, which is implemented for values and for signals of them.
+/// As proc macro crates can only export macros, the trait lives in a hidden module generated alongside the component.
+/// The `_signal` setters are still generated.
+/// Fields with a generic type, and fields whose value setter converts its value (`#[cow]`, `#[try_into]` and fallback fields) keep their usual value setter.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::{Mutable, Signal};
+/// #[component(render_fn = my_flexible_cmp, signal_or_value)]
+/// struct MyFlexibleCmp {
+///     #[signal]
+///     #[default("".to_string())]
+///     label: String,
+/// }
+///
+/// fn my_flexible_cmp(props: impl MyFlexibleCmpPropsTrait) -> impl Signal<Item = String> {
+///     props.take().label
+/// }
+///
+/// let state = Mutable::new("from a signal".to_string());
+///
+/// let _constant = my_flexible_cmp!({.label("constant".to_string())});
+/// let _dynamic = my_flexible_cmp!({.label(state.signal_cloned())});
+/// ```
+///
+/// ### `sealed`
+/// Seals the generated `MyComponentPropsTrait` with a supertrait in a private module, so it can only be implemented by the generated props struct.
+/// This lets methods be added to the trait without breaking implementations outside of the module of the component.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// mod my_sealed {
+/// #   use futures_signals_component_macro::component;
+///     #[component(render_fn = my_sealed_cmp, sealed)]
+///     pub struct MySealedCmp {
+///         #[default(1)]
+///         pub count: i32,
+///     }
+///
+///     pub fn my_sealed_cmp(props: impl MySealedCmpPropsTrait) -> i32 {
+///         props.take().count
+///     }
+/// }
+///
+/// assert_eq!(my_sealed::my_sealed_cmp(my_sealed::MySealedCmpProps::new()), 1);
+/// ```
+///
+/// ### `thread_safe`
+/// Makes `Send` and `Sync` supertraits of `MyComponentPropsTrait`, and bounds every signal of the component by `Send + Sync`, for thread safe component systems such as Bevy.
+/// This is the component wide form of annotating every signal field with `#[send]`, and the props of fields with a generic type must be `Send + Sync` as well.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::{Mutable, Signal};
+/// #[component(render_fn = my_threaded_cmp, thread_safe)]
+/// struct MyThreadedCmp {
+///     #[signal]
+///     #[default(0)]
+///     count: i32,
+/// }
+///
+/// fn my_threaded_cmp(props: impl MyThreadedCmpPropsTrait + 'static) -> std::thread::JoinHandle<()> {
+///     std::thread::spawn(move || {
+///         let _count = props.take().count;
+///     })
+/// }
+///
+/// let count = Mutable::new(1);
+/// my_threaded_cmp!({.count_signal(count.signal())}).join().unwrap();
+/// ```
+///
+/// `thread_safe` can not be combined with `dyn_trait`, as boxed signals are not `Sync`, or with `#[emits]` and `#[slot]` fields, which are not `Send`.
+///
+/// ### `newtype`
+/// For components consisting of a single `#[signal]` field with a default, generates the `#[repr(transparent)]` newtype `MyComponentPropsSignal` over the signal.
+/// `into_signal()` on the props struct unwraps the props into it, which is useful for building lightweight signal adapters out of components.
+/// The newtype derefs to the wrapped signal, and is itself a signal when the wrapped one is `Unpin`.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::{Mutable, SignalExt};
+/// #[component(render_fn = my_counter_cmp, newtype)]
+/// struct MyCounterCmp {
+///     #[signal]
+///     #[default(0)]
+///     count: i32,
+/// }
+///
+/// fn my_counter_cmp<T: MyCounterCmpPropsTrait>(props: T) -> MyCounterCmpPropsSignal<T::TcountSignal> {
+///     props.take().into_signal()
+/// }
+///
+/// let count = Mutable::new(2);
+/// let doubled = my_counter_cmp!({.count_signal(count.signal())}).map(|count| count * 2);
+/// # let _ = doubled;
+/// ```
+///
+/// ### `prop_mask`
+/// Tracks which fields have been set in the bitflags-style `MyComponentPropsMask`, which has an associated const with the bit of every field, named after the field in upper snake case.
+/// Every setter sets the bit of its field, and `set_mask()` on the props struct returns the mask of the fields set so far.
+/// Unlike `count_set_fields()`, this includes fields with a `#[default]`.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_masked_cmp, prop_mask)]
+/// struct MyMaskedCmp {
+///     #[default(0)]
+///     width: u32,
+///     #[default(0)]
+///     height: u32,
+/// }
+///
+/// fn my_masked_cmp(props: impl MyMaskedCmpPropsTrait) -> MyMaskedCmpPropsMask {
+///     props.take().set_mask()
+/// }
+///
+/// let mask = my_masked_cmp!({.height(10)});
+/// assert!(mask.contains(MyMaskedCmpPropsMask::HEIGHT));
+/// assert!(!mask.contains(MyMaskedCmpPropsMask::WIDTH));
+/// ```
+///
+/// Components with `prop_mask` can have at most 64 fields.
+///
+/// ### `futures_signals_path = path`, `dominator_path = path` and `web_sys_path = path`
+/// The generated code names `futures_signals`, `dominator` and `web_sys` by their crate names.
+/// When they are only available through a re-export, such as a facade crate of a design system, pass the path to use instead.
+/// The paths are used as written, both next to the component and in the modules and macros generated for it, so they should be absolute.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::{Mutable, Signal};
+/// mod my_facade {
+///     pub use futures_signals;
+/// }
+///
+/// #[component(render_fn = my_facade_cmp, futures_signals_path = crate::my_facade::futures_signals)]
+/// struct MyFacadeCmp {
+///     #[signal]
+///     #[default(0)]
+///     value: i32,
+/// }
+///
+/// fn my_facade_cmp(props: impl MyFacadeCmpPropsTrait) -> impl Signal<Item = i32> {
+///     props.take().value
+/// }
+///
+/// # fn main() {
+/// let value = Mutable::new(1);
+/// let _value = my_facade_cmp!({.value_signal(value.signal())});
+/// # }
+/// ```
+///
+/// ### `vis = visibility`
+/// The generated props struct, traits and other items take the visibility of the component struct.
+/// Pass another visibility to override it, such as exposing the props of a private component struct to the rest of the crate:
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// mod widgets {
+///     # use futures_signals_component_macro::component;
+///     #[component(render_fn = label, vis = pub(crate))]
+///     struct Label {
+///         #[default("".to_string())]
+///         text: String,
+///     }
+///
+///     pub(crate) fn label(props: impl LabelPropsTrait) -> String {
+///         props.take().text
+///     }
+/// }
+///
+/// # fn main() {
+/// let props = widgets::LabelProps::new().text("hello".to_string());
+/// assert_eq!(widgets::label(props), "hello");
+/// # }
+/// ```
+///
+/// The generated macros are `#[macro_export]`ed from the crate root only when the component has a visibility, from the struct or from `vis`.
+/// The macros of private components stay in textual scope, so they can be used below the component in the same module, or in a parent module with `#[macro_use]` on the modules in between.
+///
+/// ### `derive(Default, Clone, Debug)`
+/// `Default` is implemented for the props with their default generics, as `MyCmpProps::new()`, so tests and other generic code can create the props without naming `new()`.
+/// Every generic param of the component must have a default type.
+///
+/// `Clone` is implemented for the props when the types of their fields are `Clone`.
+/// Signals can not be cloned, so components with signal fields need `clone_signals` as well, which implements `Clone` by itself.
+///
+/// `Debug` is implemented for the props when the types of their plain fields are `Debug`, printing every field by name, including unset `#[required]` fields as `()`.
+/// Signal, `#[slot]` and `#[emits]` fields print a placeholder, i.e. `MyCmpProps { label: <signal>, count: Some(2) }`.
+/// `arbitrary` components implement `Debug` this way as well, as proptest prints the failing props.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_defaulted_cmp, derive(Default, Debug))]
+/// struct MyDefaultedCmp {
+///     #[default(3)]
+///     count: i32,
+/// }
+/// # fn my_defaulted_cmp(props: impl MyDefaultedCmpPropsTrait) -> i32 { props.take().count }
+///
+/// assert_eq!(my_defaulted_cmp(MyDefaultedCmpProps::default()), 3);
+/// assert!(format!("{:?}", MyDefaultedCmpProps::default()).starts_with("MyDefaultedCmpProps { count: 3"));
+/// ```
+///
+/// ### `must_use`
+/// Marks the component rendered by the generated component macro as `#[must_use]`, so accidentally discarding it is warned about.
+/// It is meant for render functions returning a value that does nothing until used, such as a `Dom`, rather than ones returning `()`.
+///
+/// ## The `render_fn`
+///
+/// Your `render_fn` can not know the concrete type of your props struct, as it is heavily generic, and changes based on properties the user of the builder chose.
+///
+/// It should always accept and argument that implements the generated `MyComponentPropsTrait` trait.
+/// This impl in turn has a `take()` method, which will consume the props struct and output Self, which will give us access to its member values (these match our fields in the original struct by identifier).
+///
+/// We can then destruct the Self into variables:
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn=take_destruct_example)]
+/// struct TakeDestructExample {
+///     #[signal]
+///     optional_string_signal: String,
+///
+///     #[signal]
+///     #[default("hi".to_string())]
+///     string_signal: String,
+/// }
+///
+/// fn take_destruct_example(props: impl TakeDestructExamplePropsTrait + 'static) {
+///     let TakeDestructExampleProps {
+///         optional_string_signal /* this has the type Option<impl Signal<Item=String>> */,
+///         string_signal /* this has the type impl Signal<Item=String> */
+/// # , ..
+/// } = props.take();
+/// }
+/// ```
+///
+/// The `render_fn` can also be a path, such as an associated function, which keeps the render logic namespaced with the component.
+/// The annotated struct itself is replaced by the generated items, so the props struct is a natural home for it:
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = MyNamespacedCmpProps::render)]
+/// struct MyNamespacedCmp {
+///     #[default(1)]
+///     count: i32,
+/// }
+///
+/// impl MyNamespacedCmpProps {
+///     fn render(props: impl MyNamespacedCmpPropsTrait) -> i32 {
+///         props.take().count
+///     }
+/// }
+///
+/// assert_eq!(my_namespaced_cmp!({.count(2)}), 2);
+/// ```
+///
+/// The return type of your render_fn should be the component type your rendering library expects.
+/// In the examples we use the DOMINATOR dom node, but you can use the `#[component]` macro to produce components for any library working with `futures-signals`.
+///
+/// Alongside the component macro, a `{component}_scaffold!()` macro is generated.
+/// It expands to a minimal component macro invocation which sets every prop without a default to a placeholder (`Default::default()`, or an empty `Vec` for `#[signal_vec]` props).
+/// Props whose type is a generic param are left out, since no placeholder type can be inferred for them.
+/// The expanded invocation is also listed in the docs of the scaffold macro, so it can be copied as a starting point.
+///
+/// A `{component}_prop_names!` macro is generated as well, listing the names of the props of the component.
+/// `my_component_prop_names!()` expands to an array of the names, while `my_component_prop_names!(callback)` invokes `callback!` with the names as idents.
+/// This helps macros built on top of a component validate field references at compile time:
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_named_cmp)]
+/// struct MyNamedCmp {
+///     #[signal]
+///     label: String,
+///     #[default(1)]
+///     count: i32,
+/// }
+/// # fn my_named_cmp(_props: impl MyNamedCmpPropsTrait) {}
+///
+/// macro_rules! count_props {
+///     ($($name:ident),*) => { [$(stringify!($name)),*].len() };
+/// }
+///
+/// assert_eq!(my_named_cmp_prop_names!()[..2], ["label", "count"]);
+/// assert!(my_named_cmp_prop_names!(count_props) >= 2);
+/// ```
+///
+/// The component macro can also render the props with another function than the `render_fn`, which lets a component be rendered differently without duplicating its props:
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_text_cmp)]
+/// struct MyTextCmp {
+///     #[default("text".to_string())]
+///     text: String,
+/// }
+///
+/// fn my_text_cmp(props: impl MyTextCmpPropsTrait) -> String {
+///     props.take().text
+/// }
+///
+/// fn my_shouting_text_cmp(props: impl MyTextCmpPropsTrait) -> String {
+///     props.take().text.to_uppercase()
+/// }
+///
+/// assert_eq!(my_text_cmp!(render = my_shouting_text_cmp, {.text("hi".to_string())}), "HI");
+/// ```
+///
+/// Given props followed by `=>`, the component macro continues from them rather than from the default props, i.e. to share a preset between invocations.
+/// With the `dominator` feature, `my_cmp!(apply = closure, { ... })` sets the `apply` prop up front, which lets macros wrapping the component macro thread their own closure through.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_preset_text_cmp)]
+/// struct MyPresetTextCmp {
+///     #[default("text".to_string())]
+///     text: String,
+///     #[default(1)]
+///     repeat: usize,
+/// }
+///
+/// fn my_preset_text_cmp(props: impl MyPresetTextCmpPropsTrait) -> String {
+///     let props = props.take();
+///     props.text.repeat(props.repeat)
+/// }
+///
+/// let preset = || MyPresetTextCmpProps::new().repeat(2);
+/// assert_eq!(my_preset_text_cmp!(preset() => {.text("hi".to_string())}), "hihi");
+/// ```
+///
+/// For components rendered differently on the server than on the client, i.e. for server side rendering with hydration, an alternate render fn can be given with `ssr_render_fn = my_server_render_fn`.
+/// The component macro then calls it instead of the `render_fn` when the crate invoking the macro has its `ssr` feature enabled, so the same `my_cmp!({ ... })` call compiles to either function.
+/// Only the render fn of the enabled configuration needs to exist, so the other one can be gated behind the same `#[cfg]`.
+/// The calling crate should declare an `ssr` feature, as the `unexpected_cfgs` lint warns about the generated `#[cfg(feature = "ssr")]` otherwise.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_client_cmp, ssr_render_fn = my_server_cmp)]
+/// struct MyHydratedCmp {
+///     #[default("text".to_string())]
+///     text: String,
+/// }
+///
+/// fn my_client_cmp(props: impl MyHydratedCmpPropsTrait) -> String {
+///     props.take().text
+/// }
+///
+/// #[cfg(feature = "ssr")]
+/// fn my_server_cmp(props: impl MyHydratedCmpPropsTrait) -> String {
+///     format!("<span>{}</span>", props.take().text)
+/// }
+///
+/// // this doctest is built without an `ssr` feature
+/// assert_eq!(my_hydrated_cmp!({.text("hi".to_string())}), "hi");
+/// ```
+///
+/// A component rendered in several ways can list its render fns, i.e. `render_fn = [my_compact_cmp, my_full_cmp]`, sharing a single props struct and trait.
+/// Each render fn gets a macro named after it, while the component macro calls the first one.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = [my_compact_cmp, my_full_cmp])]
+/// struct MyVariantCmp {
+///     #[default("text".to_string())]
+///     text: String,
+/// }
+///
+/// fn my_compact_cmp(props: impl MyVariantCmpPropsTrait) -> String {
+///     props.take().text.chars().take(2).collect()
+/// }
 ///
-/// Fields that are not annotated with the `#[default]` attribute are optional, and their type is wrapped in the `Option` enum.
+/// fn my_full_cmp(props: impl MyVariantCmpPropsTrait) -> String {
+///     props.take().text
+/// }
 ///
+/// assert_eq!(my_compact_cmp!({.text("hello".to_string())}), "he");
+/// assert_eq!(my_full_cmp!({.text("hello".to_string())}), "hello");
+/// assert_eq!(my_variant_cmp!({}), "te");
 /// ```
+///
+/// A free function named after the component, i.e. `my_cmp_props()`, creates the default props like `MyCmpProps::new()` does.
+/// It has the visibility of the component, and is handy in iterator adapters and combinators:
+///
+/// ```rust
 /// # use futures_signals_component_macro::component;
-/// #[component(render_fn = my_cmp)]
-/// struct MyCmp {
+/// #[component(render_fn = my_item_cmp)]
+/// struct MyItemCmp {
+///     #[default(0)]
+///     index: usize,
+/// }
+///
+/// fn my_item_cmp(props: impl MyItemCmpPropsTrait) -> usize {
+///     props.take().index
+/// }
+///
+/// let items = std::iter::repeat_with(my_item_cmp_props)
+///     .take(3)
+///     .enumerate()
+///     .map(|(index, props)| my_item_cmp(props.index(index)))
+///     .collect::<Vec<_>>();
+/// assert_eq!(items, vec![0, 1, 2]);
+/// ```
+///
+/// With the `builder` argument, the component struct itself is replaced by an uninhabited type of the same name and visibility, with a `MyCmp::builder()` function creating the default props as well.
+/// It gives callers an entry point named after the component, without the `Props` suffix, i.e. `my_item_cmp(MyItemCmp::builder().index(1))`.
+/// It can not be combined with a `props_name` or `trait_name` giving the props struct or trait the name of the component.
+///
+/// For documentation tooling, a `{COMPONENT}_DEFAULTS` const lists the fields which have a default, along with their default expression as written:
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_documented_cmp)]
+/// struct MyDocumentedCmp {
 ///     #[default(42)]
-///     my_int: i32,
-///     #[default({ let foo = 32; foo.to_string() })]
-///     my_string: String
+///     count: i32,
+///     label: String,
+///     #[signal]
+///     #[default(vec![1, 2])]
+///     items: Vec<i32>,
 /// }
+/// # fn my_documented_cmp(_props: impl MyDocumentedCmpPropsTrait) {}
+///
+/// assert_eq!(MY_DOCUMENTED_CMP_DEFAULTS, [("count", "42"), ("items", "vec![1, 2]")]);
 /// ```
 ///
-/// ## The `render_fn`
+/// The props struct has a `count_set_fields()` method, which counts the optional fields (those without a `#[default]`) the caller explicitly set, leaving out props injected by crate features, such as `apply`.
+/// This is meant for telemetry, i.e. for learning how a component library is configured in the wild:
 ///
-/// Your `render_fn` can not know the concrete type of your props struct, as it is heavily generic, and changes based on properties the user of the builder chose.
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_counted_cmp)]
+/// struct MyCountedCmp {
+///     #[signal]
+///     label: String,
+///     name: String,
+///     #[default(1)]
+///     count: i32,
+/// }
 ///
-/// It should always accept and argument that implements the generated `MyComponentPropsTrait` trait.
-/// This impl in turn has a `take()` method, which will consume the props struct and output Self, which will give us access to its member values (these match our fields in the original struct by identifier).
+/// fn my_counted_cmp(props: impl MyCountedCmpPropsTrait) -> usize {
+///     props.take().count_set_fields()
+/// }
 ///
-/// We can then destruct the Self into variables:
+/// assert_eq!(my_counted_cmp!({.name("a".to_string()).count(2)}), 1);
+/// ```
+///
+/// Components with non-signal fields get an `eq_ignoring_signals(&self, other: &Self)` method, which compares the non-signal fields, treating the signals of both props as equal.
+/// This helps with memoizing components mixing static configuration and live signals, i.e. only rendering a component again when its configuration changes.
+/// Bindings, slots and `#[emits]` fields have no equality and are left out, and the method is only available when the types of the compared fields are `PartialEq`:
 ///
 /// ```rust
 /// # use futures_signals_component_macro::component;
-/// #[component(render_fn=take_destruct_example)]
-/// struct TakeDestructExample {
+/// # use futures_signals::signal::Mutable;
+/// #[component(render_fn = my_memo_cmp)]
+/// struct MyMemoCmp {
 ///     #[signal]
-///     optional_string_signal: String,
+///     #[default(0)]
+///     value: i32,
+///     #[default("a".to_string())]
+///     name: String,
+/// }
+/// # fn my_memo_cmp(_props: impl MyMemoCmpPropsTrait) {}
+///
+/// let value = Mutable::new(1);
+/// let props = MyMemoCmpProps::new().value_signal(value.signal());
+///
+/// assert!(props.eq_ignoring_signals(&MyMemoCmpProps::new().value_signal(value.signal())));
+/// assert!(!props.eq_ignoring_signals(&MyMemoCmpProps::new().value_signal(value.signal()).name("b".to_string())));
+/// ```
+///
+/// Components without `#[signal]` and `#[signal_vec]` fields can implement `Clone` for their props struct with `derive(Clone)`, so a partly configured builder can be reused for several instances.
+/// Like `eq_ignoring_signals()`, the impl is only available when the types of the fields are `Clone`:
 ///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_badge, derive(Clone))]
+/// struct MyBadge {
+///     #[default("new".to_string())]
+///     text: String,
+///     #[default(1)]
+///     count: i32,
+/// }
+/// # fn my_badge(props: impl MyBadgePropsTrait) -> i32 { props.take().count }
+///
+/// let base = MyBadgeProps::new().text("sale".to_string());
+/// assert_eq!(my_badge(base.clone().count(2)), 2);
+/// assert_eq!(my_badge(base), 1);
+/// ```
+///
+/// ## Lifetimes
+/// Components may have lifetime params, which lets fields borrow for the duration of a scope.
+/// All generated items, including the props trait, take the lifetimes of the component, and the signals of `#[signal]` and `#[signal_vec]` fields are bound by them rather than `'static`.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::{always, SignalExt};
+/// #[component(render_fn = my_scoped_cmp)]
+/// struct MyScopedCmp<'a> {
 ///     #[signal]
-///     #[default("hi".to_string())]
-///     string_signal: String,
+///     label: &'a str,
+///     name: &'a str,
 /// }
 ///
-/// fn take_destruct_example(props: impl TakeDestructExamplePropsTrait + 'static) {
-///     let TakeDestructExampleProps {
-///         optional_string_signal /* this has the type Option<impl Signal<Item=String>> */,
-///         string_signal /* this has the type impl Signal<Item=String> */
-/// # , ..
-/// } = props.take();
+/// fn my_scoped_cmp<'a>(props: impl MyScopedCmpPropsTrait<'a>) -> Option<&'a str> {
+///     props.take().name
 /// }
+///
+/// let text = "a scoped label".to_string();
+/// // the signal borrows `text`, so it is only valid for as long as `text` is
+/// let name = my_scoped_cmp!({ .label_signal(always(6).map(|end| &text[..end])).name(&text) });
+/// assert_eq!(name, Some("a scoped label"));
 /// ```
 ///
-/// The return type of your render_fn should be the component type your rendering library expects.
-/// In the examples we use the DOMINATOR dom node, but you can use the `#[component]` macro to produce components for any library working with `futures-signals`.
+/// Lifetimes can not be combined with `dyn_trait`, `wasm_export`, `arbitrary`, `dynamic` or `from_json`.
+///
+/// ## Const generics
+/// Components may have const params, i.e. for fixed-size array fields. Like lifetimes, they are taken by all generated items, including the props trait, right after the lifetimes.
+/// Every const param has to be used by the type of a field.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_grid_cmp)]
+/// struct MyGridCmp<const N: usize> {
+///     #[default([0; N])]
+///     cells: [i32; N],
+/// }
+///
+/// fn my_grid_cmp<const N: usize>(props: impl MyGridCmpPropsTrait<N>) -> i32 {
+///     props.take().cells.iter().sum()
+/// }
+///
+/// assert_eq!(my_grid_cmp!({.cells([1, 2, 3])}), 6);
+/// ```
+///
+/// Const params can not be combined with `max_size`, `dyn_trait`, `wasm_export`, `arbitrary`, `dynamic` or `from_json`.
+///
+/// ## Where clauses
+/// Predicates of a `where` clause on the component are treated as inline bounds of the generic param or lifetime they bound, so they carry over to the props struct, its trait and its impls.
+/// Predicates bounding anything other than a generic param or lifetime of the component, i.e. `Vec<T>: Debug`, are rejected.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_labeled_cmp)]
+/// struct MyLabeledCmp<T = i32>
+/// where
+///     T: Clone + ToString,
+/// {
+///     value: T,
+/// }
+///
+/// fn my_labeled_cmp<P: MyLabeledCmpPropsTrait>(props: P) -> String {
+///     props.take().value.map(|value| value.clone().to_string()).unwrap_or_default()
+/// }
+///
+/// assert_eq!(my_labeled_cmp!({.value(12)}), "12");
+/// ```
+///
+/// ## Generic parameter order of the props struct
+/// The generic params of the generated `MyComponentProps` struct are stable, so hand written `impl` blocks against it stay valid across versions.
+/// For each field, in declaration order, the props struct takes:
+///
+/// 1. the generic param of the component used by the field's type, if any
+/// 2. `T{field_name}Signal` for `#[signal]` and `#[signal_vec]` fields
+///
+/// Lifetime and const params of the component come before all of these.
+/// Props injected by crate features, such as the `apply` prop of the `dominator` feature, always come after the fields of the struct.
+/// The `T{field_name}Required` params of `#[required]` fields come last, in declaration order, and default to the field types.
+/// With `extends`, the fields of the extended component come first.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::Signal;
+/// #[component(render_fn = my_ordered_cmp)]
+/// struct MyOrderedCmp<T: ToString = i32> {
+///     #[signal]
+///     label: T,
+///     #[signal]
+///     #[default(1)]
+///     count: i32,
+///     #[default(true)]
+///     flag: bool,
+/// }
+/// # fn my_ordered_cmp(_props: impl MyOrderedCmpPropsTrait) {}
+///
+/// // `T`, then `TlabelSignal` for `label`, then `TcountSignal` for `count`, and nothing for `flag`
+/// impl<T: ToString, TlabelSignal: Signal<Item = T>, TcountSignal: Signal<Item = i32>>
+///     MyOrderedCmpProps<T, TlabelSignal, TcountSignal>
+/// {
+///     pub fn is_flagged(&self) -> bool {
+///         self.flag
+///     }
+/// }
+///
+/// assert!(MyOrderedCmpProps::new().is_flagged());
+/// ```
+///
+/// ## Field order of the props struct
+/// The fields of the props struct, and so of the struct returned by `take()`, are in declaration order, whether they are defaulted, optional, `#[required]` or signals.
+/// Props injected by crate features come after them, followed by private bookkeeping fields, so a pattern naming the fields in declaration order needs a trailing `..`.
+/// Rust patterns match named fields by name rather than by position, but the stable order lets a pattern be mirrored by a tuple of the same fields:
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// #[component(render_fn = my_span_cmp)]
+/// struct MySpanCmp {
+///     start: u32,
+///     #[default(10)]
+///     len: u32,
+///     #[required]
+///     label: &'static str,
+/// }
+///
+/// fn my_span_cmp(props: impl MySpanCmpPropsTrait) -> (Option<u32>, u32, &'static str) {
+///     let MySpanCmpProps { start, len, label, .. } = props.take();
+///     (start, len, label)
+/// }
+///
+/// assert_eq!(my_span_cmp!({.label("a").start(1)}), (Some(1), 10, "a"));
+/// ```
+///
+/// ## Accessors of the props trait
+/// With the `accessors` argument, the props trait has a `&self` accessor for each plain field besides `take()`, named after it, so the render fn can read a prop before taking the props.
+/// Accessors of fields without a default return an `Option` of a reference.
+/// On the props struct itself the setters of the same names take precedence, so the accessors are called through the trait there, i.e. `MyCmpPropsTrait::field(&props)`.
+/// Signals, slots, event sinks, bindings and fields of a generic type have no accessor, as the trait can not name their types.
+/// A field named after a method of the props trait, such as `take`, is rejected, as its accessor would clash with it.
+///
+/// ```rust
+/// # use futures_signals_component_macro::component;
+/// # use futures_signals::signal::Signal;
+/// #[component(render_fn = my_gated_cmp, accessors)]
+/// struct MyGatedCmp {
+///     #[default(true)]
+///     enabled: bool,
+///     #[signal]
+///     #[default(0)]
+///     count: i32,
+///     tooltip: String,
+/// }
+///
+/// fn my_gated_cmp(props: impl MyGatedCmpPropsTrait) -> Option<String> {
+///     if !*props.enabled() {
+///         return None;
+///     }
+///
+///     let tooltip = props.tooltip().cloned();
+///     let _count = props.take().count;
+///     tooltip
+/// }
+///
+/// assert_eq!(my_gated_cmp!({.enabled(false)}), None);
+/// assert_eq!(my_gated_cmp!({.tooltip("hi".to_string())}), Some("hi".to_string()));
+/// ```
 ///
 /// # Example:
 ///
@@ -134,14 +2127,126 @@ use syn::{GenericArgument, Meta, PathArguments, Type};
 /// ```
 #[proc_macro_attribute]
 pub fn component(args: TokenStream, input: TokenStream) -> TokenStream {
-    let struct_ = syn::parse::<syn::ItemStruct>(input).expect("failed to parse struct");
-    let arg = syn::parse::<AttributeArgument>(args).expect("failed to parse attribute args");
+    let mut struct_ = match syn::parse::<syn::ItemStruct>(input) {
+        Ok(struct_) => struct_,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let args = proc_macro2::TokenStream::from(args);
+    let arg = match syn::parse2::<AttributeArgument>(args.clone()) {
+        Ok(arg) => arg,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let base = match struct_
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("component_base"))
+        .map(|attr| attr.parse_args::<ComponentBase>())
+        .transpose()
+    {
+        Ok(base) => base,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    // The fields of the extended component are not visible to us, so we hand the struct to its companion macro,
+    // which calls back into `#[component]` with the base generics and fields attached as `#[component_base(...)]`
+    if let (Some(extends), None) = (&arg.extends, &base) {
+        let extend_macro = Ident::new(
+            &format!("__{}_extend", extends.to_string().to_case(Case::Snake)),
+            extends.span(),
+        );
+
+        let component_macro_path = &arg.component_macro_path;
+
+        return quote! {
+            #extend_macro! { (#component_macro_path::component) (#args) #struct_ }
+        }
+        .into();
+    }
+
+    if let Some(base) = base {
+        if let Err(e) = merge_component_base(&mut struct_, base) {
+            return e.to_compile_error().into();
+        }
+    }
+
+    // Overrides are applied as `#[default(...)]` attributes, so they carry over to components extending this one
+    for prop_override in &arg.overrides {
+        let field = struct_
+            .fields
+            .iter_mut()
+            .find(|field| field.ident.as_ref() == Some(&prop_override.name));
+
+        let Some(field) = field else {
+            return syn::Error::new_spanned(
+                &prop_override.name,
+                format!(
+                    "can not override `{}`, as the component has no such field",
+                    prop_override.name
+                ),
+            )
+            .to_compile_error()
+            .into();
+        };
+
+        let default = &prop_override.default;
+        field.attrs.retain(|attr| {
+            !attr.path().is_ident("default")
+                && !attr.path().is_ident("default_once")
+                && !attr.path().is_ident("default_async")
+                && !attr.path().is_ident("cfg_native")
+                && !attr.path().is_ident("cfg_wasm")
+                && !attr.path().is_ident("required")
+        });
+        field.attrs.push(syn::parse_quote!(#[default(#default)]));
+    }
+
+    // Where clause predicates are folded into the bounds of the params they bound, so they are carried along to every
+    // generated item the params are, just like inline bounds
+    if let Some(where_clause) = struct_.generics.where_clause.take() {
+        for predicate in where_clause.predicates {
+            let folded = match &predicate {
+                syn::WherePredicate::Type(bound) if bound.lifetimes.is_none() => {
+                    let param = struct_.generics.type_params_mut().find(|param| {
+                        matches!(&bound.bounded_ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident(&param.ident))
+                    });
+
+                    param
+                        .map(|param| param.bounds.extend(bound.bounds.iter().cloned()))
+                        .is_some()
+                }
+                syn::WherePredicate::Lifetime(bound) => struct_
+                    .generics
+                    .lifetimes_mut()
+                    .find(|param| param.lifetime == bound.lifetime)
+                    .map(|param| param.bounds.extend(bound.bounds.iter().cloned()))
+                    .is_some(),
+                _ => false,
+            };
+
+            if !folded {
+                return syn::Error::new_spanned(
+                    predicate,
+                    "where clause predicates must bound a generic param of the component",
+                )
+                .to_compile_error()
+                .into();
+            }
+        }
+    }
+
+    let definition = arg.extendable.then(|| {
+        let generics = &struct_.generics;
+        let fields = &struct_.fields;
+
+        quote! {#generics #fields}
+    });
 
     let docs = struct_
         .attrs
         .into_iter()
         .filter_map(|attr| {
-            if *attr.path().get_ident().unwrap() == "doc" {
+            if attr.path().is_ident("doc") {
                 if let Meta::NameValue(docstring) = attr.meta {
                     Some(docstring.value)
                 } else {
@@ -155,85 +2260,574 @@ pub fn component(args: TokenStream, input: TokenStream) -> TokenStream {
 
     let fields = match struct_.fields {
         syn::Fields::Named(fields) => fields.named,
-        _ => panic!("struct must have named fields"),
+        syn::Fields::Unnamed(fields) => return named_fields_error(&fields).into(),
+        syn::Fields::Unit => return named_fields_error(&struct_.ident).into(),
+    };
+
+    let fields = match expand_events(fields, &arg.dominator_path) {
+        Ok(fields) => fields,
+        Err(e) => return e.to_compile_error().into(),
     };
 
+    let lifetimes = struct_.generics.lifetimes().cloned().collect::<Vec<_>>();
+
     let struct_generics = struct_
         .generics
         .params
         .iter()
-        .map(|param| match param {
-            syn::GenericParam::Type(type_param) => PropGenerics {
+        .filter_map(|param| match param {
+            syn::GenericParam::Type(type_param) => Some(PropGenerics {
                 param: type_param.clone(),
-            },
-            _ => panic!("prop struct must have only type params"),
+            }),
+            syn::GenericParam::Lifetime(_) | syn::GenericParam::Const(_) => None,
         })
         .collect::<Vec<_>>();
 
-    let fields = fields
+    let const_params = struct_.generics.const_params().cloned().collect::<Vec<_>>();
+
+    let mut props = match fields
         .iter()
-        .map(|field| parse_field(field, &struct_generics));
+        .map(|field| {
+            parse_field(
+                field,
+                &struct_generics,
+                &arg.dominator_path,
+                &arg.futures_signals_path,
+            )
+        })
+        .collect::<syn::Result<Punctuated<_, Token![,]>>>()
+    {
+        Ok(props) => props,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    // Generic params that no field refers to would be silently dropped from the generated props
+    // struct, so we reject them up front with an error pointing at the offending param
+    if let Some(unused) = struct_generics.iter().find(|generic| {
+        !props
+            .iter()
+            .any(|prop| prop.generics.as_ref() == Some(*generic))
+    }) {
+        return syn::Error::new_spanned(
+            &unused.param.ident,
+            format!(
+                "generic param `{}` is not used by any field of the component",
+                unused.param.ident
+            ),
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    // Const params are only in scope of the generated items, so every one of them has to show up in a field type
+    if let Some(unused) = const_params.iter().find(|param| {
+        let ident = param.ident.to_string();
+
+        !props.iter().any(|prop| {
+            let type_ = &prop.type_;
+            quote! {#type_}
+                .to_string()
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .any(|token| token == ident)
+        })
+    }) {
+        return syn::Error::new_spanned(
+            &unused.ident,
+            format!(
+                "const param `{}` is not used by any field of the component",
+                unused.ident
+            ),
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    // `dyn_trait` and `boxed` both box the signals of the props, which restricts the fields and arguments alike
+    let boxes_signals = arg.dyn_trait || arg.boxed;
+
+    // The size check and the runtime built props need concrete values for the const params
+    if let Some(param) = const_params.first() {
+        if arg.max_size.is_some()
+            || boxes_signals
+            || arg.wasm_export.is_some()
+            || arg.arbitrary
+            || arg.dynamic
+            || arg.from_json
+        {
+            return syn::Error::new_spanned(
+                &param.ident,
+                "components with const params can not be used with max_size, dyn_trait, boxed, wasm_export, arbitrary, dynamic or from_json",
+            )
+            .to_compile_error()
+            .into();
+        }
+    }
+
+    // Boxed signals, JS exports, proptest strategies, dynamic and deserialized values have to be 'static
+    if let Some(lifetime) = lifetimes.first() {
+        if boxes_signals
+            || arg.wasm_export.is_some()
+            || arg.arbitrary
+            || arg.dynamic
+            || arg.from_json
+        {
+            return syn::Error::new_spanned(
+                lifetime,
+                "components with lifetime params can not be used with dyn_trait, boxed, wasm_export, arbitrary, dynamic or from_json",
+            )
+            .to_compile_error()
+            .into();
+        }
+    }
+
+    // Props built from runtime data start out from `new()`, so they can not prove their required props are set
+    if let Some(prop) = props.iter().find(|prop| prop.required) {
+        if arg.wasm_export.is_some() || arg.arbitrary || arg.dynamic || arg.from_json {
+            return syn::Error::new_spanned(
+                &prop.name,
+                "#[required] fields can not be used with wasm_export, arbitrary, dynamic or from_json",
+            )
+            .to_compile_error()
+            .into();
+        }
+    }
+
+    // Streams can not be boxed back into signals, and fallback, map and on_change signals are stored mapped,
+    // so there is no boxed form of the props for `take_boxed()` to return
+    if let (true, Some(prop)) = (
+        boxes_signals,
+        props.iter().find(|prop| {
+            prop.as_stream
+                || prop.fallback.is_some()
+                || prop.map.is_some()
+                || prop.on_change.is_some()
+        }),
+    ) {
+        return syn::Error::new_spanned(
+            &prop.name,
+            "as_stream, fallback, map and on_change fields can not be used with dyn_trait or boxed",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    if let (true, Some(prop)) = (arg.snapshot, props.iter().find(|prop| prop.as_stream)) {
+        return syn::Error::new_spanned(
+            &prop.name,
+            "as_stream fields can not be used with snapshot",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    if arg.clone_signals {
+        if let Some(prop) = props.iter().find(|prop| prop.as_stream) {
+            return syn::Error::new_spanned(
+                &prop.name,
+                "as_stream fields can not be used with clone_signals",
+            )
+            .to_compile_error()
+            .into();
+        }
+
+        if boxes_signals {
+            return syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "clone_signals can not be combined with dyn_trait or boxed",
+            )
+            .to_compile_error()
+            .into();
+        }
+
+        for prop in props.iter_mut() {
+            prop.broadcast = matches!(prop.is_signal, Some(parse::SignalType::Item));
+        }
+    }
+
+    // Boxed signals are `Send`, but not `Sync`
+    if boxes_signals {
+        if let Some(prop) = props.iter().find(|prop| prop.is_sync) {
+            return syn::Error::new_spanned(
+                &prop.name,
+                "#[sync] fields can not be used with dyn_trait or boxed",
+            )
+            .to_compile_error()
+            .into();
+        }
+
+        // The boxed signals of dyn props only have the bounds of trait objects
+        if let Some(prop) = props.iter().find(|prop| !prop.signal_bounds.is_empty()) {
+            return syn::Error::new_spanned(
+                &prop.name,
+                "fields with signal bounds can not be used with dyn_trait or boxed",
+            )
+            .to_compile_error()
+            .into();
+        }
+
+        // The boxed signals of dyn props are stored as they are, rather than in a `Broadcaster`
+        if let Some(prop) = props.iter().find(|prop| prop.signal_ref) {
+            return syn::Error::new_spanned(
+                &prop.name,
+                "#[signal_ref] fields can not be used with dyn_trait or boxed",
+            )
+            .to_compile_error()
+            .into();
+        }
+    }
+
+    if arg.thread_safe {
+        // Boxed signals are `Send`, but not `Sync`
+        if boxes_signals {
+            return syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "thread_safe can not be combined with dyn_trait or boxed",
+            )
+            .to_compile_error()
+            .into();
+        }
+
+        // Event sinks and slots hold `Rc`s, so the props would never be `Send`
+        if let Some(prop) = props.iter().find(|prop| prop.emits.is_some() || prop.slot) {
+            return syn::Error::new_spanned(
+                &prop.name,
+                "#[emits] and #[slot] fields can not be used with thread_safe",
+            )
+            .to_compile_error()
+            .into();
+        }
+
+        for prop in props.iter_mut() {
+            prop.is_send = true;
+        }
+    }
+
+    // The signals of generic fields carry the bounds of their param, so a missing `Send` or `Sync` would otherwise
+    // surface as an error about the renamed param of a setter deep in the generated code
+    for prop in props.iter() {
+        let Some(generic) = prop.generics.as_ref().filter(|_| prop.is_signal.is_some()) else {
+            continue;
+        };
+
+        let has_bound = |name: &str| {
+            generic.param.bounds.iter().any(|bound| {
+                matches!(bound, syn::TypeParamBound::Trait(t) if t.path.segments.last().is_some_and(|s| s.ident == name))
+            })
+        };
+
+        let missing = [
+            ("Send", prop.is_send || arg.thread_safe),
+            ("Sync", prop.is_sync || arg.thread_safe),
+        ]
+        .into_iter()
+        .filter(|(name, required)| *required && !has_bound(name))
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+
+        if !missing.is_empty() {
+            let ident = &generic.param.ident;
+
+            return syn::Error::new_spanned(
+                ident,
+                format!(
+                    "the signal of `{}` must be `{}`, so the generic param `{}` must be bound by it as well, i.e. `{}: {}`",
+                    prop.name,
+                    missing.join(" + "),
+                    ident,
+                    ident,
+                    missing.join(" + ")
+                ),
+            )
+            .to_compile_error()
+            .into();
+        }
+    }
+
+    // `Default` is implemented for the props with their default generics, which `new()` needs all of
+    if arg.derive_default {
+        if let Some(generic) = props
+            .iter()
+            .filter_map(|prop| prop.generics.as_ref())
+            .find(|generic| generic.param.default.is_none())
+        {
+            return syn::Error::new_spanned(
+                &generic.param.ident,
+                format!(
+                    "derive(Default) requires the generic params of the component to have a default type, i.e. `{} = ...`",
+                    generic.param.ident
+                ),
+            )
+            .to_compile_error()
+            .into();
+        }
+    }
 
+    // Signals are only clonable once `clone_signals` stores them in a `Broadcaster`
+    if arg.derive_clone && !arg.clone_signals {
+        if let Some(prop) = props.iter().find(|prop| prop.is_signal.is_some()) {
+            return syn::Error::new(
+                prop.name.span(),
+                format!(
+                    "derive(Clone) can not clone the signal of `{}`, add the `clone_signals` argument to store it in a `Broadcaster`",
+                    prop.name
+                ),
+            )
+            .to_compile_error()
+            .into();
+        }
+    }
+
+    // The `builder()` host takes the name of the component, which `props_name` or `trait_name` may give away
+    if arg.builder {
+        if let Some(name) = [&arg.props_name, &arg.trait_name]
+            .into_iter()
+            .flatten()
+            .find(|name| **name == struct_.ident)
+        {
+            return syn::Error::new(
+                name.span(),
+                format!(
+                    "`builder` declares a type named `{}`, which can not be the name of the props struct or trait as well",
+                    name
+                ),
+            )
+            .to_compile_error()
+            .into();
+        }
+    }
+
+    // Every field, including the injected `apply` prop of the dominator feature, has a bit of the `u64` mask
+    if arg.prop_mask && props.len() + usize::from(cfg!(feature = "dominator") && arg.apply) > 64 {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "prop_mask components can have at most 64 fields",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    // The newtype wraps the stored signal of the only field, so it must always be set
+    if arg.newtype {
+        let single_signal = match props.iter().collect::<Vec<_>>().as_slice() {
+            [prop] => {
+                matches!(prop.is_signal, Some(parse::SignalType::Item))
+                    && prop.default.is_some()
+                    && !prop.binding
+            }
+            _ => false,
+        };
+
+        if !single_signal {
+            return syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "newtype components must have a single #[signal] field, with a default",
+            )
+            .to_compile_error()
+            .into();
+        }
+    }
+
+    // the dominator `apply` prop takes a `DomBuilder` of an `HtmlElement`, unless the component configures it
+    let apply_element = arg.apply.then(|| {
+        let web_sys = &arg.web_sys_path;
+        arg.apply_element
+            .unwrap_or_else(|| syn::parse_quote!(#web_sys::HtmlElement))
+    });
+
+    #[cfg_attr(not(feature = "dominator"), allow(unused_mut))]
     let mut cmp: Component = Component {
         name: struct_.ident,
+        vis: arg.vis.unwrap_or(struct_.vis),
+        lifetimes,
+        const_params,
         render_fn: arg.fn_name,
-        props: fields.collect(),
+        variant_render_fns: arg.variant_fns,
+        ssr_render_fn: arg.ssr_fn_name,
+        async_take: arg.async_take,
+        delta: arg.delta,
+        signal_suffix: arg.signal_suffix,
+        definition,
+        wasm_export: arg.wasm_export,
+        dyn_trait: arg.dyn_trait,
+        boxed: arg.boxed,
+        clone_signals: arg.clone_signals,
+        must_use: arg.must_use,
+        with_helpers: arg.with_helpers,
+        snapshot: arg.snapshot,
+        impl_traits: arg.impl_traits,
+        arbitrary: arg.arbitrary,
+        apply_after: arg.apply_after,
+        dynamic: arg.dynamic,
+        macro_module: arg.macro_module,
+        sealed: arg.sealed,
+        signal_or_value: arg.signal_or_value,
+        from_json: arg.from_json,
+        metadata: arg.metadata,
+        max_size: arg.max_size,
+        thread_safe: arg.thread_safe,
+        macro_name: arg.macro_name,
+        props_name: arg.props_name,
+        trait_name: arg.trait_name,
+        newtype: arg.newtype,
+        prop_mask: arg.prop_mask,
+        accessors: arg.accessors,
+        derive_default: arg.derive_default,
+        derive_clone: arg.derive_clone,
+        derive_debug: arg.derive_debug,
+        builder: arg.builder,
+        futures_signals: arg.futures_signals_path,
+        dominator: arg.dominator_path,
+        web_sys: arg.web_sys_path,
+        apply_element,
+        props,
         docs,
     };
 
-    #[cfg(feature = "dominator")]
-    let apply_prop = parse::Prop {
-        is_signal: None,
-        is_send: false,
-        name: syn::Ident::new("apply", cmp.name.span()),
-        generics: Some(PropGenerics { param: syn::parse_str::<syn::TypeParam>("TApplyFn: FnOnce(dominator::DomBuilder<web_sys::HtmlElement>) -> dominator::DomBuilder<web_sys::HtmlElement> = fn(dominator::DomBuilder<web_sys::HtmlElement>)->dominator::DomBuilder<web_sys::HtmlElement>").expect("failed to parse type param") }),
-        type_: syn::parse_str::<Type>("TApplyFn").expect("failed to parse type"),
-        default: None,
-        docs: vec![],
+    // The accessors are methods of the props traits, which can not have two items of the same name
+    let trait_items: &[&str] = if cmp.dyn_trait {
+        &["take", "take_boxed"]
+    } else {
+        &["take"]
     };
 
+    if let Some(prop) = render::render_props_accessors::accessor_props(&cmp)
+        .find(|prop| trait_items.iter().any(|item| prop.name == item))
+    {
+        return syn::Error::new(
+            prop.name.span(),
+            format!(
+                "the accessor of `{}` clashes with the `{}` method of the props trait, rename the field or remove `accessors`",
+                prop.name, prop.name
+            ),
+        )
+        .to_compile_error()
+        .into();
+    }
+
     #[cfg(feature = "dominator")]
-    cmp.props.push(apply_prop);
+    if let Some(element) = &cmp.apply_element {
+        let dominator = &cmp.dominator;
+        let builder = quote::quote! {#dominator::DomBuilder<#element>};
+
+        cmp.props.push(parse::Prop {
+            generics: Some(PropGenerics {
+                param: syn::parse_quote!(TApplyFn: FnOnce(#builder) -> #builder = fn(#builder) -> #builder),
+            }),
+            ..parse::Prop::new(
+                syn::Ident::new("apply", cmp.name.span()),
+                syn::parse_quote!(TApplyFn),
+            )
+        });
+    }
 
     render_props(&cmp).into()
 }
 
+/// Reports a component struct without named fields at its fields, or at its name for unit structs
+fn named_fields_error(tokens: &impl quote::ToTokens) -> proc_macro2::TokenStream {
+    syn::Error::new_spanned(tokens, "components must be structs with named fields")
+        .to_compile_error()
+}
+
+/// Prepends the generics and fields of an extended component to the ones of `struct_`.
+/// Fields and generic params of `struct_` replace same-named ones of the base.
+fn merge_component_base(struct_: &mut syn::ItemStruct, base: ComponentBase) -> syn::Result<()> {
+    if let syn::Fields::Unit = struct_.fields {
+        struct_.fields = syn::Fields::Named(syn::parse_quote!({}));
+        struct_.semi_token = None;
+    }
+
+    let syn::Fields::Named(fields) = &mut struct_.fields else {
+        return Err(syn::Error::new_spanned(
+            &struct_.fields,
+            "components must be structs with named fields",
+        ));
+    };
+
+    let mut merged_fields = base.fields.named;
+
+    for field in std::mem::take(&mut fields.named) {
+        match merged_fields
+            .iter_mut()
+            .find(|base_field| base_field.ident == field.ident)
+        {
+            Some(base_field) => *base_field = field,
+            None => merged_fields.push(field),
+        }
+    }
+
+    fields.named = merged_fields;
+
+    let mut merged_params = base.generics.params;
+
+    for param in std::mem::take(&mut struct_.generics.params) {
+        match merged_params
+            .iter_mut()
+            .find(|base_param| generic_param_ident(base_param) == generic_param_ident(&param))
+        {
+            Some(base_param) => *base_param = param,
+            None => merged_params.push(param),
+        }
+    }
+
+    struct_.generics.params = merged_params;
+
+    Ok(())
+}
+
+fn generic_param_ident(param: &syn::GenericParam) -> Option<&syn::Ident> {
+    match param {
+        syn::GenericParam::Type(type_param) => Some(&type_param.ident),
+        _ => None,
+    }
+}
+
 fn get_type_generic_param_use(
     type_: &Type,
     struct_generics: &Vec<PropGenerics>,
 ) -> Vec<PropGenerics> {
     let mut out = vec![];
 
-    if let Type::Path(type_path) = &type_ {
-        for segment in &type_path.path.segments {
-            if let Some(generic) = struct_generics
-                .iter()
-                .find(|generic| segment.ident == generic.param.ident)
-            {
-                out.push(generic.clone());
+    // a param nested more than once in the type, i.e. `(T, Vec<T>)`, is still a single use of it
+    let mut push = |generics: Vec<PropGenerics>| {
+        for generic in generics {
+            if !out.contains(&generic) {
+                out.push(generic);
             }
+        }
+    };
 
-            if let PathArguments::AngleBracketed(angle_bracketed_arguments) = &segment.arguments {
-                for argument in &angle_bracketed_arguments.args {
-                    match &argument {
-                        GenericArgument::Type(Type::Path(generic_type)) => {
-                            for segment in generic_type.path.segments.iter() {
-                                if let Some(generic) = struct_generics
-                                    .iter()
-                                    .find(|generic| segment.ident == generic.param.ident)
-                                {
-                                    out.push(generic.clone());
-                                }
-                            }
-                        }
-                        GenericArgument::Type(type_) => {
-                            out.append(&mut get_type_generic_param_use(type_, struct_generics));
+    match type_ {
+        Type::Path(type_path) => {
+            for segment in &type_path.path.segments {
+                if let Some(generic) = struct_generics
+                    .iter()
+                    .find(|generic| segment.ident == generic.param.ident)
+                {
+                    push(vec![generic.clone()]);
+                }
+
+                if let PathArguments::AngleBracketed(angle_bracketed_arguments) = &segment.arguments
+                {
+                    for argument in &angle_bracketed_arguments.args {
+                        if let GenericArgument::Type(type_) = argument {
+                            push(get_type_generic_param_use(type_, struct_generics));
                         }
-                        _ => {}
                     }
                 }
             }
         }
+        Type::Reference(reference) => {
+            push(get_type_generic_param_use(&reference.elem, struct_generics))
+        }
+        Type::Array(array) => push(get_type_generic_param_use(&array.elem, struct_generics)),
+        Type::Slice(slice) => push(get_type_generic_param_use(&slice.elem, struct_generics)),
+        Type::Tuple(tuple) => {
+            for elem in &tuple.elems {
+                push(get_type_generic_param_use(elem, struct_generics));
+            }
+        }
+        _ => {}
     }
 
     out
@@ -1,16 +1,164 @@
+pub mod parse_events;
 pub mod parse_field;
 
-use proc_macro2::Ident;
+use proc_macro2::{Ident, Span, TokenStream};
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::{Attribute, Expr, Meta, Token, Type, TypeParam};
+use syn::{
+    bracketed, parenthesized, Attribute, ConstParam, Expr, FieldsNamed, Generics, LifetimeParam,
+    LitBool, LitInt, LitStr, Meta, Path, Signature, Token, Type, TypeParam, TypeParamBound,
+    Visibility,
+};
+
+const COMPONENT_ARGUMENTS: &[&str] = &[
+    "render_fn",
+    "async_take",
+    "delta",
+    "signal_suffix",
+    "extends",
+    "extendable",
+    "component_macro_path",
+    "override",
+    "wasm_export",
+    "dyn_trait",
+    "boxed",
+    "clone_signals",
+    "must_use",
+    "with_helpers",
+    "snapshot",
+    "impl_trait",
+    "arbitrary",
+    "apply_timing",
+    "dynamic",
+    "macro_module",
+    "sealed",
+    "signal_or_value",
+    "from_json",
+    "metadata",
+    "ssr_render_fn",
+    "max_size",
+    "thread_safe",
+    "macro_name",
+    "props_name",
+    "trait_name",
+    "newtype",
+    "prop_mask",
+    "futures_signals_path",
+    "dominator_path",
+    "web_sys_path",
+    "apply",
+    "apply_element",
+    "vis",
+    "accessors",
+    "derive",
+    "builder",
+];
 
 pub struct AttributeArgument {
-    #[allow(dead_code)]
-    pub param: Ident,
-    #[allow(dead_code)]
-    pub eq: Token![=],
-    pub fn_name: Ident,
+    pub fn_name: Path,
+    pub variant_fns: Vec<Path>,
+    pub ssr_fn_name: Option<Path>,
+    pub async_take: bool,
+    pub delta: bool,
+    pub signal_suffix: String,
+    pub extends: Option<Ident>,
+    pub extendable: bool,
+    pub component_macro_path: Path,
+    pub overrides: Vec<PropOverride>,
+    pub wasm_export: Option<Type>,
+    pub dyn_trait: bool,
+    pub boxed: bool,
+    pub clone_signals: bool,
+    pub must_use: bool,
+    pub with_helpers: bool,
+    pub snapshot: bool,
+    pub impl_traits: Vec<ImplTrait>,
+    pub arbitrary: bool,
+    pub apply_after: bool,
+    pub dynamic: bool,
+    pub macro_module: Option<Ident>,
+    pub sealed: bool,
+    pub signal_or_value: bool,
+    pub from_json: bool,
+    pub metadata: Option<Path>,
+    pub max_size: Option<LitInt>,
+    pub thread_safe: bool,
+    pub macro_name: Option<Ident>,
+    pub props_name: Option<Ident>,
+    pub trait_name: Option<Ident>,
+    pub newtype: bool,
+    pub prop_mask: bool,
+    pub futures_signals_path: Path,
+    pub dominator_path: Path,
+    pub web_sys_path: Path,
+    pub apply: bool,
+    pub apply_element: Option<Type>,
+    pub vis: Option<Visibility>,
+    pub accessors: bool,
+    pub derive_default: bool,
+    pub derive_clone: bool,
+    pub derive_debug: bool,
+    pub builder: bool,
+}
+
+/// A `Trait, fn method(self) -> Output` entry of the `impl_trait(...)` component argument
+#[derive(Clone)]
+pub struct ImplTrait {
+    pub trait_: Path,
+    pub method: Signature,
+}
+
+impl Parse for ImplTrait {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let trait_ = input.parse::<Path>()?;
+        input.parse::<Token![,]>()?;
+        let method = input.parse::<Signature>()?;
+
+        match method.receiver() {
+            Some(receiver) if receiver.reference.is_none() => {}
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &method,
+                    "the impl_trait method must take `self` by value, as it is passed on to the render_fn",
+                ))
+            }
+        }
+
+        Ok(ImplTrait { trait_, method })
+    }
+}
+
+/// A `field = expr` entry of the `override(...)` component argument
+pub struct PropOverride {
+    pub name: Ident,
+    pub default: Expr,
+}
+
+impl Parse for PropOverride {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+        let default = input.parse::<Expr>()?;
+
+        Ok(PropOverride { name, default })
+    }
+}
+
+/// The generics and fields of an extended component, passed along by its companion `__{component}_extend!` macro
+/// through the `#[component_base(...)]` attribute
+pub struct ComponentBase {
+    pub generics: Generics,
+    pub fields: FieldsNamed,
+}
+
+impl Parse for ComponentBase {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let generics = input.parse::<Generics>()?;
+        let fields = input.parse::<FieldsNamed>()?;
+
+        Ok(ComponentBase { generics, fields })
+    }
 }
 
 #[derive(Clone)]
@@ -34,34 +182,590 @@ pub enum SignalType {
 pub struct Prop {
     pub is_signal: Option<SignalType>,
     pub is_send: bool,
+    /// The signal must be `Sync`, as annotated with `#[sync]`
+    pub is_sync: bool,
+    pub as_stream: bool,
+    /// The signal, and thereby any value passed to the value setter, must be `'static`
+    pub owned: bool,
+    /// Extra bounds of the signal type param, from `#[signal(bounds = "...")]`
+    pub signal_bounds: Vec<TypeParamBound>,
+    /// The signal is stored in a `Broadcaster`, making it clonable
+    pub broadcast: bool,
+    /// The signal is set with `{name}_signal_ref()`, and borrowed by the render fn through its `Broadcaster`
+    pub signal_ref: bool,
     pub name: Ident,
     pub generics: Option<PropGenerics>,
     pub type_: Type,
     pub default: Option<syn::Expr>,
+    /// The default is evaluated once per process, and cloned for every props value
+    pub default_once: bool,
+    /// The default is a signal, which is boxed as the default signal type rather than wrapped in `always()`
+    pub default_signal: bool,
+    /// The default is a future, whose output is the first value of the default signal, with the fallback shown until then
+    pub default_async: bool,
+    /// The default names generic params of the component, so it is evaluated by a function generic over them
+    pub generic_default: bool,
+    /// Value setters accept `impl Into<T>` rather than the field type itself
+    pub into: bool,
+    /// The `String` field is stored as a `Cow<'static, str>`, which its default is converted into
+    pub cow: bool,
+    /// The event type of an event sink field, whose setter accepts `impl Fn(Event) + 'static`
+    pub emits: Option<Type>,
+    /// The value setter accepts `impl TryInto<T>`, and returns a `Result` with the conversion error
+    pub try_into: bool,
+    /// The value used in place of `None`, while the received signal of `Option<T>` has not produced a value yet
+    pub fallback: Option<Expr>,
+    /// The item type of the signals the setters accept, and the closure mapping them into the field type
+    pub map: Option<(Type, Expr)>,
+    /// A callback called with a reference to every value of the signal, as the render fn polls it
+    pub on_change: Option<Expr>,
+    /// The field is a two-way binding, stored as the `Mutable<T>` passed to its setter
+    pub binding: bool,
+    /// The value type of an observable field, stored as a `Mutable` its value setter creates
+    pub observable: Option<Type>,
+    /// The `String` field is stored as an `Arc<str>` from the string pool of the component
+    pub intern: bool,
+    /// The field is a named slot for a framework element, i.e. a dominator `Dom`
+    pub slot: bool,
+    /// The item type of a `Vec` field, whose setter collects any iterator of items convertible into it
+    pub collect: Option<Type>,
+    /// The field must be set before the props can be passed to the render fn, which is tracked in a generic param of the props
+    pub required: bool,
+    /// The field has no setters, and always holds its default
+    pub skip: bool,
+    /// The signal is a theme, which `apply_{field}()` sets as the `data-theme` attribute of a dominator element
+    #[cfg_attr(not(feature = "dominator"), allow(dead_code))]
+    pub theme: bool,
+    /// The `Duration` field has a `{field}_ms` setter as well, taking milliseconds
+    pub duration_ms: bool,
+    /// The borrowed form of the field type, the value setter accepts `impl AsRef` of and stores an owned copy of
+    pub to_owned: Option<Type>,
+    /// The validator the value setter passes a reference to the value to, panicking on `Err` in debug builds
+    pub validate: Option<Path>,
+    /// The name of the setters of the field, rather than the name of the field
+    pub rename: Option<Ident>,
+    /// The message of a one-time runtime warning, logged when the setters of the field are first called
+    pub deprecated_runtime: Option<Expr>,
+    /// The `#[deprecated]` attribute of the field, which is put on its setters
+    pub deprecated: Option<Attribute>,
+    /// The prop is added by the macro, i.e. the `apply` prop of the `dominator` feature, rather than declared as a field
+    pub injected: bool,
     pub docs: Vec<Expr>,
 }
 
+impl Prop {
+    /// A plain prop of the given name and type, without any field attributes, for props the macro adds to the component
+    #[cfg_attr(not(feature = "dominator"), allow(dead_code))]
+    pub fn new(name: Ident, type_: Type) -> Self {
+        Self {
+            is_signal: None,
+            is_send: false,
+            is_sync: false,
+            as_stream: false,
+            owned: false,
+            signal_bounds: vec![],
+            broadcast: false,
+            signal_ref: false,
+            name,
+            generics: None,
+            type_,
+            default: None,
+            default_once: false,
+            default_signal: false,
+            default_async: false,
+            generic_default: false,
+            into: false,
+            cow: false,
+            emits: None,
+            try_into: false,
+            fallback: None,
+            map: None,
+            on_change: None,
+            binding: false,
+            observable: None,
+            intern: false,
+            slot: false,
+            collect: None,
+            required: false,
+            skip: false,
+            theme: false,
+            duration_ms: false,
+            to_owned: None,
+            validate: None,
+            rename: None,
+            deprecated_runtime: None,
+            deprecated: None,
+            injected: true,
+            docs: vec![],
+        }
+    }
+}
+
 pub struct Component {
     pub name: Ident,
-    pub render_fn: Ident,
+    pub vis: Visibility,
+    pub lifetimes: Vec<LifetimeParam>,
+    /// The const params of the component, which come right after its lifetimes
+    pub const_params: Vec<ConstParam>,
+    /// The render fn, which may be an associated function, i.e. `MyCmpProps::render`
+    pub render_fn: Path,
+    /// The render fns of a `render_fn = [compact, full]` list, each rendering the props through a macro named after it
+    pub variant_render_fns: Vec<Path>,
+    /// The render fn the component macro calls instead of `render_fn` when the calling crate has its `ssr` feature enabled
+    pub ssr_render_fn: Option<Path>,
+    pub async_take: bool,
+    pub delta: bool,
+    pub signal_suffix: String,
+    /// The generics and fields of the component as written, captured by the `__{component}_extend!` macro of
+    /// `extendable` components
+    pub definition: Option<TokenStream>,
+    #[cfg_attr(not(feature = "wasm"), allow(dead_code))]
+    pub wasm_export: Option<Type>,
+    pub dyn_trait: bool,
+    /// The props convert into the `DynXProps` newtype, which holds them with all signals boxed
+    pub boxed: bool,
+    pub clone_signals: bool,
+    pub must_use: bool,
+    pub with_helpers: bool,
+    pub snapshot: bool,
+    pub impl_traits: Vec<ImplTrait>,
+    #[cfg_attr(not(feature = "proptest"), allow(dead_code))]
+    pub arbitrary: bool,
+    /// The dominator `apply` prop is applied after the builder operations of the render fn, rather than before them
+    #[cfg_attr(not(feature = "dominator"), allow(dead_code))]
+    pub apply_after: bool,
+    pub dynamic: bool,
+    /// The module the component macros are placed in, rather than the enclosing scope
+    pub macro_module: Option<Ident>,
+    pub sealed: bool,
+    /// The value setters of signal fields accept either a value or a signal
+    pub signal_or_value: bool,
+    #[cfg_attr(not(feature = "serde"), allow(dead_code))]
+    pub from_json: bool,
+    /// The module defining the `ComponentMeta` and `PropInfo` types the `METADATA` const of the props is built from
+    pub metadata: Option<Path>,
+    /// The size in bytes the props struct, with its default generics, must not exceed
+    pub max_size: Option<LitInt>,
+    /// The props trait has `Send + Sync` supertraits, and all signals must be `Send + Sync`
+    pub thread_safe: bool,
+    /// The name of the component macro, rather than the snake cased component name
+    pub macro_name: Option<Ident>,
+    /// The name of the props struct, rather than `{Component}Props`
+    pub props_name: Option<Ident>,
+    /// The name of the props trait, rather than `{Component}PropsTrait`
+    pub trait_name: Option<Ident>,
+    /// The single signal field is wrapped in a `#[repr(transparent)]` newtype by `into_signal()`
+    pub newtype: bool,
+    /// The props track which of their fields are set in a bitflags-style mask, as returned by `set_mask()`
+    pub prop_mask: bool,
+    /// The props trait has a `&self` accessor for each plain field
+    pub accessors: bool,
+    /// The props with their default generics implement `Default`, as `new()`
+    pub derive_default: bool,
+    /// The props implement `Clone` when their field types do, which `clone_signals` implies
+    pub derive_clone: bool,
+    /// The props implement `Debug` when their plain field types do, which `arbitrary` implies
+    pub derive_debug: bool,
+    /// The component struct is replaced by an uninhabited type with a `builder()` function creating the props
+    pub builder: bool,
+    /// The path of the `futures_signals` crate, which the generated code names it by
+    pub futures_signals: Path,
+    /// The path of the `dominator` crate, which the generated code names it by
+    #[cfg_attr(not(feature = "dominator"), allow(dead_code))]
+    pub dominator: Path,
+    /// The path of the `web_sys` crate, which the generated code names it by
+    #[cfg_attr(not(feature = "dominator"), allow(dead_code))]
+    pub web_sys: Path,
+    /// The element type of the `DomBuilder` the dominator `apply` prop is applied to, or `None` if `apply = false`
+    #[cfg_attr(not(feature = "dominator"), allow(dead_code))]
+    pub apply_element: Option<Type>,
     pub props: Punctuated<Prop, Token![,]>,
     pub docs: Vec<Expr>,
 }
 
 impl Parse for AttributeArgument {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let param = input.parse::<Ident>()?;
-        let eq = input.parse::<Token![=]>()?;
-        let fn_name = input.parse::<Ident>()?;
+        let args = input.fork().parse::<TokenStream>()?;
+        let mut fn_name = None;
+        let mut variant_fns = vec![];
+        let mut ssr_fn_name = None;
+        let mut async_take = false;
+        let mut delta = false;
+        let mut signal_suffix = "_signal".to_string();
+        let mut extends = None;
+        let mut extendable = false;
+        let mut component_macro_path = syn::parse_quote!(::futures_signals_component_macro);
+        let mut overrides = vec![];
+        let mut wasm_export = None;
+        let mut dyn_trait = false;
+        let mut boxed = false;
+        let mut clone_signals = false;
+        let mut must_use = false;
+        let mut with_helpers = false;
+        let mut snapshot = false;
+        let mut impl_traits = vec![];
+        let mut arbitrary = false;
+        let mut apply_after = false;
+        let mut dynamic = false;
+        let mut macro_module = None;
+        let mut sealed = false;
+        let mut signal_or_value = false;
+        let mut from_json = false;
+        let mut metadata = None;
+        let mut max_size = None;
+        let mut thread_safe = false;
+        let mut macro_name = None;
+        let mut props_name = None;
+        let mut trait_name = None;
+        let mut futures_signals_path = syn::parse_quote!(futures_signals);
+        let mut dominator_path = syn::parse_quote!(dominator);
+        let mut web_sys_path = syn::parse_quote!(web_sys);
+        let mut newtype = false;
+        let mut prop_mask = false;
+        let mut apply = true;
+        let mut apply_element = None;
+        let mut vis = None;
+        let mut accessors = false;
+        let mut derive_default = false;
+        let mut derive_clone = false;
+        let mut derive_debug = false;
+        let mut builder = false;
+        // the argument configuring the `apply` prop, which is rejected alongside `apply = false`
+        let mut apply_config: Option<Ident> = None;
+
+        while !input.is_empty() {
+            // `override` is a reserved keyword, so keys are parsed as any ident
+            let key = Ident::parse_any(input)?;
+
+            match key.to_string().as_str() {
+                "render_fn" => {
+                    input.parse::<Token![=]>()?;
+
+                    // `render_fn = [compact, full]` renders the props with each fn, through a macro named after it
+                    if input.peek(syn::token::Bracket) {
+                        let content;
+                        bracketed!(content in input);
+                        variant_fns = Punctuated::<Path, Token![,]>::parse_terminated(&content)?
+                            .into_iter()
+                            .collect::<Vec<_>>();
+
+                        if variant_fns.is_empty() {
+                            return Err(syn::Error::new(
+                                key.span(),
+                                "expected at least one render function, i.e. `render_fn = [compact, full]`",
+                            ));
+                        }
+
+                        fn_name = variant_fns.first().cloned();
+                    } else {
+                        fn_name = Some(input.parse::<Path>().map_err(|e| {
+                            syn::Error::new(
+                                e.span(),
+                                "expected `render_fn = <path>`, naming the render function",
+                            )
+                        })?);
+                    }
+                }
+                "ssr_render_fn" => {
+                    input.parse::<Token![=]>()?;
+                    ssr_fn_name = Some(input.parse::<Path>()?);
+                }
+                "async_take" => async_take = true,
+                "delta" => delta = true,
+                "dyn_trait" => dyn_trait = true,
+                "boxed" => {
+                    boxed = if input.peek(Token![=]) {
+                        input.parse::<Token![=]>()?;
+                        input.parse::<LitBool>()?.value
+                    } else {
+                        true
+                    };
+                }
+                "clone_signals" => clone_signals = true,
+                "with_helpers" => with_helpers = true,
+                "snapshot" => snapshot = true,
+                "dynamic" => dynamic = true,
+                "extendable" => extendable = true,
+                "sealed" => sealed = true,
+                "signal_or_value" => signal_or_value = true,
+                "thread_safe" => thread_safe = true,
+                "newtype" => newtype = true,
+                "prop_mask" => prop_mask = true,
+                "accessors" => accessors = true,
+                "builder" => builder = true,
+                "must_use" => {
+                    must_use = if input.peek(Token![=]) {
+                        input.parse::<Token![=]>()?;
+                        input.parse::<LitBool>()?.value
+                    } else {
+                        true
+                    };
+                }
+                "signal_suffix" => {
+                    input.parse::<Token![=]>()?;
+                    let suffix = input.parse::<LitStr>()?;
+
+                    if suffix.value().is_empty() {
+                        return Err(syn::Error::new(
+                            suffix.span(),
+                            "signal_suffix can not be empty, as the signal setters would clash with the value setters",
+                        ));
+                    }
+
+                    // the suffix is appended to field names, so it must continue an identifier
+                    if syn::parse_str::<Ident>(&format!("x{}", suffix.value())).is_err() {
+                        return Err(syn::Error::new_spanned(
+                            &suffix,
+                            format!(
+                                "signal_suffix `{}` can not be appended to an identifier, it may only contain letters, digits and underscores",
+                                suffix.value()
+                            ),
+                        ));
+                    }
+
+                    signal_suffix = suffix.value();
+                }
+                "macro_name" => {
+                    input.parse::<Token![=]>()?;
+                    macro_name = Some(input.parse::<Ident>()?);
+                }
+                "props_name" => {
+                    input.parse::<Token![=]>()?;
+                    props_name = Some(input.parse::<Ident>()?);
+                }
+                "trait_name" => {
+                    input.parse::<Token![=]>()?;
+                    trait_name = Some(input.parse::<Ident>()?);
+                }
+                "macro_module" => {
+                    input.parse::<Token![=]>()?;
+                    macro_module = Some(input.parse::<Ident>()?);
+                }
+                "metadata" => {
+                    input.parse::<Token![=]>()?;
+                    metadata = Some(input.parse::<Path>()?);
+                }
+                "futures_signals_path" => {
+                    input.parse::<Token![=]>()?;
+                    futures_signals_path = input.parse::<Path>()?;
+                }
+                "dominator_path" => {
+                    input.parse::<Token![=]>()?;
+                    dominator_path = input.parse::<Path>()?;
+                }
+                "web_sys_path" => {
+                    input.parse::<Token![=]>()?;
+                    web_sys_path = input.parse::<Path>()?;
+                }
+                "component_macro_path" => {
+                    input.parse::<Token![=]>()?;
+                    component_macro_path = input.parse::<Path>()?;
+                }
+                "vis" => {
+                    input.parse::<Token![=]>()?;
+                    vis = Some(input.parse::<Visibility>()?);
+                }
+                "max_size" => {
+                    input.parse::<Token![=]>()?;
+                    let size = input.parse::<LitInt>()?;
+                    size.base10_parse::<usize>()?;
+                    max_size = Some(size);
+                }
+                "extends" => {
+                    input.parse::<Token![=]>()?;
+                    extends = Some(input.parse::<Ident>()?);
+                }
+                "wasm_export" => {
+                    input.parse::<Token![=]>()?;
+                    let return_type = input.parse::<Type>()?;
+
+                    if !cfg!(feature = "wasm") {
+                        return Err(syn::Error::new(
+                            key.span(),
+                            "wasm_export requires the `wasm` feature of futures-signals-component-macro",
+                        ));
+                    }
+
+                    wasm_export = Some(return_type);
+                }
+                "arbitrary" => {
+                    if !cfg!(feature = "proptest") {
+                        return Err(syn::Error::new(
+                            key.span(),
+                            "arbitrary requires the `proptest` feature of futures-signals-component-macro",
+                        ));
+                    }
+
+                    arbitrary = true;
+                }
+                "apply_timing" => {
+                    input.parse::<Token![=]>()?;
+                    let timing = input.parse::<Ident>()?;
+
+                    if !cfg!(feature = "dominator") {
+                        return Err(syn::Error::new(
+                            key.span(),
+                            "apply_timing requires the `dominator` feature of futures-signals-component-macro",
+                        ));
+                    }
+
+                    apply_config = Some(key.clone());
+                    apply_after = match timing.to_string().as_str() {
+                        "before" => false,
+                        "after" => true,
+                        _ => {
+                            return Err(syn::Error::new(
+                                timing.span(),
+                                "unknown apply_timing, expected one of: before, after",
+                            ))
+                        }
+                    };
+                }
+                "apply" | "apply_element" => {
+                    input.parse::<Token![=]>()?;
 
-        Ok(AttributeArgument { param, eq, fn_name })
+                    if !cfg!(feature = "dominator") {
+                        return Err(syn::Error::new(
+                            key.span(),
+                            format!("{key} requires the `dominator` feature of futures-signals-component-macro"),
+                        ));
+                    }
+
+                    if key == "apply" {
+                        apply = input.parse::<LitBool>()?.value;
+                    } else {
+                        apply_element = Some(input.parse::<Type>()?);
+                        apply_config = Some(key.clone());
+                    }
+                }
+                "from_json" => {
+                    if !cfg!(feature = "serde") {
+                        return Err(syn::Error::new(
+                            key.span(),
+                            "from_json requires the `serde` feature of futures-signals-component-macro",
+                        ));
+                    }
+
+                    from_json = true;
+                }
+                "impl_trait" => {
+                    let content;
+                    parenthesized!(content in input);
+                    impl_traits.push(content.parse::<ImplTrait>()?);
+                }
+                "derive" => {
+                    let content;
+                    parenthesized!(content in input);
+
+                    for derive in Punctuated::<Ident, Token![,]>::parse_terminated(&content)? {
+                        match derive.to_string().as_str() {
+                            "Default" => derive_default = true,
+                            "Clone" => derive_clone = true,
+                            "Debug" => derive_debug = true,
+                            _ => {
+                                return Err(syn::Error::new(
+                                    derive.span(),
+                                    "unsupported derive, expected one of: Default, Clone, Debug",
+                                ))
+                            }
+                        }
+                    }
+                }
+                "override" => {
+                    let content;
+                    parenthesized!(content in input);
+                    overrides.extend(Punctuated::<PropOverride, Token![,]>::parse_terminated(
+                        &content,
+                    )?);
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!(
+                            "unknown component argument, expected one of: {}",
+                            COMPONENT_ARGUMENTS.join(", ")
+                        ),
+                    ))
+                }
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        let fn_name = fn_name.ok_or_else(|| {
+            let message = "expected `render_fn = <path>`, naming the render function, i.e. `#[component(render_fn = my_cmp)]`";
+
+            // point at the arguments, or at the attribute itself when it has none
+            if args.is_empty() {
+                syn::Error::new(Span::call_site(), message)
+            } else {
+                syn::Error::new_spanned(&args, message)
+            }
+        })?;
+
+        if let Some(key) = apply_config.filter(|_| !apply) {
+            return Err(syn::Error::new(
+                key.span(),
+                format!("{key} configures the `apply` prop, which `apply = false` removes"),
+            ));
+        }
+
+        Ok(AttributeArgument {
+            fn_name,
+            variant_fns,
+            ssr_fn_name,
+            async_take,
+            delta,
+            signal_suffix,
+            extends,
+            extendable,
+            component_macro_path,
+            overrides,
+            wasm_export,
+            dyn_trait,
+            boxed,
+            clone_signals,
+            must_use,
+            with_helpers,
+            snapshot,
+            impl_traits,
+            arbitrary,
+            apply_after,
+            dynamic,
+            macro_module,
+            sealed,
+            signal_or_value,
+            from_json,
+            metadata,
+            max_size,
+            thread_safe,
+            macro_name,
+            props_name,
+            trait_name,
+            newtype,
+            prop_mask,
+            futures_signals_path,
+            dominator_path,
+            web_sys_path,
+            apply,
+            apply_element,
+            vis,
+            accessors,
+            derive_default,
+            derive_clone,
+            derive_debug,
+            builder,
+        })
     }
 }
+
 pub fn docs_from_attrs<'a>(attrs: impl Iterator<Item = &'a Attribute>) -> Vec<syn::Expr> {
     attrs
         .into_iter()
         .filter_map(|attr| {
-            if *attr.path().get_ident().unwrap() == "doc" {
+            if attr.path().is_ident("doc") {
                 if let Meta::NameValue(docstring) = &attr.meta {
                     Some(docstring.value.clone())
                 } else {
@@ -0,0 +1,99 @@
+use convert_case::{Case, Casing};
+use proc_macro2::Ident;
+use quote::quote;
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::{Field, Path, Token};
+
+/// A single `name: Event` entry of an `#[events(...)]` attribute
+struct EventDecl {
+    name: Ident,
+    event: Ident,
+}
+
+impl syn::parse::Parse for EventDecl {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let event = input.parse()?;
+
+        Ok(Self { name, event })
+    }
+}
+
+/// Replaces each `#[events(click: Click, ..)]` field with one `#[emits(dominator::events::Click)] on_click: _` field per event,
+/// naming dominator by the given path.
+/// The name of every event has to be the snake case name of its dominator event type, which catches typos in either.
+pub fn expand_events(
+    fields: Punctuated<Field, Token![,]>,
+    dominator: &Path,
+) -> syn::Result<Punctuated<Field, Token![,]>> {
+    let mut expanded = Punctuated::new();
+
+    for field in fields {
+        let Some(attr) = field.attrs.iter().find(|a| a.path().is_ident("events")) else {
+            expanded.push(field);
+            continue;
+        };
+
+        if !cfg!(feature = "dominator") {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "#[events] requires the `dominator` feature of futures-signals-component-macro",
+            ));
+        }
+
+        if !matches!(field.ty, syn::Type::Infer(_)) {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                "#[events] fields must have the type `_`, as they are replaced by a field per event",
+            ));
+        }
+
+        if let Some(other) = field
+            .attrs
+            .iter()
+            .find(|a| !a.path().is_ident("events") && !a.path().is_ident("doc"))
+        {
+            return Err(syn::Error::new_spanned(
+                other,
+                "#[events] can not be combined with other field attributes",
+            ));
+        }
+
+        let decls = attr.parse_args_with(Punctuated::<EventDecl, Token![,]>::parse_terminated)?;
+
+        if decls.is_empty() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "#[events] expects at least one event, i.e. `#[events(click: Click)]`",
+            ));
+        }
+
+        for decl in decls {
+            let expected = decl.event.to_string().to_case(Case::Snake);
+
+            if decl.name != expected {
+                return Err(syn::Error::new_spanned(
+                    &decl.name,
+                    format!(
+                        "event `{}` does not match its type `{}`, expected `{}`",
+                        decl.name, decl.event, expected
+                    ),
+                ));
+            }
+
+            let field_name = Ident::new(&format!("on_{}", decl.name), decl.name.span());
+            let event = &decl.event;
+            let doc = format!(" Handler of the `{}` events of the component", decl.name);
+
+            expanded.push(Field::parse_named.parse2(quote! {
+                #[doc = #doc]
+                #[emits(#dominator::events::#event)]
+                #field_name: _
+            })?);
+        }
+    }
+
+    Ok(expanded)
+}
@@ -1,43 +1,1032 @@
 use crate::get_type_generic_param_use;
 use crate::parse::{docs_from_attrs, Prop, PropGenerics, SignalType};
-use syn::Field;
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::punctuated::Punctuated;
+use syn::{
+    Expr, Field, GenericArgument, Lit, Meta, Pat, Path, PathArguments, Token, Type, TypeParamBound,
+    UnOp,
+};
 
-pub fn parse_field(field: &Field, struct_generics: &Vec<PropGenerics>) -> Prop {
-    let is_signal = field.attrs.iter().any(|a| a.path().is_ident("signal"));
+const SIGNAL_OPTIONS: &[&str] = &[
+    "as_stream",
+    "owned",
+    "fallback",
+    "on_change",
+    "bounds",
+    "default_none",
+    "map",
+    "shared",
+];
+
+/// The field attributes a field can have at most once.
+/// `#[signal]` and `#[signal_vec]` may be repeated, as the options of all of them are combined.
+const SINGLE_FIELD_ATTRIBUTES: &[&str] = &[
+    "binding",
+    "cfg_native",
+    "cfg_wasm",
+    "collect",
+    "cow",
+    "default",
+    "default_async",
+    "default_once",
+    "default_signal",
+    "deprecated_runtime",
+    "duration_ms",
+    "emits",
+    "intern",
+    "into",
+    "observable",
+    "rename",
+    "required",
+    "send",
+    "signal_ref",
+    "skip",
+    "slot",
+    "sync",
+    "theme",
+    "to_owned",
+    "try_into",
+    "validate",
+];
+
+/// The storage modes of a signal field, of which a field has at most one
+const SIGNAL_FIELD_ATTRIBUTES: &[&str] = &["signal", "signal_vec", "signal_ref"];
+
+/// The field attributes configuring the setters of a field, which `#[skip]` fields have none of
+const SETTER_FIELD_ATTRIBUTES: &[&str] = &[
+    "collect",
+    "deprecated_runtime",
+    "duration_ms",
+    "into",
+    "rename",
+    "to_owned",
+    "try_into",
+    "validate",
+];
+
+/// Rejects conflicting field attributes with an error pointing at the attribute in conflict, rather than letting one of
+/// them win silently
+fn check_field_attributes(field: &Field) -> syn::Result<()> {
+    let find = |name: &str| field.attrs.iter().find(|a| a.path().is_ident(name));
+
+    for name in SINGLE_FIELD_ATTRIBUTES {
+        if let Some(duplicate) = field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident(name))
+            .nth(1)
+        {
+            return Err(syn::Error::new_spanned(
+                duplicate,
+                format!("duplicate #[{}] attribute, a field can only have one", name),
+            ));
+        }
+    }
+
+    let mut signal_attrs = SIGNAL_FIELD_ATTRIBUTES
+        .iter()
+        .filter_map(|name| find(name).map(|attr| (name, attr)));
+
+    if let (Some((first, _)), Some((second, attr))) = (signal_attrs.next(), signal_attrs.next()) {
+        return Err(syn::Error::new_spanned(
+            attr,
+            format!(
+                "#[{}] can not be combined with #[{}], a field is stored as one kind of signal",
+                second, first
+            ),
+        ));
+    }
+
+    if find("skip").is_some() {
+        if let Some((name, attr)) = SETTER_FIELD_ATTRIBUTES
+            .iter()
+            .find_map(|name| find(name).map(|attr| (name, attr)))
+        {
+            return Err(syn::Error::new_spanned(
+                attr,
+                format!(
+                    "#[{}] configures the setters of the field, which #[skip] leaves out",
+                    name
+                ),
+            ));
+        }
+    }
+
+    // renamed setters must be valid identifiers, which is reported at the given name
+    if let Some(Meta::NameValue(syn::MetaNameValue {
+        value: Expr::Lit(syn::ExprLit {
+            lit: Lit::Str(name),
+            ..
+        }),
+        ..
+    })) = find("rename").map(|a| &a.meta)
+    {
+        if name.parse::<syn::Ident>().is_err() {
+            return Err(syn::Error::new_spanned(
+                name,
+                format!("`{}` is not a valid identifier", name.value()),
+            ));
+        }
+    }
+
+    // a default signal replaces the default value of the field, so the two can not both be given
+    if let (Some(attr), Some(_)) = (find("default_signal"), find("default")) {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "#[default_signal] can not be combined with #[default], as it is the default of the field",
+        ));
+    }
+
+    if let (Some(attr), Some(_)) = (find("default_async"), find("default")) {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "#[default_async] can not be combined with #[default], as it is the default of the field",
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn parse_field(
+    field: &Field,
+    struct_generics: &Vec<PropGenerics>,
+    dominator: &Path,
+    futures_signals: &Path,
+) -> syn::Result<Prop> {
+    check_field_attributes(field)?;
+
+    // `#[signal_ref]` fields are signals stored in a `Broadcaster`, which the render fn borrows the values of
+    let signal_ref = field.attrs.iter().any(|a| a.path().is_ident("signal_ref"));
+
+    let is_signal = signal_ref || field.attrs.iter().any(|a| a.path().is_ident("signal"));
     let is_signal_vec = field.attrs.iter().any(|a| a.path().is_ident("signal_vec"));
     let is_send = field.attrs.iter().any(|a| a.path().is_ident("send"));
+    let is_sync = field.attrs.iter().any(|a| a.path().is_ident("sync"));
+
+    // errors are reported at the field attribute they are about
+    let attr = |name: &str| field.attrs.iter().find(|a| a.path().is_ident(name));
+    let attr_error = |name: &str, message: &str| match attr(name) {
+        Some(attr) => syn::Error::new_spanned(attr, message),
+        None => syn::Error::new_spanned(field, message),
+    };
+
+    if is_sync && !is_signal && !is_signal_vec {
+        return Err(attr_error(
+            "sync",
+            "#[sync] can only be used on #[signal] and #[signal_vec] fields",
+        ));
+    }
+
+    let signal_options = field
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("signal") || a.path().is_ident("signal_vec"))
+        .filter(|a| matches!(a.meta, Meta::List(_)))
+        .map(|a| a.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated))
+        .collect::<syn::Result<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+    if let Some(option) = signal_options.iter().find(|option| {
+        !SIGNAL_OPTIONS
+            .iter()
+            .any(|known| option.path().is_ident(known))
+    }) {
+        return Err(syn::Error::new_spanned(
+            option.path(),
+            format!(
+                "unknown signal option, expected one of: {}",
+                SIGNAL_OPTIONS.join(", ")
+            ),
+        ));
+    }
+
+    let signal_option = |name: &str| {
+        signal_options
+            .iter()
+            .find(|option| option.path().is_ident(name))
+    };
+
+    let as_stream = signal_option("as_stream").is_some();
+    let owned = signal_option("owned").is_some();
+
+    let fallback = signal_option("fallback")
+        .map(|option| match option {
+            Meta::NameValue(fallback) => Ok(fallback.value.clone()),
+            _ => Err(syn::Error::new_spanned(
+                option,
+                "the fallback signal option expects a value, i.e. `#[signal(fallback = expr)]`",
+            )),
+        })
+        .transpose()?;
+
+    let on_change = signal_option("on_change")
+        .map(|option| match option {
+            Meta::NameValue(on_change) => Ok(on_change.value.clone()),
+            _ => Err(syn::Error::new_spanned(
+                option,
+                "the on_change signal option expects a callback, i.e. `#[signal(on_change = callback)]`",
+            )),
+        })
+        .transpose()?;
+
+    // `bounds = "Unpin + 'static"` adds bounds to the signal type param of the field, on top of the ones of `owned`, `#[send]` and `#[sync]`
+    let signal_bounds = signal_option("bounds")
+        .map(|option| match option {
+            Meta::NameValue(syn::MetaNameValue {
+                value:
+                    Expr::Lit(syn::ExprLit {
+                        lit: Lit::Str(bounds),
+                        ..
+                    }),
+                ..
+            }) => syn::parse::Parser::parse_str(
+                Punctuated::<TypeParamBound, Token![+]>::parse_terminated,
+                bounds.value().trim_start().trim_start_matches('+'),
+            )
+            .map(|bounds| bounds.into_iter().collect())
+            .map_err(|error| {
+                syn::Error::new_spanned(
+                    bounds,
+                    format!("failed to parse the bounds of the signal: {}", error),
+                )
+            }),
+            _ => Err(syn::Error::new_spanned(
+                option,
+                "the bounds signal option expects a string of bounds, i.e. `#[signal(bounds = \"Unpin + 'static\")]`",
+            )),
+        })
+        .transpose()?
+        .unwrap_or_default();
+
+    if let Some(option) = signal_option("on_change").filter(|_| is_signal_vec) {
+        return Err(syn::Error::new_spanned(
+            option,
+            "on_change can only be used with #[signal]",
+        ));
+    }
+
+    if let Some(option) = signal_option("fallback").filter(|_| is_signal_vec) {
+        return Err(syn::Error::new_spanned(
+            option,
+            "fallback can only be used with #[signal], as signal vecs have no pending value",
+        ));
+    }
 
     let default = field
         .attrs
         .iter()
         .find(|a| a.path().is_ident("default"))
-        .map(|a| {
-            a.parse_args::<syn::Expr>()
-                .expect("failed to parse default value")
-        });
+        .map(|a| a.parse_args::<syn::Expr>())
+        .transpose()?;
+
+    // `#[default_once(expr)]` defaults are evaluated once per process, and cloned for every props value
+    let default_once_expr = field
+        .attrs
+        .iter()
+        .find(|a| a.path().is_ident("default_once"))
+        .map(|a| a.parse_args::<syn::Expr>())
+        .transpose()?;
+    let default_once = default_once_expr.is_some();
+
+    if default_once && default.is_some() {
+        return Err(attr_error(
+            "default_once",
+            "field cannot have both #[default] and #[default_once]",
+        ));
+    }
+
+    // `#[default_signal(expr)]` defaults are signals, stored boxed rather than wrapped in `always()`
+    let default_signal_expr = field
+        .attrs
+        .iter()
+        .find(|a| a.path().is_ident("default_signal"))
+        .map(|a| a.parse_args::<syn::Expr>())
+        .transpose()?;
+    let default_signal = default_signal_expr.is_some();
+
+    if default_signal {
+        if !is_signal || is_signal_vec {
+            return Err(attr_error(
+                "default_signal",
+                "#[default_signal] can only be used on #[signal] fields",
+            ));
+        }
+
+        if default_once || fallback.is_some() {
+            return Err(attr_error(
+                "default_signal",
+                "#[default_signal] can not be combined with #[default_once] or a fallback",
+            ));
+        }
+    }
+
+    // `#[default_async(expr)]` defaults are futures, whose signal is boxed like a default signal, and shows the fallback
+    // until the future resolves
+    let default_async_expr = field
+        .attrs
+        .iter()
+        .find(|a| a.path().is_ident("default_async"))
+        .map(|a| a.parse_args::<syn::Expr>())
+        .transpose()?;
+    let default_async = default_async_expr.is_some();
+
+    if default_async {
+        if !is_signal || is_signal_vec {
+            return Err(attr_error(
+                "default_async",
+                "#[default_async] can only be used on #[signal] fields",
+            ));
+        }
+
+        if fallback.is_none() {
+            return Err(attr_error(
+                "default_async",
+                "#[default_async] needs a fallback to show until the future resolves, i.e. `#[signal(fallback = expr)]`",
+            ));
+        }
 
-    if is_signal && is_signal_vec {
-        panic!("field cannot be both signal and signal_vec");
+        if default_once || default_signal {
+            return Err(attr_error(
+                "default_async",
+                "#[default_async] can not be combined with #[default_once] or #[default_signal]",
+            ));
+        }
+    }
+
+    // `#[cfg_native(default = expr)]` and `#[cfg_wasm(default = expr)]` override the default on their target, with
+    // `#[default]` as the default of a target without one
+    let target_default = |name: &str| {
+        field
+            .attrs
+            .iter()
+            .find(|a| a.path().is_ident(name))
+            .map(|a| match a.parse_args::<syn::MetaNameValue>() {
+                Ok(arg) if arg.path.is_ident("default") => Ok(arg.value),
+                _ => Err(syn::Error::new_spanned(
+                    a,
+                    format!(
+                        "#[{0}] expects a default value, i.e. `#[{0}(default = expr)]`",
+                        name
+                    ),
+                )),
+            })
+            .transpose()
+    };
+    let native_default = target_default("cfg_native")?;
+    let wasm_default = target_default("cfg_wasm")?;
+
+    if let Some(target_attr) = attr("cfg_native").or_else(|| attr("cfg_wasm")) {
+        if default_once || default_signal || default_async || fallback.is_some() {
+            return Err(syn::Error::new_spanned(
+                target_attr,
+                "#[cfg_native] and #[cfg_wasm] can not be combined with #[default_once], #[default_signal], #[default_async] or a fallback",
+            ));
+        }
+
+        if (native_default.is_none() || wasm_default.is_none()) && default.is_none() {
+            return Err(syn::Error::new_spanned(
+                target_attr,
+                "a field with a #[cfg_native] or #[cfg_wasm] default needs a default on both targets, either from both attributes or from #[default]",
+            ));
+        }
+    }
+
+    let default = if native_default.is_some() || wasm_default.is_some() {
+        // a target without its own default has the `#[default]` of the field, which was checked above
+        let native = native_default.or_else(|| default.clone());
+        let wasm = wasm_default.or_else(|| default.clone());
+
+        Some(syn::parse_quote! {{
+            #[cfg(not(target_arch = "wasm32"))]
+            let default = #native;
+            #[cfg(target_arch = "wasm32")]
+            let default = #wasm;
+            default
+        }})
+    } else {
+        default
+    };
+
+    // `#[signal(default_none)]` fields of an `Option<T>` type default to an `always(None)` signal rather than wrapping
+    // the signal in another `Option` like other fields without a default
+    let default_none = signal_option("default_none");
+
+    if let Some(option) = default_none.filter(|_| is_signal_vec || signal_ref) {
+        return Err(syn::Error::new_spanned(
+            option,
+            "default_none can only be used with #[signal]",
+        ));
     }
 
+    if let Some(option) = default_none.filter(|_| !is_option_type(&field.ty)) {
+        return Err(syn::Error::new_spanned(
+            option,
+            "default_none can only be used on fields of an `Option` type",
+        ));
+    }
+
+    let option_default = default_none.map(|_| syn::parse_quote!(None));
+
+    let default = default
+        .or(default_once_expr)
+        .or(default_signal_expr)
+        .or(default_async_expr)
+        .or_else(|| fallback.clone())
+        .or(option_default);
+
     // Extract generics from field, if any, and make sure they are matched exactly once to the structs generics
     let field_generics = get_type_generic_param_use(&field.ty, struct_generics);
 
     if field_generics.len() > 1 {
-        panic!("field must have at most one generic param");
+        return Err(syn::Error::new_spanned(
+            &field.ty,
+            "field must have at most one generic param",
+        ));
     }
 
-    let generics = field_generics.first().map(|generic| {
-        if struct_generics.iter().filter(|g| g == &generic).count() != 1 {
-            panic!("field generic param must match exactly one struct generic param");
-        }
+    let generics = field_generics
+        .first()
+        .map(|generic| {
+            if struct_generics.iter().filter(|g| g == &generic).count() != 1 {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "field generic param must match exactly one struct generic param",
+                ));
+            }
+
+            Ok(generic.clone())
+        })
+        .transpose()?;
+
+    if let Some(option) = signal_option("on_change").filter(|_| generics.is_some()) {
+        return Err(syn::Error::new_spanned(
+            option,
+            "on_change can not be used on fields with a generic type",
+        ));
+    }
+
+    if default_signal && generics.is_some() {
+        return Err(attr_error(
+            "default_signal",
+            "#[default_signal] can not be used on fields with a generic type",
+        ));
+    }
+
+    if default_async && generics.is_some() {
+        return Err(attr_error(
+            "default_async",
+            "#[default_async] can not be used on fields with a generic type",
+        ));
+    }
 
-        generic.clone()
-    });
+    if let Some(option) = signal_option("fallback").filter(|_| generics.is_some()) {
+        return Err(syn::Error::new_spanned(
+            option,
+            "fallback can not be used on fields with a generic type, as the fallback value has a fixed type",
+        ));
+    }
 
     let field_docs = docs_from_attrs(field.attrs.iter());
 
-    Prop {
+    // `#[cow]` string fields are stored as a `Cow`, with setters accepting anything convertible into it
+    let is_cow = field.attrs.iter().any(|a| a.path().is_ident("cow"));
+
+    // `map = |v: In| ...` makes the setters accept signals and values of `In`, which are mapped into the field type
+    let map = signal_option("map")
+        .map(|option| {
+            let closure = match option {
+                Meta::NameValue(syn::MetaNameValue {
+                    value: Expr::Closure(closure),
+                    ..
+                }) if closure.inputs.len() == 1 => closure,
+                _ => return Err(syn::Error::new_spanned(
+                    option,
+                    "the map signal option expects a closure, i.e. `#[signal(map = |v: bool| v.to_string())]`",
+                )),
+            };
+
+            let Some(Pat::Type(input)) = closure.inputs.first() else {
+                return Err(syn::Error::new_spanned(
+                    &closure.inputs,
+                    "the argument of the map closure must have a type, as it is the item type of the accepted signals",
+                ));
+            };
+
+            Ok(((*input.ty).clone(), Expr::Closure(closure.clone())))
+        })
+        .transpose()?;
+
+    if let Some(option) = signal_option("map") {
+        if is_signal_vec {
+            return Err(syn::Error::new_spanned(
+                option,
+                "map can only be used with #[signal]",
+            ));
+        }
+
+        if generics.is_some() {
+            return Err(syn::Error::new_spanned(
+                option,
+                "map can not be used on fields with a generic type",
+            ));
+        }
+
+        if fallback.is_some()
+            || default_once
+            || default_signal
+            || default_async
+            || is_cow
+            || field.attrs.iter().any(|a| a.path().is_ident("try_into"))
+        {
+            return Err(syn::Error::new_spanned(
+                option,
+                "map can not be combined with a fallback, #[default_once], #[default_signal], #[default_async], #[cow] or #[try_into]",
+            ));
+        }
+    }
+
+    // `shared` makes the value setter of an `Arc<T>` or `Rc<T>` field accept a `T`, wrapping it in a new pointer
+    let shared = signal_option("shared").is_some();
+
+    if let Some(option) = signal_option("shared") {
+        if is_signal_vec || signal_ref {
+            return Err(syn::Error::new_spanned(
+                option,
+                "shared can only be used with #[signal]",
+            ));
+        }
+
+        if !is_wrapper_type(&field.ty, &["Arc", "Rc"]) {
+            return Err(syn::Error::new_spanned(
+                option,
+                "the shared signal option requires a field of type `Arc<T>` or `Rc<T>`",
+            ));
+        }
+
+        if map.is_some() || field.attrs.iter().any(|a| a.path().is_ident("try_into")) {
+            return Err(syn::Error::new_spanned(
+                option,
+                "shared can not be combined with map or #[try_into]",
+            ));
+        }
+    }
+
+    // `#[emits(Event)]` fields are event sinks, stored as a shared callback taking the event
+    let emits = field
+        .attrs
+        .iter()
+        .find(|a| a.path().is_ident("emits"))
+        .map(|a| a.parse_args::<Type>())
+        .transpose()?;
+
+    if let Some(event) = &emits {
+        if !matches!(field.ty, Type::Infer(_)) {
+            return Err(attr_error(
+                "emits",
+                "#[emits] fields must have the type `_`, as it is derived from the event type",
+            ));
+        }
+
+        if is_signal || is_signal_vec {
+            return Err(attr_error("emits", "#[emits] fields can not be signals"));
+        }
+
+        if !get_type_generic_param_use(event, struct_generics).is_empty() {
+            return Err(attr_error(
+                "emits",
+                "#[emits] event types can not use generic params of the component",
+            ));
+        }
+    }
+
+    let try_into = field.attrs.iter().any(|a| a.path().is_ident("try_into"));
+
+    if try_into {
+        if is_signal_vec || is_cow || emits.is_some() {
+            return Err(attr_error(
+                "try_into",
+                "#[try_into] can not be combined with #[signal_vec], #[cow] or #[emits]",
+            ));
+        }
+
+        if generics.is_some() {
+            return Err(attr_error(
+                "try_into",
+                "#[try_into] can not be used on fields with a generic type",
+            ));
+        }
+    }
+
+    // `#[binding]` fields are two-way bindings, stored as the `Mutable` shared with the caller
+    let binding = field.attrs.iter().any(|a| a.path().is_ident("binding"));
+
+    if binding {
+        if is_signal || is_signal_vec || is_cow || try_into || emits.is_some() {
+            return Err(attr_error("binding", "#[binding] can not be combined with #[signal], #[signal_vec], #[cow], #[try_into] or #[emits]"));
+        }
+
+        if generics.is_some() {
+            return Err(attr_error(
+                "binding",
+                "#[binding] can not be used on fields with a generic type",
+            ));
+        }
+    }
+
+    // `#[intern]` string fields share their allocation with equal strings set on other props of the component
+    let intern = field.attrs.iter().any(|a| a.path().is_ident("intern"));
+
+    if intern {
+        if !cfg!(feature = "intern") {
+            return Err(attr_error(
+                "intern",
+                "#[intern] requires the `intern` feature of futures-signals-component-macro",
+            ));
+        }
+
+        if is_signal || is_signal_vec || is_cow || try_into || emits.is_some() || binding {
+            return Err(attr_error("intern", "#[intern] can not be combined with #[signal], #[signal_vec], #[cow], #[try_into], #[emits] or #[binding]"));
+        }
+
+        if !matches!(&field.ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("String"))
+        {
+            return Err(attr_error(
+                "intern",
+                "#[intern] can only be used on String fields",
+            ));
+        }
+    }
+
+    // `#[collect]` fields are `Vec`s, whose setter collects an iterator
+    let collect = attr("collect")
+        .map(|a| {
+            if is_signal || is_signal_vec || try_into || emits.is_some() || binding {
+                return Err(syn::Error::new_spanned(a, "#[collect] can not be combined with #[signal], #[signal_vec], #[try_into], #[emits] or #[binding]"));
+            }
+
+            if generics.is_some() {
+                return Err(syn::Error::new_spanned(a, "#[collect] can not be used on fields with a generic type"));
+            }
+
+            vec_item_type(&field.ty)
+                .ok_or_else(|| syn::Error::new_spanned(a, "#[collect] can only be used on Vec fields"))
+        })
+        .transpose()?;
+
+    // `#[slot]` fields hold an element of the framework, which is a `Dom` unless the type is given
+    let slot = field.attrs.iter().any(|a| a.path().is_ident("slot"));
+
+    if slot {
+        if !cfg!(feature = "dominator") {
+            return Err(attr_error(
+                "slot",
+                "#[slot] requires the `dominator` feature of futures-signals-component-macro",
+            ));
+        }
+
+        if is_signal
+            || is_signal_vec
+            || is_cow
+            || try_into
+            || emits.is_some()
+            || binding
+            || intern
+            || collect.is_some()
+        {
+            return Err(attr_error(
+                "slot",
+                "#[slot] can not be combined with other field attributes than #[default]",
+            ));
+        }
+
+        if generics.is_some() {
+            return Err(attr_error("slot", "#[slot] can not be used on fields with a generic type, use a generic field instead"));
+        }
+    }
+
+    // `#[required]` fields have no default, and the props only implement the props trait once they are set
+    let required_attr = field.attrs.iter().find(|a| a.path().is_ident("required"));
+    let required = required_attr.is_some();
+
+    if let Some(attr) = required_attr {
+        if default.is_some() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "#[required] fields can not have a default",
+            ));
+        }
+
+        if is_signal || is_signal_vec || binding {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "#[required] can not be combined with #[signal], #[signal_vec] or #[binding]",
+            ));
+        }
+
+        if generics.is_some() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "#[required] can not be used on fields with a generic type, as setting them already changes the type of the props",
+            ));
+        }
+    }
+
+    // `#[skip]` fields have no setters, so they always hold their default
+    let skip = field.attrs.iter().any(|a| a.path().is_ident("skip"));
+
+    if skip {
+        if default.is_none() {
+            return Err(attr_error(
+                "skip",
+                "#[skip] fields must have a #[default], as they can not be set",
+            ));
+        }
+
+        if required {
+            return Err(attr_error(
+                "skip",
+                "#[skip] can not be combined with #[required], as skipped fields can not be set",
+            ));
+        }
+    }
+
+    // `#[theme]` signals are applied to the element of the component as its `data-theme` attribute
+    let theme = field.attrs.iter().any(|a| a.path().is_ident("theme"));
+
+    if theme {
+        if !cfg!(feature = "dominator") {
+            return Err(attr_error(
+                "theme",
+                "#[theme] requires the `dominator` feature of futures-signals-component-macro",
+            ));
+        }
+
+        if !is_signal || as_stream {
+            return Err(attr_error(
+                "theme",
+                "#[theme] can only be used on #[signal] fields, which are not streams",
+            ));
+        }
+
+        if default.is_none() {
+            return Err(attr_error(
+                "theme",
+                "#[theme] fields must have a #[default] theme, as the element always has one",
+            ));
+        }
+    }
+
+    // `#[duration_ms]` fields get a `{field}_ms` setter, taking the duration in milliseconds
+    let duration_ms = field.attrs.iter().any(|a| a.path().is_ident("duration_ms"));
+
+    if duration_ms {
+        if is_signal || is_signal_vec || try_into || emits.is_some() || binding {
+            return Err(attr_error("duration_ms", "#[duration_ms] can not be combined with #[signal], #[signal_vec], #[try_into], #[emits] or #[binding]"));
+        }
+
+        if !matches!(&field.ty, Type::Path(path) if path.qself.is_none() && path.path.segments.last().is_some_and(|s| s.ident == "Duration"))
+        {
+            return Err(attr_error(
+                "duration_ms",
+                "#[duration_ms] can only be used on Duration fields",
+            ));
+        }
+    }
+
+    if default_once {
+        if generics.is_some() {
+            return Err(attr_error("default_once", "#[default_once] can not be used on fields with a generic type, as the default is stored in a static"));
+        }
+
+        if is_cow || intern || binding || emits.is_some() {
+            return Err(attr_error("default_once", "#[default_once] can not be combined with #[cow], #[intern], #[binding] or #[emits]"));
+        }
+    }
+
+    // `#[into]` value setters accept anything convertible into the field type, while the default is used as written
+    let into = field.attrs.iter().any(|a| a.path().is_ident("into"));
+
+    if into {
+        if is_cow || try_into || emits.is_some() || binding || intern || collect.is_some() {
+            return Err(attr_error("into", "#[into] can not be combined with #[cow], #[try_into], #[emits], #[binding], #[intern] or #[collect], which convert their values already"));
+        }
+
+        if generics.is_some() {
+            return Err(attr_error("into", "#[into] can not be used on fields with a generic type, as the type to convert into would be ambiguous"));
+        }
+    }
+
+    // `#[to_owned]` value setters accept anything referencing the borrowed form of the field type, and store an owned copy
+    let to_owned = field
+        .attrs
+        .iter()
+        .find(|a| a.path().is_ident("to_owned"))
+        .map(|a| {
+            if is_signal
+                || is_signal_vec
+                || is_cow
+                || into
+                || try_into
+                || emits.is_some()
+                || binding
+                || intern
+                || collect.is_some()
+                || duration_ms
+            {
+                return Err(syn::Error::new_spanned(a, "#[to_owned] can not be combined with #[signal], #[signal_vec], #[cow], #[into], #[try_into], #[emits], #[binding], #[intern], #[collect] or #[duration_ms]"));
+            }
+
+            if generics.is_some() {
+                return Err(syn::Error::new_spanned(a, "#[to_owned] can not be used on fields with a generic type"));
+            }
+
+            match &a.meta {
+                Meta::List(_) => a.parse_args::<Type>().map_err(|_| {
+                    syn::Error::new_spanned(
+                        a,
+                        "#[to_owned] expects the borrowed type, i.e. `#[to_owned(str)]`",
+                    )
+                }),
+                _ => borrowed_type(&field.ty).ok_or_else(|| {
+                    syn::Error::new_spanned(a, "the borrowed form of the field type is not known, pass it to #[to_owned], i.e. `#[to_owned(str)]`")
+                }),
+            }
+        })
+        .transpose()?;
+
+    // `#[validate(path)]` fields check the values passed to their value setter in debug builds
+    let validate = field
+        .attrs
+        .iter()
+        .find(|a| a.path().is_ident("validate"))
+        .map(|a| {
+            if is_signal
+                || is_signal_vec
+                || is_cow
+                || into
+                || emits.is_some()
+                || binding
+                || intern
+                || collect.is_some()
+                || to_owned.is_some()
+                || slot
+                || skip
+            {
+                return Err(syn::Error::new_spanned(a, "#[validate] can not be combined with #[signal], #[signal_vec], #[cow], #[into], #[emits], #[binding], #[intern], #[collect], #[to_owned], #[slot] or #[skip]"));
+            }
+
+            a.parse_args::<Path>().map_err(|_| {
+                syn::Error::new_spanned(
+                    a,
+                    "#[validate] expects the path of a validator, i.e. `#[validate(validate_label)]`",
+                )
+            })
+        })
+        .transpose()?;
+
+    // `#[observable]` fields are stored as a `Mutable` created from the value passed to their setter, which the render
+    // fn can both observe and update
+    let observable = field.attrs.iter().any(|a| a.path().is_ident("observable"));
+
+    if observable {
+        if is_signal
+            || is_signal_vec
+            || is_cow
+            || into
+            || try_into
+            || emits.is_some()
+            || binding
+            || intern
+            || collect.is_some()
+            || to_owned.is_some()
+            || duration_ms
+            || slot
+            || required
+        {
+            return Err(attr_error("observable", "#[observable] can not be combined with #[signal], #[signal_vec], #[cow], #[into], #[try_into], #[emits], #[binding], #[intern], #[collect], #[to_owned], #[duration_ms], #[slot] or #[required]"));
+        }
+
+        if generics.is_some() {
+            return Err(attr_error(
+                "observable",
+                "#[observable] can not be used on fields with a generic type",
+            ));
+        }
+
+        if default_once {
+            return Err(attr_error("observable", "#[observable] can not be combined with #[default_once], as every props value needs a `Mutable` of its own"));
+        }
+    }
+
+    // `#[rename = "name"]` renames the setters of the field, which keeps its name on the props struct
+    let rename = field
+        .attrs
+        .iter()
+        .find(|a| a.path().is_ident("rename"))
+        .map(|a| match &a.meta {
+            Meta::NameValue(syn::MetaNameValue {
+                value:
+                    Expr::Lit(syn::ExprLit {
+                        lit: Lit::Str(name),
+                        ..
+                    }),
+                ..
+            }) => name.parse::<syn::Ident>(),
+            _ => Err(syn::Error::new_spanned(
+                a,
+                "#[rename] expects the name of the setter, i.e. `#[rename = \"ty\"]`",
+            )),
+        })
+        .transpose()?;
+
+    let deprecated_runtime = field
+        .attrs
+        .iter()
+        .find(|a| a.path().is_ident("deprecated_runtime"))
+        .map(|a| match &a.meta {
+            Meta::NameValue(message) => Ok(message.value.clone()),
+            _ => Err(syn::Error::new_spanned(a, "#[deprecated_runtime] expects a message, i.e. `#[deprecated_runtime = \"use foo instead\"]`")),
+        })
+        .transpose()?;
+
+    if deprecated_runtime.is_some() && !cfg!(feature = "log") {
+        return Err(attr_error(
+            "deprecated_runtime",
+            "#[deprecated_runtime] requires the `log` feature of futures-signals-component-macro",
+        ));
+    }
+
+    let deprecated = field
+        .attrs
+        .iter()
+        .find(|a| a.path().is_ident("deprecated"))
+        .cloned();
+
+    // defaults naming generic params, i.e. `T::default()`, are evaluated by a function generic over the component params
+    let generic_default = default
+        .as_ref()
+        .is_some_and(|default| tokens_use_generics(default.to_token_stream(), struct_generics));
+
+    if generic_default && (default_once || is_cow || intern) {
+        return Err(attr_error("default", "defaults using generic params can not be combined with #[default_once], #[cow] or #[intern]"));
+    }
+
+    if generic_default && default_async {
+        return Err(attr_error(
+            "default_async",
+            "#[default_async] futures can not use generic params of the component",
+        ));
+    }
+
+    let type_ = if let Some(event) = &emits {
+        syn::parse_quote!(::std::rc::Rc<dyn Fn(#event)>)
+    } else if slot && matches!(field.ty, Type::Infer(_)) {
+        syn::parse_quote!(#dominator::Dom)
+    } else if matches!(field.ty, Type::Infer(_)) {
+        default
+            .as_ref()
+            .and_then(infer_type_from_default)
+            .ok_or_else(|| {
+                syn::Error::new_spanned(
+                    &field.ty,
+                    format!(
+                        "the type of field `{}` can not be inferred, use a `#[default]` with a suffixed literal (i.e. `0i32`), or an explicit type",
+                        field.ident.as_ref().expect("field must have name")
+                    ),
+                )
+            })?
+    } else if intern {
+        syn::parse_quote!(::std::sync::Arc<str>)
+    } else if is_cow {
+        if !matches!(&field.ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("String"))
+        {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                "#[cow] can only be used on String fields",
+            ));
+        }
+
+        syn::parse_quote!(::std::borrow::Cow<'static, str>)
+    } else {
+        field.ty.clone()
+    };
+
+    let observable = observable.then(|| type_.clone());
+
+    let (type_, default) = if binding || observable.is_some() {
+        (
+            syn::parse_quote!(#futures_signals::signal::Mutable<#type_>),
+            default
+                .map(|default| syn::parse_quote!(#futures_signals::signal::Mutable::new(#default))),
+        )
+    } else {
+        (type_, default)
+    };
+
+    Ok(Prop {
         is_signal: if is_signal {
             Some(SignalType::Item)
         } else if is_signal_vec {
@@ -46,10 +1035,137 @@ pub fn parse_field(field: &Field, struct_generics: &Vec<PropGenerics>) -> Prop {
             None
         },
         is_send,
+        is_sync,
+        as_stream,
+        owned,
+        signal_bounds,
+        broadcast: signal_ref,
+        signal_ref,
         name: field.ident.clone().expect("field must have name"),
         generics,
-        type_: field.ty.clone(),
+        type_,
         default,
+        // signal items can not be converted by the signal setter, so `#[into]` only changes plain setters, and the value
+        // setter of `#[signal_vec]` fields, which converts each item. `#[cow]` and `shared` fields convert the value of
+        // their value setter, as a `Cow` and an `Arc<T>` or `Rc<T>` can be created from the plain value
+        into: is_cow || shared || (into && !is_signal),
+        cow: is_cow,
+        emits,
+        try_into,
+        fallback,
+        map,
+        on_change,
+        binding,
+        observable,
+        intern,
+        slot,
+        collect,
+        required,
+        skip,
+        theme,
+        duration_ms,
+        to_owned,
+        validate,
+        rename,
+        default_once,
+        default_signal,
+        default_async,
+        generic_default,
+        deprecated_runtime,
+        deprecated,
+        injected: false,
         docs: field_docs,
+    })
+}
+
+/// Whether the tokens name any of the generic params of the component
+fn tokens_use_generics(tokens: TokenStream, struct_generics: &[PropGenerics]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => struct_generics
+            .iter()
+            .any(|generic| generic.param.ident == ident),
+        TokenTree::Group(group) => tokens_use_generics(group.stream(), struct_generics),
+        _ => false,
+    })
+}
+
+/// Infers the type of a `_` field from its default, which must be a literal with an unambiguous type
+fn infer_type_from_default(default: &Expr) -> Option<Type> {
+    match default {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Int(int) if !int.suffix().is_empty() => syn::parse_str(int.suffix()).ok(),
+            Lit::Float(float) if !float.suffix().is_empty() => syn::parse_str(float.suffix()).ok(),
+            Lit::Bool(_) => Some(syn::parse_quote!(bool)),
+            Lit::Char(_) => Some(syn::parse_quote!(char)),
+            Lit::Str(_) => Some(syn::parse_quote!(&'static str)),
+            _ => None,
+        },
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => {
+            infer_type_from_default(&unary.expr)
+        }
+        Expr::Paren(paren) => infer_type_from_default(&paren.expr),
+        _ => None,
+    }
+}
+
+/// The item type of a `Vec<T>` type
+fn vec_item_type(type_: &Type) -> Option<Type> {
+    let Type::Path(path) = type_ else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+
+    if path.qself.is_some() || segment.ident != "Vec" {
+        return None;
     }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match args.args.first()? {
+            GenericArgument::Type(item) => Some(item.clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The borrowed form of the std owned types, which `#[to_owned]` setters accept references to
+fn borrowed_type(type_: &Type) -> Option<Type> {
+    if let Some(item) = vec_item_type(type_) {
+        return Some(syn::parse_quote!([#item]));
+    }
+
+    let Type::Path(path) = type_ else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+
+    if path.qself.is_some() || !segment.arguments.is_none() {
+        return None;
+    }
+
+    match segment.ident.to_string().as_str() {
+        "String" => Some(syn::parse_quote!(str)),
+        "PathBuf" => Some(syn::parse_quote!(::std::path::Path)),
+        "OsString" => Some(syn::parse_quote!(::std::ffi::OsStr)),
+        "CString" => Some(syn::parse_quote!(::std::ffi::CStr)),
+        _ => None,
+    }
+}
+
+/// Whether the type is an `Option<T>`
+fn is_option_type(type_: &Type) -> bool {
+    is_wrapper_type(type_, &["Option"])
+}
+
+/// Whether the type is one of the named wrappers of a single type, i.e. `Arc<T>` for `["Arc", "Rc"]`
+fn is_wrapper_type(type_: &Type, wrappers: &[&str]) -> bool {
+    let Type::Path(path) = type_ else {
+        return false;
+    };
+
+    path.qself.is_none()
+        && path.path.segments.last().is_some_and(|segment| {
+            wrappers.iter().any(|wrapper| segment.ident == wrapper)
+                && matches!(&segment.arguments, PathArguments::AngleBracketed(args) if args.args.len() == 1)
+        })
 }
@@ -1,35 +1,210 @@
+#[cfg(feature = "dominator")]
+pub mod render_apply_timing;
 pub mod render_component_macro;
+#[cfg(feature = "intern")]
+pub mod render_interner;
 pub mod render_prop_impl;
+pub mod render_props_accessors;
+#[cfg(feature = "proptest")]
+pub mod render_props_arbitrary;
+pub mod render_props_boxed;
 pub mod render_props_builder_struct;
+pub mod render_props_clone;
+pub mod render_props_debug;
+pub mod render_props_defaults;
+pub mod render_props_delta;
+pub mod render_props_dyn_trait;
+pub mod render_props_dynamic;
+pub mod render_props_eq;
+#[cfg(feature = "serde")]
+pub mod render_props_from_json;
+pub mod render_props_impl_trait;
+pub mod render_props_loading;
+pub mod render_props_mask;
+pub mod render_props_max_size;
+pub mod render_props_metadata;
+pub mod render_props_newtype;
+pub mod render_props_snapshot;
+#[cfg(feature = "dominator")]
+pub mod render_props_theme;
+pub mod render_props_with_helpers;
+pub mod render_signal_or_value;
 pub mod render_utils;
+#[cfg(feature = "wasm")]
+pub mod render_wasm_export;
 
 use crate::parse::Component;
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Expr;
 
-use crate::render::render_component_macro::render_component_macro;
+use crate::render::render_component_macro::{
+    render_component_macro, render_extend_macro, render_macro_module, render_prop_names_macro,
+    render_scaffold_macro,
+};
 
 use crate::render::render_prop_impl::render_prop_impl;
+use crate::render::render_props_boxed::render_props_boxed;
 use crate::render::render_props_builder_struct::render_prop_builder_struct;
+use crate::render::render_props_clone::render_props_clone;
+use crate::render::render_props_debug::render_props_debug;
+use crate::render::render_props_defaults::render_props_defaults;
+use crate::render::render_props_delta::render_props_delta;
+use crate::render::render_props_dyn_trait::render_props_dyn_trait;
+use crate::render::render_props_dynamic::render_props_dynamic;
+use crate::render::render_props_eq::render_props_eq;
+use crate::render::render_props_impl_trait::render_props_impl_trait;
+use crate::render::render_props_loading::render_props_loading;
+use crate::render::render_props_mask::render_props_mask;
+use crate::render::render_props_max_size::render_props_max_size;
+use crate::render::render_props_metadata::render_props_metadata;
+use crate::render::render_props_newtype::render_props_newtype;
+use crate::render::render_props_snapshot::render_props_snapshot;
+use crate::render::render_props_with_helpers::render_props_with_helpers;
+use crate::render::render_signal_or_value::render_signal_or_value;
+use crate::render::render_utils::props_struct_name;
 
 /// Renders the props builder struct along with all the impls of type changing prop setters
 pub fn render_props(cmp: &Component) -> TokenStream {
-    let props_struct_name = Ident::new(&format!("{}Props", cmp.name), cmp.name.span());
+    let props_struct_name = props_struct_name(cmp);
 
     let props_struct_ts = render_prop_builder_struct(props_struct_name.clone(), cmp);
     let props_impl_ts = cmp
         .props
         .iter()
+        .filter(|prop| !prop.skip)
         .map(|prop| render_prop_impl(&props_struct_name, prop, cmp));
     let macro_ = render_component_macro(cmp);
+    let scaffold_macro = render_scaffold_macro(cmp);
+    let extend_macro = render_extend_macro(cmp);
+    let prop_names_macro = render_prop_names_macro(cmp);
+
+    let defaults_ts = render_props_defaults(cmp);
+    let eq_ts = render_props_eq(&props_struct_name, cmp);
 
     let mut s = quote! {
         #props_struct_ts
         #(#props_impl_ts)*
+        #defaults_ts
+        #eq_ts
     };
 
-    s.extend(macro_);
+    // proptest prints the props of failing cases, so `arbitrary` needs the `Debug` impl as well
+    if cmp.derive_debug || cmp.arbitrary {
+        s.extend(render_props_debug(&props_struct_name, cmp));
+    }
+
+    if cmp.signal_or_value {
+        s.extend(render_signal_or_value(cmp));
+    }
+
+    #[cfg(feature = "intern")]
+    if cmp.props.iter().any(|prop| prop.intern) {
+        s.extend(render_interner::render_interner(cmp));
+    }
+
+    if cmp.props.iter().any(|prop| prop.default_async) {
+        s.extend(render_props_loading(&props_struct_name, cmp));
+    }
+
+    if cmp.delta {
+        s.extend(render_props_delta(&props_struct_name, cmp));
+    }
+
+    if cmp.snapshot {
+        s.extend(render_props_snapshot(&props_struct_name, cmp));
+    }
+
+    if cmp.dynamic {
+        s.extend(render_props_dynamic(&props_struct_name, cmp));
+    }
+
+    if cmp.prop_mask {
+        s.extend(render_props_mask(&props_struct_name, cmp));
+    }
+
+    if cmp.newtype {
+        s.extend(render_props_newtype(&props_struct_name, cmp));
+    }
+
+    if cmp.with_helpers {
+        s.extend(render_props_with_helpers(&props_struct_name, cmp));
+    }
+
+    if cmp.clone_signals || cmp.derive_clone {
+        s.extend(render_props_clone(&props_struct_name, cmp));
+    }
+
+    if cmp.dyn_trait {
+        s.extend(render_props_dyn_trait(&props_struct_name, cmp));
+    }
+
+    if cmp.boxed {
+        s.extend(render_props_boxed(&props_struct_name, cmp));
+    }
+
+    if let Some(max_size) = &cmp.max_size {
+        s.extend(render_props_max_size(&props_struct_name, cmp, max_size));
+    }
+
+    if let Some(module) = &cmp.metadata {
+        s.extend(render_props_metadata(&props_struct_name, cmp, module));
+    }
+
+    for impl_trait in &cmp.impl_traits {
+        s.extend(render_props_impl_trait(&props_struct_name, cmp, impl_trait));
+    }
+
+    #[cfg(feature = "dominator")]
+    if let Some(element) = &cmp.apply_element {
+        s.extend(render_apply_timing::render_apply_timing(
+            &props_struct_name,
+            cmp,
+            element,
+        ));
+    }
+
+    #[cfg(feature = "dominator")]
+    if cmp.props.iter().any(|prop| prop.theme) {
+        s.extend(render_props_theme::render_props_theme(
+            &props_struct_name,
+            cmp,
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    if cmp.from_json {
+        s.extend(render_props_from_json::render_props_from_json(
+            &props_struct_name,
+            cmp,
+        ));
+    }
+
+    #[cfg(feature = "proptest")]
+    if cmp.arbitrary {
+        s.extend(render_props_arbitrary::render_props_arbitrary(
+            &props_struct_name,
+            cmp,
+        ));
+    }
+
+    #[cfg(feature = "wasm")]
+    if let Some(return_type) = &cmp.wasm_export {
+        s.extend(render_wasm_export::render_wasm_export(cmp, return_type));
+    }
+
+    let macros = quote! {
+        #macro_
+        #scaffold_macro
+        #prop_names_macro
+    };
+
+    match &cmp.macro_module {
+        Some(module) => s.extend(render_macro_module(cmp, module, macros)),
+        None => s.extend(macros),
+    }
+
+    s.extend(extend_macro);
     s
 }
 
@@ -0,0 +1,52 @@
+use crate::parse::Component;
+use crate::render::render_utils::{leading_args, leading_params};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::Type;
+
+/// Renders the `apply_before()` and `apply_after()` helpers of the dominator `apply` prop.
+/// The render fn calls both, before and after its own builder operations, and only the one matching the `apply_timing`
+/// of the component applies the closure, so the component author decides which attributes the caller can override.
+pub fn render_apply_timing(
+    props_struct_name: &Ident,
+    cmp: &Component,
+    element: &Type,
+) -> TokenStream {
+    let dominator = &cmp.dominator;
+    let lifetimes = leading_params(cmp);
+    let lifetime_args = leading_args(cmp);
+    let builder = quote! {#dominator::DomBuilder<#element>};
+
+    let apply_body = |applies: bool| {
+        if applies {
+            quote! {
+                match apply.take() {
+                    Some(apply) => apply(builder),
+                    None => builder,
+                }
+            }
+        } else {
+            quote! {
+                let _ = apply;
+                builder
+            }
+        }
+    };
+    let before_body = apply_body(!cmp.apply_after);
+    let after_body = apply_body(cmp.apply_after);
+
+    quote! {
+        #[automatically_derived]
+        impl<#(#lifetimes),*> #props_struct_name<#(#lifetime_args),*> {
+            /// Applies the `apply` prop to the builder, if the component applies it before its own builder operations
+            pub fn apply_before<TApplyFn: FnOnce(#builder) -> #builder>(apply: &mut Option<TApplyFn>, builder: #builder) -> #builder {
+                #before_body
+            }
+
+            /// Applies the `apply` prop to the builder, if the component applies it after its own builder operations
+            pub fn apply_after<TApplyFn: FnOnce(#builder) -> #builder>(apply: &mut Option<TApplyFn>, builder: #builder) -> #builder {
+                #after_body
+            }
+        }
+    }
+}
@@ -1,77 +1,437 @@
 use crate::parse::{Component, SignalType};
-use crate::render::render_utils::get_prop_signal_type_param;
+use crate::render::render_utils::{
+    component_macro_name, get_prop_signal_type_param, prop_is_signal_or_value, props_struct_name,
+    received_signal_item_type, setter_name, signal_setter_name,
+};
 use convert_case::{Case, Casing};
 use proc_macro2::{Ident, TokenStream};
 use proc_macro2::{Punct, Spacing};
 use quote::quote;
+use syn::{Path, Visibility};
 
 pub fn render_component_macro(cmp: &Component) -> TokenStream {
-    let name: Ident = syn::parse_str(cmp.name.to_string().to_case(Case::Snake).as_str())
-        .expect("failed to parse component name");
-    let render_fn = syn::parse_str::<Ident>(format!("{}", cmp.render_fn).as_str())
-        .expect("failed to parse render fn name");
-    let props_name = syn::parse_str::<Ident>(format!("{}Props", cmp.name).as_str())
-        .expect("failed to parse props name");
+    let name = component_macro_name(cmp);
+    let mut out = render_macro(cmp, &name, &cmp.render_fn, cmp.ssr_render_fn.as_ref());
+
+    for render_fn in variant_render_fns(cmp) {
+        let variant_name = variant_macro_name(render_fn);
+        out.extend(render_macro(cmp, &variant_name, render_fn, None));
+    }
+
+    out
+}
+
+/// The render fns of a `render_fn = [...]` list that get a macro of their own, which are those not named like the
+/// component macro
+fn variant_render_fns(cmp: &Component) -> impl Iterator<Item = &Path> {
+    let name = component_macro_name(cmp);
+
+    cmp.variant_render_fns
+        .iter()
+        .filter(move |render_fn| variant_macro_name(render_fn) != name)
+}
+
+/// The macro of a render fn of a `render_fn = [...]` list is named after the fn
+fn variant_macro_name(render_fn: &Path) -> Ident {
+    render_fn
+        .segments
+        .last()
+        .expect("render fn paths have at least one segment")
+        .ident
+        .clone()
+}
+
+fn render_macro(
+    cmp: &Component,
+    name: &Ident,
+    render_fn: &Path,
+    ssr_render_fn: Option<&Path>,
+) -> TokenStream {
+    let props_name = props_struct_name(cmp);
     let dollar = Punct::new('$', Spacing::Joint);
     let methods = quote!(#dollar methods);
 
-    let docs = create_generated_macro_docs_section(cmp, &name);
+    let docs = create_generated_macro_docs_section(cmp, name);
+    let export = render_macro_export(cmp);
+
+    let render_fn_override = quote!(#dollar render);
+
+    // Passing the rendered component through a `#[must_use]` fn makes discarding the macro result warn, so the call
+    // must end the expansion, after the statements choosing what is rendered
+    let render = |statements: TokenStream, rendered: TokenStream| {
+        if cmp.must_use {
+            let must_use_fn = Ident::new(&format!("rendered_{}", name), cmp.name.span());
+
+            quote! {
+                #[must_use = "the rendered component is dropped without being used"]
+                #[inline(always)]
+                fn #must_use_fn<T>(rendered: T) -> T {
+                    rendered
+                }
+
+                #statements
+                #must_use_fn(#rendered)
+            }
+        } else {
+            quote! {
+                #statements
+                #rendered
+            }
+        }
+    };
+    // the `ssr` feature is checked where the macro is invoked, so it's the feature of the calling crate
+    let default_render = match ssr_render_fn {
+        Some(ssr_render_fn) => render(
+            quote! {
+                #[cfg(feature = "ssr")]
+                let rendered = #ssr_render_fn (applied_props);
+                #[cfg(not(feature = "ssr"))]
+                let rendered = #render_fn (applied_props);
+            },
+            quote! {rendered},
+        ),
+        None => render(quote! {}, quote! {#render_fn (applied_props)}),
+    };
+    let override_render = render(quote! {}, quote! {#render_fn_override (applied_props)});
+    let props_receiver = quote!(#dollar props);
+
+    // with the `apply` prop, the closure applied to the `DomBuilder` of the component can be passed up front, i.e. by
+    // macros wrapping the component macro
+    #[cfg(feature = "dominator")]
+    let apply_arm = if cmp.apply_element.is_some() {
+        let apply_fn = quote!(#dollar apply);
+
+        quote! {
+            (apply = #apply_fn:expr, {#dollar(#methods:tt)*}) => {{
+                let default_props = #props_name::new().apply(#apply_fn);
+                let applied_props = default_props #dollar(#methods)*;
+                #default_render
+            }};
+        }
+    } else {
+        quote! {}
+    };
+    #[cfg(not(feature = "dominator"))]
+    let apply_arm = quote! {};
 
-    let out = quote! {
+    quote! {
         #docs
-        #[macro_export]
+        #export
         macro_rules! #name {
             ({#dollar(#methods:tt)*}) => {{
                 let default_props = #props_name::new();
                 let applied_props = default_props #dollar(#methods)*;
-                #render_fn (applied_props)
-            }}
+                #default_render
+            }};
+            (render = #render_fn_override:expr, {#dollar(#methods:tt)*}) => {{
+                let default_props = #props_name::new();
+                let applied_props = default_props #dollar(#methods)*;
+                #override_render
+            }};
+            #apply_arm
+            (#props_receiver:expr => {#dollar(#methods:tt)*}) => {{
+                let default_props = #props_receiver;
+                let applied_props = default_props #dollar(#methods)*;
+                #default_render
+            }};
         }
+    }
+}
+
+/// Renders the `{component}_scaffold!()` macro, which expands to a minimal invocation of the component macro.
+/// Every prop without a default that can be named without a generic is set to a placeholder value.
+pub fn render_scaffold_macro(cmp: &Component) -> TokenStream {
+    let dominator = &cmp.dominator;
+    let macro_name = component_macro_name(cmp);
+    let name = Ident::new(&format!("{}_scaffold", macro_name), cmp.name.span());
+
+    let placeholders = cmp
+        .props
+        .iter()
+        .filter(|prop| prop.default.is_none() && prop.generics.is_none())
+        .map(|prop| {
+            let prop_name = setter_name(prop);
+            let ty_ = prop
+                .map
+                .as_ref()
+                .map(|(input, _)| input)
+                .or(prop.observable.as_ref())
+                .unwrap_or(&prop.type_);
+
+            if prop.emits.is_some() {
+                return quote! {.#prop_name(|_| {})};
+            }
+
+            if prop.try_into {
+                return quote! {.#prop_name(<#ty_ as ::core::default::Default>::default()).unwrap()};
+            }
+
+            if prop.intern {
+                return quote! {.#prop_name(::std::string::String::new())};
+            }
+
+            if prop.slot {
+                return quote! {.#prop_name(#dominator::Dom::empty())};
+            }
+
+            match prop.is_signal {
+                Some(SignalType::Vec) => quote! {.#prop_name(::std::vec::Vec::new())},
+                _ => quote! {.#prop_name(<#ty_ as ::core::default::Default>::default())},
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let placeholder_docs = placeholders
+        .iter()
+        .map(|placeholder| format!("    {}", placeholder));
+    let doc_strings = [
+        format!(
+            "Expands to a minimal `{}!` invocation, setting every prop without a default to a placeholder value.\n",
+            macro_name
+        ),
+        "Use it as a starting point when writing a new invocation of the component:\n".to_string(),
+        "```rust,ignore".to_string(),
+        format!("{}!({{", macro_name),
+    ]
+    .into_iter()
+    .chain(placeholder_docs)
+    .chain(["});".to_string(), "```".to_string()]);
+
+    let export = render_macro_export(cmp);
+    // the component macro is only in scope where its module is
+    let macro_path = match &cmp.macro_module {
+        Some(module) => quote! {#module::#macro_name},
+        None => quote! {#macro_name},
     };
 
-    out
+    quote! {
+        #(#[doc = #doc_strings])*
+        #export
+        macro_rules! #name {
+            () => {
+                #macro_path!({
+                    #(#placeholders)*
+                })
+            }
+        }
+    }
+}
+
+/// Wraps the component macros in the `macro_module` of the component, re-exporting them by path.
+/// `macro_rules!` macros can only be exported from the crate root, so they are re-exported within the crate.
+pub fn render_macro_module(cmp: &Component, module: &Ident, macros: TokenStream) -> TokenStream {
+    let macro_name = component_macro_name(cmp);
+    let scaffold_name = Ident::new(&format!("{}_scaffold", macro_name), cmp.name.span());
+    let prop_names_name = Ident::new(&format!("{}_prop_names", macro_name), cmp.name.span());
+    let variant_names = variant_render_fns(cmp).map(variant_macro_name);
+    let vis = &cmp.vis;
+    let doc = format!("The macros of the `{}` component", cmp.name);
+
+    quote! {
+        #[doc = #doc]
+        #vis mod #module {
+            #macros
+
+            #[allow(unused_imports)]
+            pub(crate) use {#macro_name, #scaffold_name, #prop_names_name #(, #variant_names)*};
+        }
+    }
+}
+
+/// Renders the `{component}_prop_names!` macro, which lists the names of the props of the component.
+/// Without arguments it expands to an array of the names as string literals, and given the name of a macro, it invokes it
+/// with the names as comma separated idents, so macros built on top of the component can validate field references.
+pub fn render_prop_names_macro(cmp: &Component) -> TokenStream {
+    let macro_name = component_macro_name(cmp);
+    let name = Ident::new(&format!("{}_prop_names", macro_name), cmp.name.span());
+    let dollar = Punct::new('$', Spacing::Joint);
+    let prop_names = cmp.props.iter().map(|prop| &prop.name).collect::<Vec<_>>();
+    let prop_name_strs = prop_names.iter().map(|name| name.to_string());
+    let export = render_macro_export(cmp);
+
+    let doc = format!(
+        "Expands to the names of the props of the `{}!` component, as a `[&str; N]` array, or invokes `callback!(name, ...)` with them given `{}!(callback)`.",
+        macro_name, name
+    );
+
+    quote! {
+        #[doc = #doc]
+        #export
+        macro_rules! #name {
+            () => {
+                [#(#prop_name_strs),*]
+            };
+            (#dollar callback:ident) => {
+                #dollar callback!(#(#prop_names),*)
+            };
+        }
+    }
+}
+
+/// Renders the hidden `__{component}_extend!` macro of `extendable` components, which captures the generics and fields
+/// of the component. `#[component(extends = Component)]` expands to an invocation of it, passing the path of the
+/// `#[component]` attribute it calls back into with the captured definition attached as `#[component_base(...)]`.
+pub fn render_extend_macro(cmp: &Component) -> TokenStream {
+    let Some(definition) = &cmp.definition else {
+        return quote! {};
+    };
+    let name = Ident::new(
+        &format!("__{}_extend", cmp.name.to_string().to_case(Case::Snake)),
+        cmp.name.span(),
+    );
+    let dollar = Punct::new('$', Spacing::Joint);
+    let export = render_visibility_macro_export(cmp);
+
+    quote! {
+        #[doc(hidden)]
+        #export
+        macro_rules! #name {
+            (( #dollar(#dollar component:tt)* ) ( #dollar(#dollar args:tt)* ) #dollar(#dollar item:tt)*) => {
+                #[#dollar(#dollar component)*(#dollar(#dollar args)*)]
+                #[component_base(#definition)]
+                #dollar(#dollar item)*
+            }
+        }
+    }
+}
+
+/// Macros placed in a `macro_module` are re-exported from it by path, so they are never exported from the crate root
+fn render_macro_export(cmp: &Component) -> TokenStream {
+    match cmp.macro_module {
+        Some(_) => quote! {#[allow(unused_macros)]},
+        None => render_visibility_macro_export(cmp),
+    }
+}
+
+/// Only components with an explicit visibility get their macros exported from the crate root.
+/// Private components keep their macros in textual scope, which lets them be declared inside function bodies without
+/// tripping `non_local_definitions`, or clashing with same-named components declared in other functions.
+fn render_visibility_macro_export(cmp: &Component) -> TokenStream {
+    match cmp.vis {
+        Visibility::Inherited => quote! {#[allow(unused_macros)]},
+        _ => quote! {#[macro_export]},
+    }
 }
 
 fn create_generated_macro_docs_section(cmp: &Component, macro_name: &Ident) -> TokenStream {
     let mut doc_strings = vec![
         "This macro is generated by the `futures-signals-component-macros` crate.\n".to_string(),
-        format!("For more information, see the [{}Props].", cmp.name),
+        format!(
+            "For more information, see the [{}].",
+            props_struct_name(cmp)
+        ),
         "All generated methods:\n".to_string(),
         "```rust,ignore".to_string(),
         format!("{}! ({{", macro_name),
     ];
 
-    for prop in &cmp.props {
+    for prop in cmp.props.iter().filter(|prop| !prop.skip) {
         let ty_ = &prop.type_;
+        let setter = setter_name(prop);
 
         if let Some(signal) = &prop.is_signal {
             match signal {
-                SignalType::Item => {
-                    doc_strings.push(format!("    .{}(<{}>)", prop.name, quote! {#ty_}));
-                    let ty_ = get_prop_signal_type_param(prop, signal, ty_, false);
-                    doc_strings.push(format!("    .{}_signal(<{}>)", prop.name, quote! {#ty_}));
+                SignalType::Item if prop.try_into => {
+                    doc_strings.push(format!(
+                        "    .{}(<impl TryInto<{}>>)?",
+                        setter,
+                        quote! {#ty_}
+                    ));
+                    let ty_ = get_prop_signal_type_param(cmp, prop, signal, ty_, false);
+                    doc_strings.push(format!(
+                        "    .{}(<{}>)",
+                        signal_setter_name(cmp, prop, signal),
+                        quote! {#ty_}
+                    ));
                 }
-                SignalType::Vec => {
+                SignalType::Item if prop_is_signal_or_value(cmp, prop) => {
+                    doc_strings.push(format!(
+                        "    .{}(<{} or impl Signal<Item={}>>)",
+                        setter,
+                        quote! {#ty_},
+                        quote! {#ty_}
+                    ));
+                    let ty_ = get_prop_signal_type_param(cmp, prop, signal, ty_, false);
                     doc_strings.push(format!(
-                        "    .{}(<Into<Vec<{}>>>)",
-                        prop.name,
+                        "    .{}(<{}>)",
+                        signal_setter_name(cmp, prop, signal),
                         quote! {#ty_}
                     ));
-                    let ty_ = get_prop_signal_type_param(prop, signal, ty_, false);
+                }
+                SignalType::Item => {
+                    doc_strings.push(format!("    .{}(<{}>)", setter, quote! {#ty_}));
+                    let ty_ = get_prop_signal_type_param(cmp, prop, signal, ty_, false);
+                    doc_strings.push(format!(
+                        "    .{}(<{}>)",
+                        signal_setter_name(cmp, prop, signal),
+                        quote! {#ty_}
+                    ));
+                }
+                SignalType::Vec => {
+                    doc_strings.push(format!("    .{}(<Into<Vec<{}>>>)", setter, quote! {#ty_}));
+                    let ty_ = get_prop_signal_type_param(cmp, prop, signal, ty_, false);
                     doc_strings.push(format!(
-                        "    .{}_signal_vec(<{}>)",
-                        prop.name,
+                        "    .{}(<{}>)",
+                        signal_setter_name(cmp, prop, signal),
                         quote! {#ty_}
                     ));
                 }
             }
+
+            if matches!(signal, SignalType::Item) && prop.generics.is_none() {
+                let item_type = received_signal_item_type(prop, ty_);
+                doc_strings.push(format!(
+                    "    .{}_from(<impl Signal>, <impl FnMut(_) -> {}>)",
+                    setter,
+                    quote! {#item_type}
+                ));
+                doc_strings.push(format!(
+                    "    .{}_mutable(<&Mutable<{}>>)",
+                    setter,
+                    quote! {#item_type}
+                ));
+            }
+        } else if prop.try_into {
+            doc_strings.push(format!(
+                "    .{}(<impl TryInto<{}>>)?",
+                setter,
+                quote! {#ty_}
+            ));
+        } else if prop.intern {
+            doc_strings.push(format!("    .{}(<impl Into<String>>)", setter));
+        } else if let Some(item) = &prop.collect {
+            doc_strings.push(format!(
+                "    .{}(<impl IntoIterator<Item = impl Into<{}>>>)",
+                setter,
+                quote! {#item}
+            ));
+        } else if let Some(event) = &prop.emits {
+            doc_strings.push(format!("    .{}(<impl Fn({})>)", setter, quote! {#event}));
+        } else if let Some(value) = &prop.observable {
+            doc_strings.push(format!("    .{}(<{}>)", setter, quote! {#value}));
         } else {
-            doc_strings.push(format!("    .{}(<{}>)", prop.name, quote! {#ty_}));
+            doc_strings.push(format!("    .{}(<{}>)", setter, quote! {#ty_}));
         }
     }
 
     doc_strings.push("});".to_string());
     doc_strings.push("```".to_string());
+    doc_strings.push(format!(
+        "Use `{}!(render = other_render_fn, {{ ... }})` to render the props with another function than `{}`.",
+        macro_name,
+        path_to_string(&cmp.render_fn)
+    ));
+    doc_strings.push(format!(
+        "Use `{}!(props => {{ ... }})` to continue from existing props rather than the default ones.",
+        macro_name
+    ));
+
+    if let Some(ssr_render_fn) = &cmp.ssr_render_fn {
+        doc_strings.push(format!(
+            "With the `ssr` feature of the calling crate enabled, the props are rendered with `{}` instead.",
+            path_to_string(ssr_render_fn)
+        ));
+    }
 
     let doc_props = doc_strings
         .into_iter()
@@ -84,3 +444,8 @@ fn create_generated_macro_docs_section(cmp: &Component, macro_name: &Ident) -> T
 
     quote! { #(#doc_props)* }
 }
+
+/// Prints a render fn path the way it is written, i.e. `MyCmpProps::render` rather than `MyCmpProps :: render`
+fn path_to_string(path: &Path) -> String {
+    quote! {#path}.to_string().replace(' ', "")
+}
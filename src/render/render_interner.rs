@@ -0,0 +1,35 @@
+use crate::parse::Component;
+use crate::render::render_utils::interner_module;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Renders the hidden module with the string pool of a component with `#[intern]` fields.
+/// Proc macro crates can only export macros, so the pool is generated alongside the component, and shared by its props.
+pub fn render_interner(cmp: &Component) -> TokenStream {
+    let module = interner_module(cmp);
+    let vis = &cmp.vis;
+
+    quote! {
+        #[doc(hidden)]
+        #vis mod #module {
+            static POOL: ::std::sync::OnceLock<::std::sync::Mutex<::std::collections::HashSet<::std::sync::Arc<str>>>> =
+                ::std::sync::OnceLock::new();
+
+            /// Returns the pooled `Arc<str>` equal to the value, adding it to the pool if there is none
+            pub fn intern(value: String) -> ::std::sync::Arc<str> {
+                let mut pool = POOL
+                    .get_or_init(::core::default::Default::default)
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+                if let Some(interned) = pool.get(value.as_str()) {
+                    return interned.clone();
+                }
+
+                let interned: ::std::sync::Arc<str> = value.into();
+                pool.insert(interned.clone());
+                interned
+            }
+        }
+    }
+}
@@ -2,7 +2,11 @@ use crate::parse::{Component, Prop, SignalType};
 use crate::render::render_doc_exprs;
 use crate::render::render_utils::{
     compute_component_generics, get_prop_signal_always_type, get_prop_signal_type_param,
-    new_prop_signal_name, prop_signal_name,
+    intern_expr, is_setter_name, leading_args, leading_params, lifetimes_marker, loading_field,
+    new_prop_signal_name, prop_is_optional, prop_is_signal_or_value, prop_mask_field,
+    prop_mask_flag, prop_mask_name, prop_signal_name, received_signal_item_type,
+    replace_prop_generic, required_generics, required_param_name, setter_name, setter_value_type,
+    signal_or_value_module, signal_setter_name, store_signal_expr,
 };
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
@@ -10,7 +14,10 @@ use syn::spanned::Spanned;
 use syn::{Type, TypeParam};
 
 pub fn render_prop_impl(props_struct_name: &Ident, prop: &Prop, cmp: &Component) -> TokenStream {
-    let generics = compute_component_generics(cmp, false, false);
+    let futures_signals = &cmp.futures_signals;
+    // setters keep the `#[required]` state of the other props, and setting a required prop marks it as set
+    let mut generics = compute_component_generics(cmp, false, false);
+    generics.extend(required_generics(cmp, false));
     let generic_idents = generics
         .iter()
         .map(|g| g.ident.clone())
@@ -18,7 +25,20 @@ pub fn render_prop_impl(props_struct_name: &Ident, prop: &Prop, cmp: &Component)
             syn::parse_str(quote! {#i}.to_string().as_str()).expect("failed to parse generic ident")
         })
         .collect::<Vec<_>>();
+    let lifetimes = leading_params(cmp);
+    let lifetime_args = leading_args(cmp);
     let prop_name = &prop.name;
+    let setter_fn_name = setter_name(prop);
+    let lifetimes_marker = lifetimes_marker(cmp);
+    let loading_field = loading_field(cmp);
+
+    // setting a prop of a `prop_mask` component sets its bit of the mask
+    let mask_update = prop_mask_field(cmp).map(|mask| {
+        let mask_name = prop_mask_name(props_struct_name);
+        let flag = prop_mask_flag(prop);
+
+        quote! {#mask: self.#mask | #mask_name::#flag,}
+    });
 
     let mut changed_generics: Vec<TypeParam> = vec![];
     let mut out_rewrites = vec![];
@@ -27,35 +47,74 @@ pub fn render_prop_impl(props_struct_name: &Ident, prop: &Prop, cmp: &Component)
 
     let docs = render_doc_exprs(&prop.docs);
 
-    let value_assign_expr = if let Some(_default) = &prop.default {
-        quote! {v}
-    } else {
-        quote! {Some(v)}
+    // the value setter of signal fields calls the signal setter, so only the setter storing the field warns
+    let deprecation_warning = prop.deprecated_runtime.as_ref().map(|message| {
+        let setter = match &prop.is_signal {
+            Some(signal_type) => signal_setter_name(cmp, prop, signal_type),
+            None => setter_fn_name.clone(),
+        };
+        let prefix = format!("{}::{} is deprecated: ", props_struct_name, setter);
+
+        quote! {
+            static DEPRECATION_WARNING: ::std::sync::Once = ::std::sync::Once::new();
+            DEPRECATION_WARNING.call_once(|| ::log::warn!("{}{}", #prefix, #message));
+        }
+    });
+
+    // the setters of a field call each other, which must not warn about the deprecation of the field
+    let deprecated = prop.deprecated.as_ref().map(|attr| {
+        quote! {
+            #attr
+            #[allow(deprecated)]
+        }
+    });
+
+    let assign_expr = |value: TokenStream| {
+        if prop_is_optional(prop) {
+            quote! {Some(#value)}
+        } else {
+            value
+        }
     };
 
     if is_generic_type {
         let generic = prop.generics.clone().unwrap();
 
-        let bounds = generic.param.bounds;
-        let new_generic_param =
-            syn::parse_str(format!("{}New:{}", generic.param.ident, quote! {#bounds}).as_str())
-                .expect("failed to parse signal generic");
+        let new_ident = Ident::new(
+            &format!("{}New", generic.param.ident),
+            generic.param.ident.span(),
+        );
+        let mut new_generic_param: TypeParam = syn::parse_quote!(#new_ident);
+        new_generic_param.bounds = generic.param.bounds;
+
+        // values of owned signals end up in a `'static` signal
+        if prop.owned {
+            new_generic_param.bounds.push(syn::parse_quote!('static));
+        }
+
         changed_generics.push(new_generic_param);
 
         let new_type = syn::parse_str::<Type>(format!("{}New", generic.param.ident).as_str())
             .expect("failed to parse new generic param");
         let old_type = generic.param.ident.to_string();
-        ty_ = new_type.clone();
+        ty_ = replace_prop_generic(prop, &quote! {#new_type});
         out_rewrites.push((old_type, new_type));
     }
 
-    if prop.is_signal.is_some() {
-        let param = get_prop_signal_type_param(prop, prop.is_signal.as_ref().unwrap(), &ty_, true);
-        let prop_signal_always_type =
-            get_prop_signal_always_type(prop.is_signal.as_ref().unwrap(), &ty_);
+    if prop.required {
+        out_rewrites.push((
+            required_param_name(&prop.name).to_string(),
+            prop.type_.clone(),
+        ));
+    }
+
+    if let Some(signal_type) = &prop.is_signal {
+        let value_assign_expr = assign_expr(store_signal_expr(cmp, prop, signal_type, quote! {v}));
+        let param = get_prop_signal_type_param(cmp, prop, signal_type, &ty_, true);
+        let prop_signal_always_type = get_prop_signal_always_type(cmp, prop, signal_type, &ty_);
 
         let changed_generics_nosig = changed_generics.clone();
-        changed_generics.push(param);
+        changed_generics.push(param.clone());
 
         let new_signal_name: Type = syn::parse_str(new_prop_signal_name(&prop.name).as_str())
             .expect("failed to parse new signal name");
@@ -77,50 +136,208 @@ pub fn render_prop_impl(props_struct_name: &Ident, prop: &Prop, cmp: &Component)
             );
         }
 
-        let rest_of_props = cmp.props.iter().filter(|p| p.name != prop.name).map(|p| {
-            let name = &p.name;
+        let rest_of_props = cmp
+            .props
+            .iter()
+            .filter(|p| p.name != prop.name)
+            .map(|p| &p.name)
+            .chain(&lifetimes_marker)
+            .chain(&loading_field)
+            .map(|name| {
+                quote! {
+                    #name: self.#name,
+                }
+            });
+
+        let props_signal_fn_name = signal_setter_name(cmp, prop, signal_type);
+
+        let signal_mod_ident = match signal_type {
+            SignalType::Item => Ident::new("signal", prop.type_.span()),
+            SignalType::Vec => Ident::new("signal_vec", prop.type_.span()),
+        };
+
+        // the value setter of a field with a `map` takes a value of the argument of the closure, which maps it like a signal
+        let value_type = match &prop.map {
+            Some((input, _)) => input.clone(),
+            None => ty_.clone(),
+        };
+        let always_value_type = match signal_type {
+            SignalType::Item if prop.into => quote! {impl Into<#ty_>},
+            SignalType::Item => quote! {#value_type},
+            SignalType::Vec if prop.into => quote! {impl IntoIterator<Item = impl Into<#ty_>>},
+            SignalType::Vec => quote! {impl Into<Vec<#ty_>>},
+        };
+
+        // signals of fields with a fallback are signals of `Option`s
+        let always_value = |v: TokenStream| {
+            if prop.fallback.is_some() {
+                quote! {Some(#v)}
+            } else {
+                v
+            }
+        };
+        let try_into_value = always_value(quote! {v.try_into()?});
+        // the target type is spelled out, as an `Into` bound of a generic item type would make `v.into()` ambiguous
+        let into_target = match signal_type {
+            SignalType::Item => quote! {#value_type},
+            SignalType::Vec => quote! {Vec<#ty_>},
+        };
+        let into_value = match signal_type {
+            SignalType::Vec if prop.into => quote! {
+                ::core::iter::Iterator::collect::<#into_target>(::core::iter::Iterator::map(
+                    ::core::iter::IntoIterator::into_iter(v),
+                    ::core::convert::Into::into,
+                ))
+            },
+            _ => always_value(quote! {::core::convert::Into::<#into_target>::into(v)}),
+        };
+
+        let value_setter = if prop_is_signal_or_value(cmp, prop) {
+            let module = signal_or_value_module(cmp);
+            let into_signal_or_value = quote! {#module::IntoSignalOrValue<#ty_, TMarker>};
+            let signal_type: Type = syn::parse_quote!(<TValue as #into_signal_or_value>::Signal);
+            let signal_bounds = &param.bounds;
+            let generic_idents_out_value =
+                replace_generic(generic_idents.clone(), &old_name, signal_type.clone());
 
             quote! {
-                #name: self.#name,
+                pub fn #setter_fn_name<TValue: #into_signal_or_value, TMarker>(self, v: TValue) -> #props_struct_name<#(#lifetime_args,)* #(#generic_idents_out_value),*>
+                where
+                    #signal_type: #signal_bounds,
+                {
+                    self.#props_signal_fn_name(#module::IntoSignalOrValue::into_signal(v))
+                }
             }
-        });
+        } else if prop.try_into {
+            quote! {
+                pub fn #setter_fn_name<TTryInto: TryInto<#ty_>>(self, v: TTryInto) -> Result<#props_struct_name<#(#lifetime_args,)* #(#generic_idents_out_always),*>, TTryInto::Error> {
+                    Ok(self.#props_signal_fn_name(#futures_signals::#signal_mod_ident::always(#try_into_value)))
+                }
+            }
+        } else {
+            quote! {
+                pub fn #setter_fn_name<#(#changed_generics_nosig),*>(mut self, v: #always_value_type) -> #props_struct_name<#(#lifetime_args,)* #(#generic_idents_out_always),*> {
+                    self.#props_signal_fn_name(#futures_signals::#signal_mod_ident::always(#into_value))
+                }
+            }
+        };
+
+        // `{name}_from` maps a source signal into the field, i.e. to bind a field of a larger state signal.
+        // It gives way to the setter of another field of the same name
+        let from_fn_name = Ident::new(&format!("{}_from", setter_fn_name), setter_fn_name.span());
+        let from_setter = match signal_type {
+            SignalType::Item if !is_generic_type && !is_setter_name(cmp, &from_fn_name) => {
+                let item_type = received_signal_item_type(prop, &ty_);
+                let signal_type: Type =
+                    syn::parse_quote!(#futures_signals::signal::Map<TSource, TMapFn>);
+                let signal_bounds = &param.bounds;
+                let generic_idents_out_from =
+                    replace_generic(generic_idents.clone(), &old_name, signal_type.clone());
 
-        let props_signal_fn_name = match prop.is_signal.as_ref().unwrap() {
-            SignalType::Item => syn::parse_str::<Ident>(format!("{}_signal", prop.name).as_str())
-                .expect("failed to parse props signal fn name"),
-            SignalType::Vec => {
-                syn::parse_str::<Ident>(format!("{}_signal_vec", prop.name).as_str())
-                    .expect("failed to parse props signal fn name")
+                quote! {
+                    #docs
+                    #deprecated
+                    pub fn #from_fn_name<TSource: #futures_signals::signal::Signal, TMapFn: FnMut(TSource::Item) -> #item_type>(self, source: TSource, f: TMapFn) -> #props_struct_name<#(#lifetime_args,)* #(#generic_idents_out_from),*>
+                    where
+                        #signal_type: #signal_bounds,
+                    {
+                        self.#props_signal_fn_name(#futures_signals::signal::SignalExt::map(source, f))
+                    }
+                }
             }
+            _ => quote! {},
         };
 
-        let signal_mod_ident = match prop.is_signal.as_ref().unwrap() {
-            SignalType::Item => Ident::new("signal", prop.type_.span()),
-            SignalType::Vec => Ident::new("signal_vec", prop.type_.span()),
+        // `{name}_mutable` takes the signal of a `Mutable`, cloning its values, so it is only usable for `Clone` items.
+        // Like `{name}_from`, it gives way to the setter of another field of the same name
+        let mutable_fn_name = Ident::new(
+            &format!("{}_mutable", setter_fn_name),
+            setter_fn_name.span(),
+        );
+        let mutable_setter = match signal_type {
+            SignalType::Item if !is_generic_type && !is_setter_name(cmp, &mutable_fn_name) => {
+                let signal_type: Type =
+                    syn::parse_quote!(#futures_signals::signal::MutableSignalCloned<TValue>);
+                let signal_bounds = &param.bounds;
+                let generic_idents_out_mutable =
+                    replace_generic(generic_idents.clone(), &old_name, signal_type.clone());
+
+                quote! {
+                    #docs
+                    #deprecated
+                    pub fn #mutable_fn_name<TValue: ::core::clone::Clone>(self, mutable: &#futures_signals::signal::Mutable<TValue>) -> #props_struct_name<#(#lifetime_args,)* #(#generic_idents_out_mutable),*>
+                    where
+                        #signal_type: #signal_bounds,
+                    {
+                        self.#props_signal_fn_name(mutable.signal_cloned())
+                    }
+                }
+            }
+            _ => quote! {},
         };
 
-        let always_value_type = match prop.is_signal.as_ref().unwrap() {
-            SignalType::Item => quote! {#ty_},
-            SignalType::Vec => quote! {impl Into<Vec<#ty_>>},
+        // the signal setter repeats the field docs, noting what it takes instead of a value
+        let signal_note = match signal_type {
+            SignalType::Item => format!(
+                " Accepts a signal of the value, rather than the value itself as `{}()` does.",
+                setter_fn_name
+            ),
+            SignalType::Vec => format!(
+                " Accepts a signal vec of the items, rather than the items themselves as `{}()` does.",
+                setter_fn_name
+            ),
+        };
+        let signal_docs = if prop.docs.is_empty() {
+            quote! {#[doc = #signal_note]}
+        } else {
+            quote! {
+                #docs
+                #[doc = ""]
+                #[doc = #signal_note]
+            }
         };
 
         quote! {
-            impl<#(#generics),*> #props_struct_name<#(#generic_idents),*> {
+            #[automatically_derived]
+            impl<#(#lifetimes,)* #(#generics),*> #props_struct_name<#(#lifetime_args,)* #(#generic_idents),*> {
                 #docs
-                pub fn #prop_name<#(#changed_generics_nosig),*>(mut self, v: #always_value_type) -> #props_struct_name<#(#generic_idents_out_always),*> {
-                    self.#props_signal_fn_name(futures_signals::#signal_mod_ident::always(v.into()))
-                }
+                #deprecated
+                #value_setter
+
+                #from_setter
+
+                #mutable_setter
+
+                #signal_docs
+                #deprecated
+                pub fn #props_signal_fn_name<#(#changed_generics),*>(self, v: #new_signal_name) -> #props_struct_name<#(#lifetime_args,)* #(#generic_idents_out),*> {
+                    #deprecation_warning
 
-                #docs
-                pub fn #props_signal_fn_name<#(#changed_generics),*>(self, v: #new_signal_name) -> #props_struct_name<#(#generic_idents_out),*> {
                     #props_struct_name {
                         #prop_name: #value_assign_expr,
                         #(#rest_of_props)*
+                        #mask_update
                     }
                 }
             }
         }
     } else {
+        let value_type = setter_value_type(prop, &ty_);
+        let value_assign_expr = if prop.emits.is_some() {
+            assign_expr(quote! {::std::rc::Rc::new(v)})
+        } else if prop.collect.is_some() {
+            assign_expr(quote! {v.into_iter().map(Into::into).collect()})
+        } else if prop.intern {
+            assign_expr(intern_expr(cmp, quote! {v.into()}))
+        } else if prop.into {
+            assign_expr(quote! {v.into()})
+        } else if prop.to_owned.is_some() {
+            assign_expr(quote! {::std::borrow::ToOwned::to_owned(v.as_ref())})
+        } else if prop.observable.is_some() {
+            assign_expr(quote! {#futures_signals::signal::Mutable::new(v)})
+        } else {
+            assign_expr(quote! {v})
+        };
         let mut generic_idents_out = generic_idents.clone();
 
         for (old_type, new_type) in out_rewrites.iter() {
@@ -128,24 +345,89 @@ pub fn render_prop_impl(props_struct_name: &Ident, prop: &Prop, cmp: &Component)
                 replace_generic(generic_idents_out, &old_type.to_string(), new_type.clone());
         }
 
-        let rest_of_props = cmp.props.iter().filter(|p| p.name != prop.name).map(|p| {
-            let name = &p.name;
+        let rest_of_props = cmp
+            .props
+            .iter()
+            .filter(|p| p.name != prop.name)
+            .map(|p| &p.name)
+            .chain(&lifetimes_marker)
+            .chain(&loading_field)
+            .map(|name| {
+                quote! {
+                    #name: self.#name,
+                }
+            });
 
+        let validation = prop.validate.as_ref().map(|validate| {
+            let message = format!(
+                "invalid value passed to `{}()` of `{}`: {{:?}}",
+                setter_fn_name, cmp.name
+            );
+
+            // `cfg!` rather than `#[cfg]`, so the validator is still used in release builds
             quote! {
-                #name: self.#name,
+                if cfg!(debug_assertions) {
+                    if let Err(e) = #validate(&v) {
+                        panic!(#message, e);
+                    }
+                }
             }
         });
 
-        quote! {
-            impl<#(#generics),*> #props_struct_name<#(#generic_idents),*> {
-                #docs
-                pub fn #prop_name<#(#changed_generics),*>(mut self, v: #ty_) -> #props_struct_name<#(#generic_idents_out),*> {
-                     #props_struct_name {
+        let setter = if prop.try_into {
+            quote! {
+                pub fn #setter_fn_name<TTryInto: TryInto<#ty_>>(self, v: TTryInto) -> Result<#props_struct_name<#(#lifetime_args,)* #(#generic_idents_out),*>, TTryInto::Error> {
+                    #deprecation_warning
+
+                    let v: #ty_ = v.try_into()?;
+                    #validation
+
+                    Ok(#props_struct_name {
+                        #prop_name: #value_assign_expr,
+                        #(#rest_of_props)*
+                        #mask_update
+                    })
+                }
+            }
+        } else {
+            quote! {
+                pub fn #setter_fn_name<#(#changed_generics),*>(mut self, v: #value_type) -> #props_struct_name<#(#lifetime_args,)* #(#generic_idents_out),*> {
+                    #deprecation_warning
+                    #validation
+
+                    #props_struct_name {
                         #prop_name: #value_assign_expr,
                         #(#rest_of_props)*
+                        #mask_update
                     }
                 }
             }
+        };
+
+        // `#[duration_ms]` fields can be set from a number of milliseconds as well
+        let duration_ms_setter = if prop.duration_ms {
+            let ms_fn_name = Ident::new(&format!("{}_ms", setter_fn_name), setter_fn_name.span());
+
+            quote! {
+                #docs
+                #deprecated
+                pub fn #ms_fn_name(self, ms: u64) -> #props_struct_name<#(#lifetime_args,)* #(#generic_idents_out),*> {
+                    self.#setter_fn_name(::core::time::Duration::from_millis(ms))
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        quote! {
+            #[automatically_derived]
+            impl<#(#lifetimes,)* #(#generics),*> #props_struct_name<#(#lifetime_args,)* #(#generic_idents),*> {
+                #docs
+                #deprecated
+                #setter
+
+                #duration_ms_setter
+            }
         }
     }
 }
@@ -0,0 +1,80 @@
+use crate::parse::{Component, Prop};
+use crate::render::render_utils::{compute_prop_type_ident, prop_is_optional};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The props with a `&self` accessor on the props trait, which are the plain fields the render fn can read before
+/// calling `take()`, or none unless the component opts into `accessors`.
+/// Signals, slots, event sinks, bindings and observables are left out, as they are only of use once taken, and so are fields of a
+/// generic type, which the trait only names through its associated types.
+pub fn accessor_props(cmp: &Component) -> impl Iterator<Item = &Prop> {
+    cmp.props.iter().filter(|prop| {
+        cmp.accessors
+            && prop.is_signal.is_none()
+            && !prop.slot
+            && prop.emits.is_none()
+            && !prop.binding
+            && prop.observable.is_none()
+            && prop.generics.is_none()
+    })
+}
+
+fn accessor_signature(cmp: &Component, prop: &Prop) -> TokenStream {
+    let name = &prop.name;
+    let type_ = compute_prop_type_ident(cmp, prop, false);
+    let doc = format!(" The `{}` prop, read without taking the props", name);
+
+    let return_type = if prop_is_optional(prop) {
+        quote! {Option<&#type_>}
+    } else {
+        quote! {&#type_}
+    };
+
+    quote! {
+        #[doc = #doc]
+        fn #name(&self) -> #return_type
+    }
+}
+
+/// Renders the declarations of the accessors, for the props trait and its object safe counterpart
+pub fn render_accessor_decls(cmp: &Component) -> TokenStream {
+    let decls = accessor_props(cmp).map(|prop| accessor_signature(cmp, prop));
+
+    quote! {
+        #(#decls;)*
+    }
+}
+
+/// Renders the accessors reading the fields of `props`, an expression of the props struct
+pub fn render_accessor_impls(cmp: &Component, props: TokenStream) -> TokenStream {
+    render_accessor_impls_with(cmp, |prop| {
+        let name = &prop.name;
+
+        if prop_is_optional(prop) {
+            quote! {#props.#name.as_ref()}
+        } else {
+            quote! {&#props.#name}
+        }
+    })
+}
+
+/// Renders the accessors with the given bodies, i.e. to forward them to another implementation
+pub fn render_accessor_impls_with(
+    cmp: &Component,
+    body: impl Fn(&Prop) -> TokenStream,
+) -> TokenStream {
+    let impls = accessor_props(cmp).map(|prop| {
+        let signature = accessor_signature(cmp, prop);
+        let body = body(prop);
+
+        quote! {
+            #signature {
+                #body
+            }
+        }
+    });
+
+    quote! {
+        #(#impls)*
+    }
+}
@@ -0,0 +1,71 @@
+use crate::parse::Component;
+use crate::render::render_utils::compute_prop_type_ident;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Renders a `proptest::arbitrary::Arbitrary` impl for the props struct with its default generic params.
+/// Every plain, non generic field is generated with `any()`, while signal, event sink, binding, observable, interned, slot and generic fields are left at their defaults.
+pub fn render_props_arbitrary(props_struct_name: &Ident, cmp: &Component) -> TokenStream {
+    let arbitrary_props = cmp
+        .props
+        .iter()
+        .filter(|prop| {
+            prop.is_signal.is_none()
+                && prop.emits.is_none()
+                && prop.generics.is_none()
+                && !prop.binding
+                && prop.observable.is_none()
+                && !prop.intern
+                && !prop.slot
+                && !prop.skip
+        })
+        .collect::<Vec<_>>();
+
+    // the field strategies are nested into pairs, as proptest only implements `Strategy` for tuples of up to 12 elements
+    let strategy =
+        arbitrary_props
+            .iter()
+            .rev()
+            .fold(quote! {::proptest::strategy::Just(())}, |rest, prop| {
+                let type_ = compute_prop_type_ident(cmp, prop, false);
+
+                if prop.default.is_some() {
+                    quote! {(::proptest::arbitrary::any::<#type_>(), #rest)}
+                } else {
+                    quote! {(::proptest::arbitrary::any::<Option<#type_>>(), #rest)}
+                }
+            });
+    let pattern = arbitrary_props
+        .iter()
+        .rev()
+        .fold(quote! {()}, |rest, prop| {
+            let name = &prop.name;
+
+            quote! {(#name, #rest)}
+        });
+    let assignments = arbitrary_props.iter().map(|prop| {
+        let name = &prop.name;
+
+        quote! {props.#name = #name;}
+    });
+
+    quote! {
+        #[automatically_derived]
+        impl ::proptest::arbitrary::Arbitrary for #props_struct_name {
+            type Parameters = ();
+            type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+
+            fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+                ::proptest::strategy::Strategy::boxed(::proptest::strategy::Strategy::prop_map(
+                    #strategy,
+                    |#pattern| {
+                        #[allow(unused_mut)]
+                        let mut props = #props_struct_name::new();
+                        #(#assignments)*
+                        props
+                    },
+                ))
+            }
+        }
+    }
+}
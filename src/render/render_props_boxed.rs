@@ -0,0 +1,86 @@
+use crate::parse::Component;
+use crate::render::render_props_accessors::render_accessor_impls;
+use crate::render::render_props_dyn_trait::{boxed_generic_args, boxed_props_ctor};
+use crate::render::render_utils::{compute_component_generics, props_trait_name};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::TypeParam;
+
+/// Renders the `DynXProps` newtype of `boxed` components, holding the props with all signals boxed.
+/// Unlike the boxed trait object of `dyn_trait`, it is a concrete type, which every props struct with `'static` signals
+/// converts into with `From`, so props built in different ways can be stored side by side, i.e. in a `Vec<DynXProps>`.
+pub fn render_props_boxed(props_struct_name: &Ident, cmp: &Component) -> TokenStream {
+    let vis = &cmp.vis;
+    let trait_name = props_trait_name(cmp);
+    let boxed_name = Ident::new(
+        &format!("Dyn{}", props_struct_name),
+        props_struct_name.span(),
+    );
+
+    let prop_generics = cmp
+        .props
+        .iter()
+        .filter_map(|prop| prop.generics.as_ref().map(|g| g.param.clone()))
+        .collect::<Vec<_>>();
+    let prop_generics_no_defaults = prop_generics
+        .iter()
+        .cloned()
+        .map(|mut param| {
+            param.default = None;
+            param
+        })
+        .collect::<Vec<_>>();
+    let prop_generic_idents = prop_generics.iter().map(|g| &g.ident).collect::<Vec<_>>();
+
+    let (boxed_generic_args, boxed_trait_types) = boxed_generic_args(cmp);
+    let boxed_props = quote! {#props_struct_name<#(#boxed_generic_args),*>};
+
+    let generics = compute_component_generics(cmp, false, false);
+    let generic_idents = generics.iter().map(|g| &g.ident).collect::<Vec<_>>();
+    let signal_generics = generics
+        .iter()
+        .filter(|g| !prop_generic_idents.contains(&&g.ident))
+        .map(|g: &TypeParam| &g.ident);
+
+    let props_ctor = boxed_props_ctor(props_struct_name, cmp);
+
+    let (take_allow, take_async) = if cmp.async_take {
+        (quote! {#[allow(async_fn_in_trait)]}, quote! {async})
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    let accessor_impls = render_accessor_impls(cmp, quote! {self.0});
+
+    let boxed_doc = format!(
+        "[`{}`] with all signals boxed, which every props value with `'static` signals converts into",
+        props_struct_name
+    );
+
+    quote! {
+        #[doc = #boxed_doc]
+        #vis struct #boxed_name<#(#prop_generics),*>(pub #boxed_props);
+
+        #[automatically_derived]
+        impl<#(#generics),*> From<#props_struct_name<#(#generic_idents),*>> for #boxed_name<#(#prop_generic_idents),*>
+        where
+            #(#signal_generics: 'static,)*
+        {
+            fn from(props: #props_struct_name<#(#generic_idents),*>) -> Self {
+                Self(#props_ctor)
+            }
+        }
+
+        #[automatically_derived]
+        impl<#(#prop_generics_no_defaults),*> #trait_name for #boxed_name<#(#prop_generic_idents),*> {
+            #(#boxed_trait_types)*
+
+            #take_allow
+            #take_async fn take(self) -> #boxed_props {
+                self.0
+            }
+
+            #accessor_impls
+        }
+    }
+}
@@ -1,21 +1,35 @@
-use crate::parse::{Component, SignalType};
-use crate::render::render_utils::{compute_component_generics, compute_prop_type_ident};
+use crate::parse::{Component, Prop, SignalType};
+use crate::render::render_props_accessors::{render_accessor_decls, render_accessor_impls};
+use crate::render::render_utils::{
+    compute_component_generics, compute_prop_type_ident, get_prop_signal_default_type, intern_expr,
+    leading_args, leading_params, lifetimes_marker, loading_field, loading_module,
+    prop_is_optional, prop_mask_field, prop_mask_name, props_trait_name, required_generics,
+    required_param_name, setter_name, setter_value_type, store_signal_expr,
+};
+use convert_case::{Case, Casing};
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::Type;
+use syn::{Expr, Type};
 
 pub fn render_prop_builder_struct(props_struct_name: Ident, cmp: &Component) -> TokenStream {
+    let futures_signals = &cmp.futures_signals;
     let generics = compute_component_generics(cmp, true, false);
+    let required_params = required_generics(cmp, true);
+    let lifetimes = leading_params(cmp);
+    let lifetime_args = leading_args(cmp);
 
     let props = cmp.props.iter().map(|prop| {
         let name = &prop.name;
-        let type_ = compute_prop_type_ident(prop, false);
+        let type_ = compute_prop_type_ident(cmp, prop, false);
 
-        let type_: Type = if let Some(_default) = &prop.default {
-            type_
-        } else {
+        let type_: Type = if prop.required {
+            let param = required_param_name(name);
+            syn::parse_quote!(#param)
+        } else if prop_is_optional(prop) {
             syn::parse_str::<Type>(format!("Option<{}>", quote! {#type_}).as_str())
                 .expect("failed to parse prop type")
+        } else {
+            type_
         };
 
         quote! {
@@ -26,17 +40,62 @@ pub fn render_prop_builder_struct(props_struct_name: Ident, cmp: &Component) ->
     let props_ctor = cmp.props.iter().map(|prop| {
         let name = &prop.name;
 
-        let init_val = if prop.default.is_some() {
-            let default = prop.default.as_ref().unwrap();
+        let init_val = if let Some(default) = &prop.default {
+            let default = if prop.default_once {
+                let type_ = &prop.type_;
+                let type_ = match prop.is_signal {
+                    Some(SignalType::Vec) => quote! {Vec<#type_>},
+                    _ => quote! {#type_},
+                };
+
+                quote! {{
+                    static DEFAULT: ::std::sync::OnceLock<#type_> = ::std::sync::OnceLock::new();
+                    ::core::clone::Clone::clone(DEFAULT.get_or_init(|| #default))
+                }}
+            } else if prop.generic_default {
+                render_generic_default(cmp, prop, default)
+            } else {
+                quote! {#default}
+            };
+
+            let default = if prop.intern {
+                intern_expr(cmp, quote! {::core::convert::Into::into(#default)})
+            } else if prop.cow {
+                quote! {::core::convert::Into::into(#default)}
+            } else {
+                quote! {#default}
+            };
 
             if let Some(sig) = &prop.is_signal {
-                match sig {
-                    SignalType::Item => quote! {futures_signals::signal::always(#default)},
-                    SignalType::Vec => quote! {futures_signals::signal_vec::always(#default)},
-                }
+                let default = if prop.fallback.is_some() && !prop.default_async {
+                    quote! {Some(#default)}
+                } else {
+                    default
+                };
+
+                let always = match sig {
+                    // the boxed signal is coerced before anything wraps it
+                    SignalType::Item if prop.default_signal => {
+                        let boxed_type = get_prop_signal_default_type(cmp, prop, sig, &prop.type_);
+                        quote! {(::std::boxed::Box::pin(#default) as #boxed_type)}
+                    }
+                    // the future counts as pending until it resolves, or is dropped by a setter replacing the default
+                    SignalType::Item if prop.default_async => {
+                        let boxed_type = get_prop_signal_default_type(cmp, prop, sig, &prop.type_);
+                        let module = loading_module(cmp);
+                        let pending = loading_field(cmp);
+                        quote! {(::std::boxed::Box::pin(#futures_signals::signal::from_future(#module::track(&#pending, #default))) as #boxed_type)}
+                    }
+                    SignalType::Item => quote! {#futures_signals::signal::always(#default)},
+                    SignalType::Vec => quote! {#futures_signals::signal_vec::always(#default)},
+                };
+
+                store_signal_expr(cmp, prop, sig, always)
             } else {
                 quote! {#default}
             }
+        } else if prop.required {
+            quote! {()}
         } else {
             quote! {None}
         };
@@ -46,6 +105,57 @@ pub fn render_prop_builder_struct(props_struct_name: Ident, cmp: &Component) ->
         }
     });
 
+    let (marker_field, marker_init) = match lifetimes_marker(cmp) {
+        Some(marker) => {
+            let marked_lifetimes = cmp.lifetimes.iter().map(|l| &l.lifetime);
+            let marked_consts = cmp.const_params.iter().map(|c| &c.ident);
+
+            (
+                quote! {
+                    #[doc(hidden)]
+                    pub #marker: ::core::marker::PhantomData<(#(&#marked_lifetimes (),)* #([(); #marked_consts],)*)>,
+                },
+                quote! {#marker: ::core::marker::PhantomData,},
+            )
+        }
+        None => (quote! {}, quote! {}),
+    };
+
+    let (mask_field, mask_init) = match prop_mask_field(cmp) {
+        Some(mask) => {
+            let mask_name = prop_mask_name(&props_struct_name);
+
+            (
+                quote! {
+                    #[doc(hidden)]
+                    pub #mask: #mask_name,
+                },
+                quote! {#mask: #mask_name::empty(),},
+            )
+        }
+        None => (quote! {}, quote! {}),
+    };
+
+    let loading_struct_field = loading_field(cmp).map(|pending| {
+        quote! {
+            #[doc(hidden)]
+            pub #pending: #futures_signals::signal::Mutable<usize>,
+        }
+    });
+
+    // all async defaults are pending when the props are created
+    let (loading_init, loading_field_init) = match loading_field(cmp) {
+        Some(pending) => {
+            let count = cmp.props.iter().filter(|prop| prop.default_async).count();
+
+            (
+                quote! {let #pending = #futures_signals::signal::Mutable::new(#count);},
+                quote! {#pending,},
+            )
+        }
+        None => (quote! {}, quote! {}),
+    };
+
     let generics_params_no_self = compute_component_generics(cmp, false, false);
     let generics_params = compute_component_generics(cmp, false, true);
     let generic_idents = generics_params
@@ -57,7 +167,9 @@ pub fn render_prop_builder_struct(props_struct_name: Ident, cmp: &Component) ->
         })
         .collect::<Vec<_>>();
 
-    let trait_name = Ident::new(&format!("{}PropsTrait", cmp.name), cmp.name.span());
+    let trait_name = props_trait_name(cmp);
+    let accessor_decls = render_accessor_decls(cmp);
+    let accessor_impls = render_accessor_impls(cmp, quote! {self});
 
     let trait_types = generics_params.iter().map(|g| {
         let ident = &g.ident;
@@ -95,32 +207,305 @@ pub fn render_prop_builder_struct(props_struct_name: Ident, cmp: &Component) ->
         }
     });
 
+    // only fields without a default can be told apart from the ones the caller left out
+    let set_fields = cmp
+        .props
+        .iter()
+        .filter(|prop| prop_is_optional(prop) && !prop.injected)
+        .map(|prop| {
+            let name = &prop.name;
+
+            quote! {+ self.#name.is_some() as usize}
+        });
+
+    let (take_allow, take_async) = if cmp.async_take {
+        (quote! {#[allow(async_fn_in_trait)]}, quote! {async})
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    // sealed traits get a supertrait in a private module, so only the props struct can implement them
+    let mut supertraits = vec![];
+
+    let sealed_impl = if cmp.sealed {
+        let sealed_mod = Ident::new(
+            &format!("__{}_sealed", trait_name.to_string().to_case(Case::Snake)),
+            cmp.name.span(),
+        );
+
+        supertraits.push(quote! {#sealed_mod::Sealed});
+
+        quote! {
+            #[doc(hidden)]
+            mod #sealed_mod {
+                pub trait Sealed {}
+            }
+
+            #[automatically_derived]
+            impl<#(#lifetimes,)* #(#generics_params_no_self),*> #sealed_mod::Sealed for #props_struct_name<#(#lifetime_args,)* #(#generic_idents,)* > {}
+        }
+    } else {
+        quote! {}
+    };
+
+    // thread safe props can be moved to and shared with other threads, so fields of generic types must be as well
+    let thread_safe_bounds = if cmp.thread_safe {
+        supertraits.push(quote! {Send});
+        supertraits.push(quote! {Sync});
+
+        quote! {where Self: Send + Sync}
+    } else {
+        quote! {}
+    };
+
+    let supertraits = if supertraits.is_empty() {
+        quote! {}
+    } else {
+        quote! {: #(#supertraits)+*}
+    };
+
+    // `new()` starts out with none of the `#[required]` props set, so its type spells out the defaults of the other params
+    let required_props = cmp
+        .props
+        .iter()
+        .filter(|prop| prop.required)
+        .collect::<Vec<_>>();
+    let new_props_type = if required_props.is_empty() {
+        quote! {#props_struct_name<#(#lifetime_args),*>}
+    } else {
+        let default_args = generics.iter().map(|g| {
+            let default = &g.default;
+            quote! {#default}
+        });
+        let unset_args = required_props.iter().map(|_| quote! {()});
+
+        quote! {#props_struct_name<#(#lifetime_args,)* #(#default_args,)* #(#unset_args),*>}
+    };
+
+    // `with()` takes the `#[required]` props positionally, and sets them with their setters, so the props end up with the
+    // default generic params, which have every required prop set
+    let with_ctor = if required_props.is_empty() {
+        quote! {}
+    } else {
+        let params = required_props.iter().map(|prop| {
+            let name = &prop.name;
+            let value_type = setter_value_type(prop, &prop.type_);
+            quote! {#name: #value_type}
+        });
+        // setting a `#[try_into]` prop to a value of its own type can not fail
+        let body = required_props
+            .iter()
+            .fold(quote! {Self::new()}, |props, prop| {
+                let name = &prop.name;
+                let setter = setter_name(prop);
+
+                if prop.try_into {
+                    quote! {match #props.#setter(#name) { Ok(props) => props, Err(e) => match e {} }}
+                } else {
+                    quote! {#props.#setter(#name)}
+                }
+            });
+        let names = required_props
+            .iter()
+            .map(|prop| format!("`{}`", prop.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let doc = format!(
+            " Creates the props with the required props {} set, taking them in the order they are declared",
+            names
+        );
+
+        quote! {
+            #[doc = #doc]
+            #[allow(deprecated)]
+            pub fn with(#(#params),*) -> #props_struct_name<#(#lifetime_args),*> {
+                #body
+            }
+        }
+    };
+
+    // names the props which are not set yet, when the props are passed to the render fn too early
+    let required_diagnostic = if required_props.is_empty() {
+        quote! {}
+    } else {
+        let names = required_props
+            .iter()
+            .map(|prop| format!("`{}`", setter_name(prop)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let message = format!(
+            "not all required props of `{}` are set on `{{Self}}`",
+            cmp.name
+        );
+        let label = format!("the required props {} must be set first", names);
+        let note = format!(
+            "required props are set with their setters, i.e. `.{}(...)`",
+            setter_name(required_props[0])
+        );
+
+        quote! {
+            #[diagnostic::on_unimplemented(message = #message, label = #label, note = #note)]
+        }
+    };
+
+    // `derive(Default)` components have a default for every generic param, which `new()` spells out
+    let default_impl = if cmp.derive_default {
+        quote! {
+            #[automatically_derived]
+            impl<#(#lifetimes),*> Default for #new_props_type {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // a free function is easier to pass to combinators than `XProps::new`
+    let vis = &cmp.vis;
+    let props_fn_name = Ident::new(
+        &format!("{}_props", cmp.name.to_string().to_case(Case::Snake)),
+        cmp.name.span(),
+    );
+    let props_fn_doc = format!(
+        "Creates the default [`{}`], same as `{}::new()`",
+        props_struct_name, props_struct_name
+    );
+
+    // with `builder`, the component itself is an uninhabited type, giving the props an entry point named after the component
+    let cmp_name = &cmp.name;
+    let builder_entry = if !cmp.builder {
+        quote! {}
+    } else {
+        let cmp_doc = format!(
+            " The `{}` component, whose props are built by [`{}::builder()`]",
+            cmp_name, cmp_name
+        );
+        let builder_doc = format!(
+            " Creates the default [`{}`], same as `{}::new()`",
+            props_struct_name, props_struct_name
+        );
+
+        quote! {
+            #[doc = #cmp_doc]
+            #vis enum #cmp_name {}
+
+            #[automatically_derived]
+            impl #cmp_name {
+                #[doc = #builder_doc]
+                pub fn builder<#(#lifetimes),*>() -> #new_props_type {
+                    #props_struct_name::new()
+                }
+            }
+        }
+    };
+
     quote! {
-        pub trait #trait_name {
+        #sealed_impl
+
+        #required_diagnostic
+        #vis trait #trait_name<#(#lifetimes),*> #supertraits {
             #(#trait_types)*
 
-            fn take(self) -> #props_struct_name<#(#unpack_trait_params_selfed,)* >;
+            #take_allow
+            #take_async fn take(self) -> #props_struct_name<#(#lifetime_args,)* #(#unpack_trait_params_selfed,)* >;
+
+            #accessor_decls
         }
 
         #(#docs)*
-        pub struct #props_struct_name<#(#generics,)* > {
+        #vis struct #props_struct_name<#(#lifetimes,)* #(#generics,)* #(#required_params,)* > {
             #(#props)*
+            #marker_field
+            #mask_field
+            #loading_struct_field
         }
 
-        impl<#(#generics_params_no_self),*> #trait_name for #props_struct_name<#(#generic_idents,)* > {
+        #[automatically_derived]
+        impl<#(#lifetimes,)* #(#generics_params_no_self),*> #trait_name<#(#lifetime_args),*> for #props_struct_name<#(#lifetime_args,)* #(#generic_idents,)* >
+        #thread_safe_bounds
+        {
             #(#trait_type_impls)*
 
-            fn take(self) -> #props_struct_name<#(#unpack_trait_params,)* > {
+            #take_async fn take(self) -> #props_struct_name<#(#lifetime_args,)* #(#unpack_trait_params,)* > {
                 self
             }
+
+            #accessor_impls
         }
 
-        impl #props_struct_name {
+        #[automatically_derived]
+        impl<#(#lifetimes,)* #(#generics_params_no_self),*> #props_struct_name<#(#lifetime_args,)* #(#generic_idents,)* > {
+            /// The number of optional fields, i.e. fields without a default, which have been explicitly set
+            pub fn count_set_fields(&self) -> usize {
+                0 #(#set_fields)*
+            }
+        }
+
+        #[automatically_derived]
+        impl<#(#lifetimes),*> #new_props_type {
             pub fn new() -> Self {
+                #loading_init
+
                 Self {
                     #(#props_ctor)*
+                    #marker_init
+                    #mask_init
+                    #loading_field_init
                 }
             }
+
+            #with_ctor
+        }
+
+        #default_impl
+
+        #[doc = #props_fn_doc]
+        #vis fn #props_fn_name<#(#lifetimes),*>() -> #new_props_type {
+            #props_struct_name::new()
         }
+
+        #builder_entry
     }
 }
+
+/// Evaluates a default naming generic params of the component by a function generic over all of them, which `new()`
+/// calls with their default types.
+/// The expression can thereby only use what the bounds of the params provide, i.e. `T::default()` needs `T: Default`,
+/// which rustc reports at the expression, rather than it silently depending on the default types.
+fn render_generic_default(cmp: &Component, prop: &Prop, default: &Expr) -> TokenStream {
+    let type_params = cmp
+        .props
+        .iter()
+        .filter_map(|prop| prop.generics.as_ref())
+        .map(|generic| generic.param.clone())
+        .collect::<Vec<_>>();
+    let type_args = type_params.iter().map(|param| &param.default);
+    let type_params = type_params.iter().cloned().map(|mut param| {
+        param.eq_token = None;
+        param.default = None;
+        param
+    });
+    let lifetimes = &cmp.lifetimes;
+    let const_args = cmp.const_params.iter().map(|param| &param.ident);
+    let const_params = cmp.const_params.iter().cloned().map(|mut param| {
+        param.eq_token = None;
+        param.default = None;
+        param
+    });
+    let type_ = &prop.type_;
+    let type_ = match prop.is_signal {
+        Some(SignalType::Vec) => quote! {Vec<#type_>},
+        _ => quote! {#type_},
+    };
+
+    quote! {{
+        #[allow(clippy::extra_unused_type_parameters, clippy::extra_unused_lifetimes)]
+        fn generic_default<#(#lifetimes,)* #(#type_params,)* #(#const_params),*>() -> #type_ {
+            #default
+        }
+
+        generic_default::<#(#type_args,)* #({ #const_args }),*>()
+    }}
+}
@@ -0,0 +1,60 @@
+use crate::parse::Component;
+use crate::render::render_utils::{
+    compute_component_generics, compute_prop_type_ident, erase_lifetimes, leading_args,
+    leading_params, lifetimes_marker, loading_field, prop_is_optional, prop_mask_field,
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+
+/// Renders a `Clone` impl for the props struct, bounded on each of its stored field types being `Clone`.
+/// It is generated for `clone_signals` and `derive(Clone)` components, with the bounds
+/// higher-ranked like the ones of `eq_ignoring_signals()`, and a field type without `Clone` only makes the impl unavailable.
+pub fn render_props_clone(props_struct_name: &Ident, cmp: &Component) -> TokenStream {
+    let generics = compute_component_generics(cmp, false, false);
+    let generic_idents = generics.iter().map(|g| &g.ident).collect::<Vec<_>>();
+    let lifetimes = leading_params(cmp);
+    let lifetime_args = leading_args(cmp);
+    let lifetimes_marker = lifetimes_marker(cmp);
+    let mask_field = prop_mask_field(cmp);
+    let loading_field = loading_field(cmp);
+
+    let clone_bounds = cmp.props.iter().map(|prop| {
+        let type_ = erase_lifetimes(
+            cmp,
+            compute_prop_type_ident(cmp, prop, false).to_token_stream(),
+        );
+
+        if prop_is_optional(prop) {
+            quote! {for<'__any> Option<#type_>: Clone}
+        } else {
+            quote! {for<'__any> #type_: Clone}
+        }
+    });
+
+    let field_clones = cmp
+        .props
+        .iter()
+        .map(|prop| &prop.name)
+        .chain(&lifetimes_marker)
+        .chain(&mask_field)
+        .chain(&loading_field)
+        .map(|name| {
+            quote! {
+                #name: self.#name.clone(),
+            }
+        });
+
+    quote! {
+        #[automatically_derived]
+        impl<#(#lifetimes,)* #(#generics),*> Clone for #props_struct_name<#(#lifetime_args,)* #(#generic_idents),*>
+        where
+            #(#clone_bounds,)*
+        {
+            fn clone(&self) -> Self {
+                #props_struct_name {
+                    #(#field_clones)*
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::parse::{Component, Prop, SignalType};
+use crate::render::render_utils::{
+    compute_component_generics, compute_prop_type_ident, erase_lifetimes, leading_args,
+    leading_params, prop_is_optional, required_generics, required_param_name,
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+
+/// Renders a `Debug` impl for the props struct, printing every field by name.
+/// Signals, slots and event sinks print a placeholder, as they can not be inspected, while the other fields print their
+/// values, bounded on their stored types being `Debug` the same higher-ranked way as `Clone`.
+/// Unlike the other impls, it covers the props with unset `#[required]` fields too, so half-built props can be printed.
+pub fn render_props_debug(props_struct_name: &Ident, cmp: &Component) -> TokenStream {
+    let mut generics = compute_component_generics(cmp, false, false);
+    generics.extend(required_generics(cmp, false));
+    let generic_idents = generics.iter().map(|g| &g.ident).collect::<Vec<_>>();
+    let lifetimes = leading_params(cmp);
+    let lifetime_args = leading_args(cmp);
+
+    let placeholder = |prop: &Prop| match prop.is_signal {
+        Some(SignalType::Item) => Some("<signal>"),
+        Some(SignalType::Vec) => Some("<signal_vec>"),
+        None if prop.slot => Some("<slot>"),
+        None if prop.emits.is_some() => Some("<handler>"),
+        None => None,
+    };
+
+    let debug_bounds = cmp
+        .props
+        .iter()
+        .filter(|prop| placeholder(prop).is_none())
+        .map(|prop| {
+            if prop.required {
+                let param = required_param_name(&prop.name);
+                return quote! {#param: ::core::fmt::Debug};
+            }
+
+            let type_ = erase_lifetimes(
+                cmp,
+                compute_prop_type_ident(cmp, prop, false).to_token_stream(),
+            );
+
+            if prop_is_optional(prop) {
+                quote! {for<'__any> Option<#type_>: ::core::fmt::Debug}
+            } else {
+                quote! {for<'__any> #type_: ::core::fmt::Debug}
+            }
+        });
+
+    let debug_fields = cmp.props.iter().map(|prop| {
+        let name = &prop.name;
+        let name_str = name.to_string();
+
+        match placeholder(prop) {
+            Some(placeholder) => quote! {.field(#name_str, &format_args!(#placeholder))},
+            None => quote! {.field(#name_str, &self.#name)},
+        }
+    });
+    let props_struct_name_str = props_struct_name.to_string();
+
+    quote! {
+        #[automatically_derived]
+        impl<#(#lifetimes,)* #(#generics),*> ::core::fmt::Debug for #props_struct_name<#(#lifetime_args,)* #(#generic_idents),*>
+        where
+            #(#debug_bounds,)*
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(#props_struct_name_str)
+                    #(#debug_fields)*
+                    .finish()
+            }
+        }
+    }
+}
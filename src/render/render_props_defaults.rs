@@ -0,0 +1,34 @@
+use crate::parse::Component;
+use crate::render::render_utils::tokens_to_string;
+use convert_case::{Case, Casing};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Renders the `{COMPONENT}_DEFAULTS` const, pairing the name of every field with a default with its default expression
+pub fn render_props_defaults(cmp: &Component) -> TokenStream {
+    let name = Ident::new(
+        &format!(
+            "{}_DEFAULTS",
+            cmp.name.to_string().to_case(Case::UpperSnake)
+        ),
+        cmp.name.span(),
+    );
+    let vis = &cmp.vis;
+    let doc = format!(
+        "The fields of the `{}` component which have a default, along with their default expression",
+        cmp.name
+    );
+
+    let defaults = cmp.props.iter().filter_map(|prop| {
+        let default = prop.default.as_ref()?;
+        let name = prop.name.to_string();
+        let default = tokens_to_string(default);
+
+        Some(quote! {(#name, #default)})
+    });
+
+    quote! {
+        #[doc = #doc]
+        #vis const #name: &[(&str, &str)] = &[#(#defaults),*];
+    }
+}
@@ -0,0 +1,183 @@
+use crate::parse::Component;
+use crate::render::render_utils::{
+    compute_component_generics, compute_prop_type_ident, leading_args, leading_params,
+    prop_is_optional,
+};
+use convert_case::{Case, Casing};
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::GenericParam;
+
+/// Renders the `XPropsDelta` struct, along with the `diff()` method on the props struct producing it, and the
+/// `XPropsChange` enum, along with the `changes()` method listing the updates to apply to a retained element.
+/// Only non-signal props are compared (bindings and observables are `Mutable`s and slots are elements, which have no equality), and `diff()` and `changes()` are bounded on each of their stored types being `PartialEq`.
+pub fn render_props_delta(props_struct_name: &Ident, cmp: &Component) -> TokenStream {
+    let vis = &cmp.vis;
+    let delta_struct_name = Ident::new(
+        &format!("{}Delta", props_struct_name),
+        props_struct_name.span(),
+    );
+    let generics = compute_component_generics(cmp, false, false);
+    let generic_idents = generics.iter().map(|g| &g.ident).collect::<Vec<_>>();
+    let lifetimes = leading_params(cmp);
+    let lifetime_args = leading_args(cmp);
+
+    let compared_props = cmp
+        .props
+        .iter()
+        .filter(|prop| {
+            prop.is_signal.is_none() && !prop.binding && prop.observable.is_none() && !prop.slot
+        })
+        .collect::<Vec<_>>();
+
+    let delta_fields = compared_props.iter().map(|prop| {
+        let name = &prop.name;
+        let doc = format!("`true` if `{}` differs between the compared props", name);
+
+        quote! {
+            #[doc = #doc]
+            pub #name: bool,
+        }
+    });
+
+    let eq_bounds = compared_props.iter().map(|prop| {
+        let type_ = compute_prop_type_ident(cmp, prop, false);
+
+        if prop_is_optional(prop) {
+            quote! {Option<#type_>: PartialEq}
+        } else {
+            quote! {#type_: PartialEq}
+        }
+    });
+
+    let field_diffs = compared_props.iter().map(|prop| {
+        let name = &prop.name;
+
+        quote! {
+            #name: self.#name != other.#name,
+        }
+    });
+
+    let changed_checks = compared_props.iter().map(|prop| {
+        let name = &prop.name;
+
+        quote! {self.#name}
+    });
+
+    let change_name = Ident::new(
+        &format!("{}Change", props_struct_name),
+        props_struct_name.span(),
+    );
+
+    // the change enum only has the generics of the compared fields, and the lifetimes and const params their types use
+    let change_generics = compared_props
+        .iter()
+        .filter_map(|prop| prop.generics.as_ref().map(|g| &g.param.ident))
+        .collect::<Vec<_>>();
+    let change_lifetimes = leading_params(cmp)
+        .into_iter()
+        .filter(|param| {
+            let lifetime = match param {
+                GenericParam::Lifetime(l) => l.lifetime.to_string(),
+                GenericParam::Const(c) => c.ident.to_string(),
+                GenericParam::Type(_) => return false,
+            };
+
+            compared_props.iter().any(|prop| {
+                let type_ = &prop.type_;
+                quote! {#type_}
+                    .to_string()
+                    .split(|c: char| !c.is_alphanumeric() && c != '_' && c != '\'')
+                    .any(|token| token == lifetime)
+            })
+        })
+        .collect::<Vec<_>>();
+    let change_lifetime_args = change_lifetimes.iter().map(|param| match param {
+        GenericParam::Const(c) => c.ident.to_token_stream(),
+        param => param.to_token_stream(),
+    });
+    let change_type = quote! {#change_name<#(#change_lifetime_args,)* #(#change_generics),*>};
+
+    let change_variants = compared_props.iter().map(|prop| {
+        let name = &prop.name;
+        let variant = Ident::new(&name.to_string().to_case(Case::Pascal), name.span());
+        let type_ = compute_prop_type_ident(cmp, prop, false);
+        let type_ = if prop_is_optional(prop) {
+            quote! {Option<#type_>}
+        } else {
+            quote! {#type_}
+        };
+        let doc = format!("`{}` changed to the contained value", name);
+
+        quote! {
+            #[doc = #doc]
+            #variant(#type_),
+        }
+    });
+
+    let field_changes = compared_props.iter().map(|prop| {
+        let name = &prop.name;
+        let variant = Ident::new(&name.to_string().to_case(Case::Pascal), name.span());
+
+        quote! {
+            if self.#name != new.#name {
+                changes.push(#change_name::#variant(new.#name));
+            }
+        }
+    });
+
+    let change_doc = format!(
+        "An update of a non-signal field of [`{}`], as listed by `changes()`",
+        props_struct_name
+    );
+
+    let delta_doc = format!(
+        "The non-signal fields that differ between two [`{}`] values, as computed by `diff()`",
+        props_struct_name
+    );
+
+    quote! {
+        #[doc = #delta_doc]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        #vis struct #delta_struct_name {
+            #(#delta_fields)*
+        }
+
+        #[doc = #change_doc]
+        #vis enum #change_name<#(#change_lifetimes,)* #(#change_generics),*> {
+            #(#change_variants)*
+        }
+
+        #[automatically_derived]
+        impl #delta_struct_name {
+            /// `true` if any of the compared fields differ
+            pub fn has_changes(&self) -> bool {
+                false #(|| #changed_checks)*
+            }
+        }
+
+        #[automatically_derived]
+        impl<#(#lifetimes,)* #(#generics),*> #props_struct_name<#(#lifetime_args,)* #(#generic_idents),*>
+        where
+            #(#eq_bounds,)*
+        {
+            /// Compares the non-signal fields of two props values, reporting which of them differ
+            pub fn diff(&self, other: &Self) -> #delta_struct_name {
+                #delta_struct_name {
+                    #(#field_diffs)*
+                }
+            }
+
+            /// Lists the non-signal fields of `new` which differ from this props value, so an element rendered from
+            /// this props value can be updated in place, rather than being rendered again
+            pub fn changes(&self, new: Self) -> Vec<#change_type> {
+                #[allow(unused_mut)]
+                let mut changes = Vec::new();
+
+                #(#field_changes)*
+
+                changes
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use crate::parse::{Component, SignalType};
+use crate::render::render_props_accessors::{
+    render_accessor_decls, render_accessor_impls, render_accessor_impls_with,
+};
+use crate::render::render_utils::{
+    compute_component_generics, prop_is_send, prop_mask_field, props_trait_name,
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Type, TypeParam};
+
+/// Renders the object safe `DynXPropsTrait`, which hands out the props with all signals boxed.
+/// It is implemented for every props struct with `'static` signals, and `XPropsTrait` is in turn implemented for the boxed trait object,
+/// so boxed props can be passed straight to the render function.
+pub fn render_props_dyn_trait(props_struct_name: &Ident, cmp: &Component) -> TokenStream {
+    let vis = &cmp.vis;
+    let trait_name = props_trait_name(cmp);
+    let dyn_trait_name = Ident::new(&format!("Dyn{}", trait_name), trait_name.span());
+
+    let prop_generics = cmp
+        .props
+        .iter()
+        .filter_map(|prop| prop.generics.as_ref().map(|g| g.param.clone()))
+        .collect::<Vec<_>>();
+    let prop_generics_no_defaults = prop_generics
+        .iter()
+        .cloned()
+        .map(|mut param| {
+            param.default = None;
+            param
+        })
+        .collect::<Vec<_>>();
+    let prop_generic_idents = prop_generics.iter().map(|g| &g.ident).collect::<Vec<_>>();
+
+    let (boxed_generic_args, boxed_trait_types) = boxed_generic_args(cmp);
+    let boxed_props = quote! {#props_struct_name<#(#boxed_generic_args),*>};
+
+    let generics = compute_component_generics(cmp, false, false);
+    let generic_idents = generics.iter().map(|g| &g.ident).collect::<Vec<_>>();
+    let signal_generics = generics
+        .iter()
+        .filter(|g| !prop_generic_idents.contains(&&g.ident))
+        .map(|g: &TypeParam| &g.ident);
+
+    let props_ctor = boxed_props_ctor(props_struct_name, cmp);
+
+    let (take_allow, take_async) = if cmp.async_take {
+        (quote! {#[allow(async_fn_in_trait)]}, quote! {async})
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    let accessor_decls = render_accessor_decls(cmp);
+    let accessor_impls = render_accessor_impls(cmp, quote! {self});
+    // the boxed trait object forwards the accessors of the props trait to the ones of the object safe trait
+    let forwarded_accessors = render_accessor_impls_with(cmp, |prop| {
+        let name = &prop.name;
+        quote! {#dyn_trait_name::#name(&**self)}
+    });
+
+    let dyn_trait_doc = format!(
+        "Object safe counterpart of [`{}`], handing out the props with all signals boxed",
+        trait_name
+    );
+
+    quote! {
+        #[doc = #dyn_trait_doc]
+        #vis trait #dyn_trait_name<#(#prop_generics),*> {
+            fn take_boxed(self: Box<Self>) -> #boxed_props;
+
+            #accessor_decls
+        }
+
+        #[automatically_derived]
+        impl<#(#generics),*> #dyn_trait_name<#(#prop_generic_idents),*> for #props_struct_name<#(#generic_idents),*>
+        where
+            #(#signal_generics: 'static,)*
+        {
+            fn take_boxed(self: Box<Self>) -> #boxed_props {
+                let props = *self;
+
+                #props_ctor
+            }
+
+            #accessor_impls
+        }
+
+        #[automatically_derived]
+        impl<#(#prop_generics_no_defaults),*> #trait_name for Box<dyn #dyn_trait_name<#(#prop_generic_idents),*>> {
+            #(#boxed_trait_types)*
+
+            #take_allow
+            #take_async fn take(self) -> #boxed_props {
+                self.take_boxed()
+            }
+
+            #forwarded_accessors
+        }
+    }
+}
+
+/// The generic args of the props struct with every signal replaced by its boxed form (`LocalBoxSignal<'static, T>`, or
+/// `BoxSignal` for `Send` signals), along with the associated types of the props trait for them
+pub fn boxed_generic_args(cmp: &Component) -> (Vec<TokenStream>, Vec<TokenStream>) {
+    let futures_signals = &cmp.futures_signals;
+    let mut boxed_generic_args = vec![];
+    let mut boxed_trait_types = vec![];
+
+    for prop in cmp.props.iter() {
+        if let Some(generic) = &prop.generics {
+            let ident = &generic.param.ident;
+
+            boxed_generic_args.push(quote! {#ident});
+            boxed_trait_types.push(quote! {type #ident = #ident;});
+        }
+
+        if let Some(signal_type) = &prop.is_signal {
+            let ty_ = &prop.type_;
+            let boxed: Type = match (signal_type, prop_is_send(prop)) {
+                (SignalType::Item, false) => {
+                    syn::parse_quote!(#futures_signals::signal::LocalBoxSignal<'static, #ty_>)
+                }
+                (SignalType::Item, true) => {
+                    syn::parse_quote!(#futures_signals::signal::BoxSignal<'static, #ty_>)
+                }
+                (SignalType::Vec, false) => {
+                    syn::parse_quote!(#futures_signals::signal_vec::LocalBoxSignalVec<'static, #ty_>)
+                }
+                (SignalType::Vec, true) => {
+                    syn::parse_quote!(#futures_signals::signal_vec::BoxSignalVec<'static, #ty_>)
+                }
+            };
+            let signal_ident = Ident::new(&format!("T{}Signal", prop.name), prop.name.span());
+
+            boxed_generic_args.push(quote! {#boxed});
+            boxed_trait_types.push(quote! {type #signal_ident = #boxed;});
+        }
+    }
+
+    (boxed_generic_args, boxed_trait_types)
+}
+
+/// Moves the fields of `props` into the props struct with boxed signals
+pub fn boxed_props_ctor(props_struct_name: &Ident, cmp: &Component) -> TokenStream {
+    let futures_signals = &cmp.futures_signals;
+    let mask_ctor = prop_mask_field(cmp).map(|mask| quote! {#mask: props.#mask,});
+
+    let props_ctor = cmp.props.iter().map(|prop| {
+        let name = &prop.name;
+
+        let boxed = match (&prop.is_signal, prop_is_send(prop)) {
+            (Some(SignalType::Item), false) => {
+                quote! {#futures_signals::signal::SignalExt::boxed_local(v)}
+            }
+            (Some(SignalType::Item), true) => {
+                quote! {#futures_signals::signal::SignalExt::boxed(v)}
+            }
+            (Some(SignalType::Vec), false) => {
+                quote! {#futures_signals::signal_vec::SignalVecExt::boxed_local(v)}
+            }
+            (Some(SignalType::Vec), true) => {
+                quote! {#futures_signals::signal_vec::SignalVecExt::boxed(v)}
+            }
+            (None, _) => return quote! {#name: props.#name,},
+        };
+
+        if prop.default.is_some() {
+            quote! {#name: { let v = props.#name; #boxed },}
+        } else {
+            quote! {#name: props.#name.map(|v| #boxed),}
+        }
+    });
+
+    quote! {
+        #props_struct_name {
+            #(#props_ctor)*
+            #mask_ctor
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::parse::Component;
+use crate::render::render_utils::compute_prop_type_ident;
+use convert_case::{Case, Casing};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{GenericArgument, PathArguments, Type};
+
+/// Renders the `XDynValue` enum, with a variant per distinct type of the plain fields, the `XDynError` enum,
+/// and `XProps::from_dynamic()`, which builds the props from a string keyed map of such values.
+/// Signal, event sink, binding, observable, interned, slot, skipped and generic fields are left at their defaults, and setting them through the map is an error.
+pub fn render_props_dynamic(props_struct_name: &Ident, cmp: &Component) -> TokenStream {
+    let vis = &cmp.vis;
+    let value_name = Ident::new(&format!("{}DynValue", cmp.name), cmp.name.span());
+    let error_name = Ident::new(&format!("{}DynError", cmp.name), cmp.name.span());
+
+    let dynamic_props = cmp
+        .props
+        .iter()
+        .filter(|prop| {
+            prop.is_signal.is_none()
+                && prop.emits.is_none()
+                && prop.generics.is_none()
+                && !prop.binding
+                && prop.observable.is_none()
+                && !prop.intern
+                && !prop.slot
+                && !prop.skip
+        })
+        .map(|prop| (prop, compute_prop_type_ident(cmp, prop, false)))
+        .collect::<Vec<_>>();
+
+    // fields of the same type share a variant
+    let mut variants: Vec<(Ident, Type)> = vec![];
+
+    for (_, type_) in dynamic_props.iter() {
+        let variant = variant_name(type_);
+
+        if !variants.iter().any(|(v, _)| *v == variant) {
+            variants.push((variant, type_.clone()));
+        }
+    }
+
+    let variant_decls = variants
+        .iter()
+        .map(|(variant, type_)| quote! {#variant(#type_),});
+    let variant_froms = variants.iter().map(|(variant, type_)| {
+        quote! {
+            #[automatically_derived]
+            impl ::core::convert::From<#type_> for #value_name {
+                fn from(v: #type_) -> Self {
+                    #value_name::#variant(v)
+                }
+            }
+        }
+    });
+
+    let value_arms = dynamic_props.iter().map(|(prop, type_)| {
+        let name = &prop.name;
+        let name_str = name.to_string();
+        let variant = variant_name(type_);
+        let value = if prop.default.is_some() {
+            quote! {v}
+        } else {
+            quote! {Some(v)}
+        };
+
+        quote! {
+            (#name_str, #value_name::#variant(v)) => props.#name = #value,
+            (#name_str, _) => return Err(#error_name::TypeMismatch(key)),
+        }
+    });
+
+    let unsupported_names = cmp
+        .props
+        .iter()
+        .filter(|prop| !dynamic_props.iter().any(|(p, _)| p.name == prop.name))
+        .map(|prop| prop.name.to_string())
+        .collect::<Vec<_>>();
+    let unsupported_arm = if unsupported_names.is_empty() {
+        quote! {}
+    } else {
+        quote! {#((#unsupported_names, _))|* => return Err(#error_name::UnsupportedProp(key)),}
+    };
+
+    let value_doc = format!(
+        "A dynamically typed value of a field of [`{}`], with a variant per distinct field type",
+        props_struct_name
+    );
+    let error_doc = format!(
+        "The ways building [`{}`] from dynamic values can fail",
+        props_struct_name
+    );
+
+    quote! {
+        #[doc = #value_doc]
+        #vis enum #value_name {
+            #(#variant_decls)*
+        }
+
+        #(#variant_froms)*
+
+        #[doc = #error_doc]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #vis enum #error_name {
+            /// The component has no prop with the given name
+            UnknownProp(String),
+            /// The prop is a signal, event sink or generic field, which can't be set from a dynamic value
+            UnsupportedProp(String),
+            /// The value has a different type than the prop
+            TypeMismatch(String),
+        }
+
+        #[automatically_derived]
+        impl ::core::fmt::Display for #error_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #error_name::UnknownProp(name) => ::core::write!(f, "unknown prop `{}`", name),
+                    #error_name::UnsupportedProp(name) => ::core::write!(f, "prop `{}` can not be set from a dynamic value", name),
+                    #error_name::TypeMismatch(name) => ::core::write!(f, "mismatched type for prop `{}`", name),
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl ::std::error::Error for #error_name {}
+
+        #[automatically_derived]
+        impl #props_struct_name {
+            /// Builds the props from a map of prop names to dynamic values, leaving props missing from the map at their defaults
+            pub fn from_dynamic(map: ::std::collections::HashMap<String, #value_name>) -> Result<Self, #error_name> {
+                #[allow(unused_mut)]
+                let mut props = Self::new();
+
+                for (key, value) in map {
+                    match (key.as_str(), value) {
+                        #(#value_arms)*
+                        #unsupported_arm
+                        _ => return Err(#error_name::UnknownProp(key)),
+                    }
+                }
+
+                Ok(props)
+            }
+        }
+    }
+}
+
+/// Names the variant of a field type after its type path, i.e. `U32` for `u32` and `VecString` for `Vec<String>`
+fn variant_name(type_: &Type) -> Ident {
+    fn type_name(type_: &Type) -> String {
+        match type_ {
+            Type::Path(path) => {
+                let Some(segment) = path.path.segments.last() else {
+                    return String::new();
+                };
+                let mut name = segment.ident.to_string().to_case(Case::Pascal);
+
+                if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                    for arg in args.args.iter() {
+                        if let GenericArgument::Type(t) = arg {
+                            name.push_str(&type_name(t));
+                        }
+                    }
+                }
+
+                name
+            }
+            Type::Reference(reference) => format!("Ref{}", type_name(&reference.elem)),
+            Type::Slice(slice) => format!("Slice{}", type_name(&slice.elem)),
+            Type::Array(array) => format!("Array{}", type_name(&array.elem)),
+            Type::Tuple(tuple) => tuple
+                .elems
+                .iter()
+                .fold("Tuple".to_string(), |name, t| name + &type_name(t)),
+            Type::Paren(paren) => type_name(&paren.elem),
+            _ => "Value".to_string(),
+        }
+    }
+
+    Ident::new(&type_name(type_), proc_macro2::Span::call_site())
+}
@@ -0,0 +1,70 @@
+use crate::parse::Component;
+use crate::render::render_utils::{
+    compute_component_generics, compute_prop_type_ident, erase_lifetimes, leading_args,
+    leading_params, prop_is_optional,
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+
+/// Renders `eq_ignoring_signals()` on the props struct, comparing the non-signal fields of two props values.
+/// Like `diff()` of `delta` components, it leaves out bindings, observables, slots and event sinks, which have no equality, and is
+/// bounded on each of the compared stored types being `PartialEq`.
+/// As it is generated for every component, the bounds are higher-ranked, so a field type without `PartialEq` only makes
+/// the method unavailable, rather than failing to compile as a trivially false bound.
+/// The lifetimes of the component are replaced by the higher-ranked one, as bounds naming them are ambiguous with the
+/// impls of `PartialEq` once the lifetimes have outlives bounds.
+pub fn render_props_eq(props_struct_name: &Ident, cmp: &Component) -> TokenStream {
+    let compared_props = cmp
+        .props
+        .iter()
+        .filter(|prop| {
+            prop.is_signal.is_none()
+                && !prop.binding
+                && prop.observable.is_none()
+                && !prop.slot
+                && prop.emits.is_none()
+        })
+        .collect::<Vec<_>>();
+
+    if compared_props.is_empty() {
+        return quote! {};
+    }
+
+    let generics = compute_component_generics(cmp, false, false);
+    let generic_idents = generics.iter().map(|g| &g.ident).collect::<Vec<_>>();
+    let lifetimes = leading_params(cmp);
+    let lifetime_args = leading_args(cmp);
+
+    let eq_bounds = compared_props.iter().map(|prop| {
+        let type_ = erase_lifetimes(
+            cmp,
+            compute_prop_type_ident(cmp, prop, false).to_token_stream(),
+        );
+
+        if prop_is_optional(prop) {
+            quote! {for<'__any> Option<#type_>: PartialEq}
+        } else {
+            quote! {for<'__any> #type_: PartialEq}
+        }
+    });
+
+    let field_eqs = compared_props.iter().map(|prop| {
+        let name = &prop.name;
+
+        quote! {self.#name == other.#name}
+    });
+
+    quote! {
+        #[automatically_derived]
+        impl<#(#lifetimes,)* #(#generics),*> #props_struct_name<#(#lifetime_args,)* #(#generic_idents),*>
+        where
+            #(#eq_bounds,)*
+        {
+            /// Compares the non-signal fields of two props values, treating the signals of both as always equal.
+            /// Useful for deciding whether a component must be rendered again, when only its static configuration matters.
+            pub fn eq_ignoring_signals(&self, other: &Self) -> bool {
+                true #(&& #field_eqs)*
+            }
+        }
+    }
+}
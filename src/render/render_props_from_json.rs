@@ -0,0 +1,53 @@
+use crate::parse::Component;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Renders `XProps::from_json()`, which deserializes the plain fields of the props from a JSON object.
+/// Signal, event sink, binding, observable, interned, slot and generic fields, as well as fields missing from the object, are left at their defaults.
+pub fn render_props_from_json(props_struct_name: &Ident, cmp: &Component) -> TokenStream {
+    let field_reads = cmp
+        .props
+        .iter()
+        .filter(|prop| {
+            prop.is_signal.is_none()
+                && prop.emits.is_none()
+                && prop.generics.is_none()
+                && !prop.binding
+                && prop.observable.is_none()
+                && !prop.intern
+                && !prop.slot
+                && !prop.skip
+        })
+        .map(|prop| {
+            let name = &prop.name;
+            let name_str = name.to_string();
+            let value = if prop.default.is_some() {
+                quote! {::serde_json::from_value(v)?}
+            } else {
+                quote! {Some(::serde_json::from_value(v)?)}
+            };
+
+            quote! {
+                if let Some(v) = object.remove(#name_str) {
+                    props.#name = #value;
+                }
+            }
+        });
+
+    quote! {
+        #[automatically_derived]
+        impl #props_struct_name {
+            /// Builds the props from a JSON object, deserializing its plain fields, and leaving the rest at their defaults
+            pub fn from_json(json: &str) -> Result<Self, ::serde_json::Error> {
+                #[allow(unused_mut, unused_variables)]
+                let mut object: ::serde_json::Map<String, ::serde_json::Value> = ::serde_json::from_str(json)?;
+                #[allow(unused_mut)]
+                let mut props = Self::new();
+
+                #(#field_reads)*
+
+                Ok(props)
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::parse::{Component, ImplTrait};
+use crate::render::render_utils::{compute_component_generics, leading_args, leading_params};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Renders an impl of a framework trait for the props struct, whose single method passes the props on to the render fn.
+/// This makes the props value itself usable wherever the framework expects its trait.
+pub fn render_props_impl_trait(
+    props_struct_name: &Ident,
+    cmp: &Component,
+    impl_trait: &ImplTrait,
+) -> TokenStream {
+    let generics = compute_component_generics(cmp, false, false);
+    let generic_idents = generics.iter().map(|g| &g.ident);
+    let lifetimes = leading_params(cmp);
+    let lifetime_args = leading_args(cmp);
+    let render_fn = &cmp.render_fn;
+    let trait_ = &impl_trait.trait_;
+    let method = &impl_trait.method;
+
+    quote! {
+        #[automatically_derived]
+        impl<#(#lifetimes,)* #(#generics),*> #trait_ for #props_struct_name<#(#lifetime_args,)* #(#generic_idents),*> {
+            #method {
+                #render_fn(self)
+            }
+        }
+    }
+}
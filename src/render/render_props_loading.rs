@@ -0,0 +1,60 @@
+use crate::parse::Component;
+use crate::render::render_utils::{
+    compute_component_generics, leading_args, leading_params, loading_field, loading_module,
+    required_generics,
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Renders the hidden module tracking the `#[default_async]` futures of a component, and `loading_signal()` on the props
+/// struct, which is `true` until all of them have resolved.
+/// The pending futures are counted in a hidden field of the props struct, which every async default counts down once it
+/// resolves, or once it is dropped because a setter replaced it.
+pub fn render_props_loading(props_struct_name: &Ident, cmp: &Component) -> TokenStream {
+    let futures_signals = &cmp.futures_signals;
+    let vis = &cmp.vis;
+    let module = loading_module(cmp);
+    let pending = loading_field(cmp).expect("components with async defaults have a pending field");
+    let mut generics = compute_component_generics(cmp, false, false);
+    generics.extend(required_generics(cmp, false));
+    let generic_idents = generics.iter().map(|g| &g.ident).collect::<Vec<_>>();
+    let lifetimes = leading_params(cmp);
+    let lifetime_args = leading_args(cmp);
+
+    quote! {
+        #[doc(hidden)]
+        #vis mod #module {
+            /// Counts down the pending async defaults when dropped
+            struct Pending(#futures_signals::signal::Mutable<usize>);
+
+            impl Drop for Pending {
+                fn drop(&mut self) {
+                    *self.0.lock_mut() -= 1;
+                }
+            }
+
+            /// Wraps an async default, which is pending until it resolves or is dropped
+            pub fn track<T>(
+                pending: &#futures_signals::signal::Mutable<usize>,
+                default: impl ::core::future::Future<Output = T>,
+            ) -> impl ::core::future::Future<Output = T> {
+                let pending = Pending(pending.clone());
+
+                async move {
+                    let value = default.await;
+                    drop(pending);
+                    value
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl<#(#lifetimes,)* #(#generics),*> #props_struct_name<#(#lifetime_args,)* #(#generic_idents),*> {
+            /// `true` until the async defaults of the props have resolved, which they do as their signals are polled.
+            /// Async defaults which are dropped, i.e. replaced by a setter, no longer count as pending.
+            pub fn loading_signal(&self) -> #futures_signals::signal::Map<#futures_signals::signal::MutableSignal<usize>, fn(usize) -> bool> {
+                #futures_signals::signal::SignalExt::map(self.#pending.signal(), (|pending| pending > 0) as fn(_) -> _)
+            }
+        }
+    }
+}
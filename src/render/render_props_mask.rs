@@ -0,0 +1,100 @@
+use crate::parse::Component;
+use crate::render::render_utils::{
+    compute_component_generics, leading_args, leading_params, prop_mask_field, prop_mask_flag,
+    prop_mask_name, required_generics,
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Renders the bitflags-style mask type of a `prop_mask` component, with a bit for every field, and `set_mask()` on the
+/// props struct, returning the mask of the props set so far.
+/// The mask is kept in a hidden field of the props struct, which the setters of the props set their bit in.
+pub fn render_props_mask(props_struct_name: &Ident, cmp: &Component) -> TokenStream {
+    let vis = &cmp.vis;
+    let mask = prop_mask_field(cmp).expect("prop_mask components have a mask field");
+    let mask_name = prop_mask_name(props_struct_name);
+    let mut generics = compute_component_generics(cmp, false, false);
+    generics.extend(required_generics(cmp, false));
+    let generic_idents = generics.iter().map(|g| &g.ident).collect::<Vec<_>>();
+    let lifetimes = leading_params(cmp);
+    let lifetime_args = leading_args(cmp);
+
+    let flags = cmp.props.iter().enumerate().map(|(bit, prop)| {
+        let flag = prop_mask_flag(prop);
+        let doc = format!("The bit of `{}`", prop.name);
+
+        quote! {
+            #[doc = #doc]
+            pub const #flag: Self = Self(1 << #bit);
+        }
+    });
+
+    let all_flags = cmp.props.iter().map(prop_mask_flag);
+
+    let mask_doc = format!(
+        "The set of fields of [`{}`] which have been set, as returned by `set_mask()`",
+        props_struct_name
+    );
+
+    quote! {
+        #[doc = #mask_doc]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        #vis struct #mask_name(u64);
+
+        #[automatically_derived]
+        impl #mask_name {
+            #(#flags)*
+
+            /// The mask without any fields
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            /// The mask with all fields
+            pub const fn all() -> Self {
+                Self(0 #(| Self::#all_flags.0)*)
+            }
+
+            /// The raw bits of the mask
+            pub const fn bits(&self) -> u64 {
+                self.0
+            }
+
+            /// `true` if no field is in the mask
+            pub const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            /// `true` if all fields of `other` are in the mask
+            pub const fn contains(&self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::ops::BitOr for #mask_name {
+            type Output = Self;
+
+            fn bitor(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::ops::BitAnd for #mask_name {
+            type Output = Self;
+
+            fn bitand(self, other: Self) -> Self {
+                Self(self.0 & other.0)
+            }
+        }
+
+        #[automatically_derived]
+        impl<#(#lifetimes,)* #(#generics),*> #props_struct_name<#(#lifetime_args,)* #(#generic_idents),*> {
+            /// The fields which have been set by their setters so far
+            pub fn set_mask(&self) -> #mask_name {
+                self.#mask
+            }
+        }
+    }
+}
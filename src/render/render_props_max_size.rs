@@ -0,0 +1,24 @@
+use crate::parse::Component;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::LitInt;
+
+/// Renders a compile time assertion that the props struct, instantiated with its default generics, fits in `max_size` bytes
+pub fn render_props_max_size(
+    props_struct_name: &Ident,
+    cmp: &Component,
+    max_size: &LitInt,
+) -> TokenStream {
+    let lifetimes = cmp.lifetimes.iter().map(|_| quote! {'static});
+    let message = format!(
+        "{} is larger than its max_size of {} bytes",
+        props_struct_name, max_size
+    );
+
+    quote! {
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<#props_struct_name<#(#lifetimes),*>>() <= #max_size,
+            #message
+        );
+    }
+}
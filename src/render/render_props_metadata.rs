@@ -0,0 +1,93 @@
+use crate::parse::{Component, SignalType};
+use crate::render::render_utils::{leading_args, leading_params, tokens_to_string};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Expr, ExprLit, Lit, Path};
+
+/// Renders the `METADATA` const of the props struct, describing the component and its fields for documentation tooling.
+/// The `ComponentMeta` and `PropInfo` types are defined by the calling crate in the `metadata` module, since a proc macro
+/// crate can't export them, which also lets every component of the crate share them in a single catalog.
+pub fn render_props_metadata(
+    props_struct_name: &Ident,
+    cmp: &Component,
+    module: &Path,
+) -> TokenStream {
+    let lifetimes = leading_params(cmp);
+    let lifetime_args = leading_args(cmp);
+    let name = cmp.name.to_string();
+    let docs = render_docs_str(&cmp.docs);
+
+    // the props injected by the macro are the same for every component, so only the declared fields are described
+    let props = cmp.props.iter().filter(|prop| !prop.injected).map(|prop| {
+        let name = prop.name.to_string();
+        let type_name = tokens_to_string(&prop.type_);
+        let is_signal = matches!(prop.is_signal, Some(SignalType::Item));
+        let is_signal_vec = matches!(prop.is_signal, Some(SignalType::Vec));
+        let default = match &prop.default {
+            Some(default) => {
+                let default = tokens_to_string(default);
+                quote! {Some(#default)}
+            }
+            None => quote! {None},
+        };
+        let docs = render_docs_str(&prop.docs);
+
+        quote! {
+            #module::PropInfo {
+                name: #name,
+                type_name: #type_name,
+                is_signal: #is_signal,
+                is_signal_vec: #is_signal_vec,
+                default: #default,
+                docs: #docs,
+            }
+        }
+    });
+
+    // the registry collects `ComponentMeta` values, which `inventory::collect!` must be invoked for by the calling crate
+    let submit = if cfg!(feature = "inventory") {
+        quote! {
+            ::inventory::submit! {
+                #props_struct_name::METADATA
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        #[automatically_derived]
+        impl<#(#lifetimes),*> #props_struct_name<#(#lifetime_args),*> {
+            /// The name, docs and fields of the component, as strings, for building component catalogs
+            pub const METADATA: #module::ComponentMeta = #module::ComponentMeta {
+                name: #name,
+                docs: #docs,
+                props: &[#(#props),*],
+            };
+        }
+
+        #submit
+    }
+}
+
+/// Joins doc comments into a single string, a line per `#[doc]` attribute.
+/// Literal doc comments have their leading space trimmed, while other doc expressions, i.e. `include_str!()`, are kept as is.
+fn render_docs_str(docs: &[Expr]) -> TokenStream {
+    let lines = docs.iter().map(|doc| match doc {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(line),
+            ..
+        }) => {
+            let line = line.value();
+            let line = line.strip_prefix(' ').unwrap_or(&line);
+            quote! {#line}
+        }
+        _ => quote! {#doc},
+    });
+    let separated_lines = lines.enumerate().map(|(i, line)| match i {
+        0 => line,
+        _ => quote! {"\n", #line},
+    });
+
+    quote! {::core::concat!(#(#separated_lines),*)}
+}
@@ -0,0 +1,88 @@
+use crate::parse::Component;
+use crate::render::render_utils::{
+    compute_component_generics, compute_prop_type_ident, leading_args, leading_params,
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Renders the `#[repr(transparent)]` newtype over the signal of a `newtype` component, which consists of a single
+/// `#[signal]` field, along with `into_signal()` on the props struct unwrapping the props into it.
+/// The newtype derefs to the wrapped signal, and is a signal itself when the wrapped one is `Unpin`, which keeps the
+/// projection of the pinned newtype onto its field safe.
+pub fn render_props_newtype(props_struct_name: &Ident, cmp: &Component) -> TokenStream {
+    let futures_signals = &cmp.futures_signals;
+    let prop = cmp
+        .props
+        .iter()
+        .find(|prop| prop.is_signal.is_some())
+        .expect("newtype components have a signal field");
+    let prop_name = &prop.name;
+    let stored_type = compute_prop_type_ident(cmp, prop, false);
+
+    let newtype_name = Ident::new(
+        &format!("{}Signal", props_struct_name),
+        props_struct_name.span(),
+    );
+    let generics = compute_component_generics(cmp, false, false);
+    let generic_idents = generics.iter().map(|g| &g.ident).collect::<Vec<_>>();
+    let lifetimes = leading_params(cmp);
+    let lifetime_args = leading_args(cmp);
+    let vis = &cmp.vis;
+
+    let newtype_doc = format!(
+        "The `{}` signal of [`{}`], as returned by `into_signal()`",
+        prop_name, props_struct_name
+    );
+
+    quote! {
+        #[doc = #newtype_doc]
+        #[repr(transparent)]
+        #vis struct #newtype_name<TSignal>(pub TSignal);
+
+        #[automatically_derived]
+        impl<TSignal> #newtype_name<TSignal> {
+            /// Unwraps the signal
+            pub fn into_inner(self) -> TSignal {
+                self.0
+            }
+        }
+
+        #[automatically_derived]
+        impl<TSignal> ::core::ops::Deref for #newtype_name<TSignal> {
+            type Target = TSignal;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        #[automatically_derived]
+        impl<TSignal> ::core::ops::DerefMut for #newtype_name<TSignal> {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        #[automatically_derived]
+        impl<TSignal: #futures_signals::signal::Signal + ::core::marker::Unpin> #futures_signals::signal::Signal
+            for #newtype_name<TSignal>
+        {
+            type Item = TSignal::Item;
+
+            fn poll_change(
+                self: ::core::pin::Pin<&mut Self>,
+                cx: &mut ::core::task::Context<'_>,
+            ) -> ::core::task::Poll<Option<Self::Item>> {
+                ::core::pin::Pin::new(&mut self.get_mut().0).poll_change(cx)
+            }
+        }
+
+        #[automatically_derived]
+        impl<#(#lifetimes,)* #(#generics),*> #props_struct_name<#(#lifetime_args,)* #(#generic_idents),*> {
+            /// Unwraps the props into the newtype over the signal of its only field
+            pub fn into_signal(self) -> #newtype_name<#stored_type> {
+                #newtype_name(self.#prop_name)
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::parse::{Component, SignalType};
+use crate::render::render_doc_exprs;
+use crate::render::render_utils::{
+    compute_component_generics, compute_prop_type_ident, leading_args, leading_params,
+    prop_is_optional,
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Renders the `XPropsSnapshot` struct, holding the current value of every field, and the async `snapshot()` method
+/// on the props struct producing it.
+/// Signals are read with `first()`, and signal vecs through `to_signal_cloned()`, so their items must be `Clone`.
+pub fn render_props_snapshot(props_struct_name: &Ident, cmp: &Component) -> TokenStream {
+    let vis = &cmp.vis;
+    let futures_signals = &cmp.futures_signals;
+    let snapshot_name = Ident::new(
+        &format!("{}Snapshot", props_struct_name),
+        props_struct_name.span(),
+    );
+    let generics = compute_component_generics(cmp, false, false);
+    let generic_idents = generics.iter().map(|g| &g.ident).collect::<Vec<_>>();
+    let lifetimes = leading_params(cmp);
+    let lifetime_args = leading_args(cmp);
+
+    let snapshot_generics = cmp
+        .props
+        .iter()
+        .filter_map(|prop| prop.generics.as_ref().map(|g| g.param.clone()))
+        .collect::<Vec<_>>();
+    let snapshot_generic_idents = snapshot_generics.iter().map(|g| &g.ident);
+    let snapshot_type = quote! {#snapshot_name<#(#lifetime_args,)* #(#snapshot_generic_idents),*>};
+
+    let fields = cmp.props.iter().map(|prop| {
+        let name = &prop.name;
+        let item_type = &prop.type_;
+        let docs = render_doc_exprs(&prop.docs);
+
+        let type_ = match prop.is_signal {
+            Some(SignalType::Item) => quote! {#item_type},
+            Some(SignalType::Vec) => quote! {Vec<#item_type>},
+            None => {
+                let type_ = compute_prop_type_ident(cmp, prop, false);
+                quote! {#type_}
+            }
+        };
+
+        let type_ = if prop_is_optional(prop) {
+            quote! {Option<#type_>}
+        } else {
+            type_
+        };
+
+        quote! {
+            #docs
+            pub #name: #type_,
+        }
+    });
+
+    let clone_bounds = cmp
+        .props
+        .iter()
+        .filter(|prop| prop.broadcast || matches!(prop.is_signal, Some(SignalType::Vec)))
+        .map(|prop| {
+            let item_type = &prop.type_;
+
+            quote! {#item_type: Clone}
+        });
+
+    let field_reads = cmp.props.iter().map(|prop| {
+        let name = &prop.name;
+
+        let read = match prop.is_signal {
+            Some(SignalType::Item) if prop.broadcast => quote! {
+                #futures_signals::signal::SignalExt::to_future(#futures_signals::signal::SignalExt::first(v.signal_cloned())).await
+            },
+            Some(SignalType::Item) => quote! {
+                #futures_signals::signal::SignalExt::to_future(#futures_signals::signal::SignalExt::first(v)).await
+            },
+            Some(SignalType::Vec) => quote! {
+                #futures_signals::signal::SignalExt::to_future(#futures_signals::signal::SignalExt::first(
+                    #futures_signals::signal_vec::SignalVecExt::to_signal_cloned(v)
+                )).await
+            },
+            None => return quote! {#name: self.#name,},
+        };
+
+        if prop.default.is_some() {
+            quote! {#name: { let v = self.#name; #read },}
+        } else {
+            quote! {
+                #name: match self.#name {
+                    Some(v) => Some(#read),
+                    None => None,
+                },
+            }
+        }
+    });
+
+    let snapshot_doc = format!(
+        "The current values of the fields of [`{}`], as read by `snapshot()`",
+        props_struct_name
+    );
+
+    quote! {
+        #[doc = #snapshot_doc]
+        #vis struct #snapshot_name<#(#lifetimes,)* #(#snapshot_generics),*> {
+            #(#fields)*
+        }
+
+        #[automatically_derived]
+        impl<#(#lifetimes,)* #(#generics),*> #props_struct_name<#(#lifetime_args,)* #(#generic_idents),*>
+        where
+            #(#clone_bounds,)*
+        {
+            /// Reads the current value of every signal into a plain snapshot struct, i.e. for asserting on the initial state in tests
+            pub async fn snapshot(self) -> #snapshot_type {
+                #snapshot_name {
+                    #(#field_reads)*
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::parse::Component;
+use crate::render::render_utils::{leading_args, leading_params};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Renders an `apply_{field}()` helper for each `#[theme]` field, which sets the `data-theme` attribute of the element
+/// to the values of the theme signal, so stylesheets can select the theme with `[data-theme="dark"]`.
+/// The render fn calls it with the signal it took from the props, i.e. `.apply(|b| MyCmpProps::apply_theme(theme, b))`.
+pub fn render_props_theme(props_struct_name: &Ident, cmp: &Component) -> TokenStream {
+    let futures_signals = &cmp.futures_signals;
+    let (dominator, web_sys) = (&cmp.dominator, &cmp.web_sys);
+    let lifetimes = leading_params(cmp);
+    let lifetime_args = leading_args(cmp);
+    let builder = quote! {#dominator::DomBuilder<#web_sys::HtmlElement>};
+
+    let helpers = cmp.props.iter().filter(|prop| prop.theme).map(|prop| {
+        let name = &prop.name;
+        let helper_name = Ident::new(&format!("apply_{}", name), name.span());
+        let doc = format!(
+            "Sets the `data-theme` attribute of the builder to the values of the `{}` signal",
+            name
+        );
+
+        quote! {
+            #[doc = #doc]
+            pub fn #helper_name<TThemeSignal, TTheme, TThemeStr>(#name: TThemeSignal, builder: #builder) -> #builder
+            where
+                TThemeSignal: #futures_signals::signal::Signal<Item = TTheme> + 'static,
+                TTheme: #dominator::traits::OptionStr<Output = TThemeStr>,
+                TThemeStr: #dominator::traits::AsStr,
+            {
+                builder.attr_signal("data-theme", #name)
+            }
+        }
+    });
+
+    quote! {
+        #[automatically_derived]
+        impl<#(#lifetimes),*> #props_struct_name<#(#lifetime_args),*> {
+            #(#helpers)*
+        }
+    }
+}
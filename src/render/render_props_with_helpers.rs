@@ -0,0 +1,43 @@
+use crate::parse::Component;
+use crate::render::render_utils::{
+    compute_component_generics, compute_prop_type_ident, leading_args, leading_params,
+    prop_is_optional,
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Renders a `with_{field}()` helper for each field without a default, running a closure with the value only if it was set
+pub fn render_props_with_helpers(props_struct_name: &Ident, cmp: &Component) -> TokenStream {
+    let generics = compute_component_generics(cmp, false, false);
+    let generic_idents = generics.iter().map(|g| &g.ident).collect::<Vec<_>>();
+    let lifetimes = leading_params(cmp);
+    let lifetime_args = leading_args(cmp);
+
+    let helpers = cmp
+        .props
+        .iter()
+        .filter(|prop| prop_is_optional(prop))
+        .map(|prop| {
+            let name = &prop.name;
+            let helper_name = Ident::new(&format!("with_{}", name), name.span());
+            let type_ = compute_prop_type_ident(cmp, prop, false);
+            let doc = format!(
+                "Takes the value of `{}` and runs `f` with it, if it was set",
+                name
+            );
+
+            quote! {
+                #[doc = #doc]
+                pub fn #helper_name<R>(&mut self, f: impl FnOnce(#type_) -> R) -> Option<R> {
+                    self.#name.take().map(f)
+                }
+            }
+        });
+
+    quote! {
+        #[automatically_derived]
+        impl<#(#lifetimes,)* #(#generics),*> #props_struct_name<#(#lifetime_args,)* #(#generic_idents),*> {
+            #(#helpers)*
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::parse::Component;
+use crate::render::render_utils::signal_or_value_module;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Renders the hidden module with the `IntoSignalOrValue` trait of a `signal_or_value` component.
+/// Proc macro crates can only export macros, so the trait is generated alongside the component.
+/// The marker param keeps the impls for values and signals from overlapping.
+pub fn render_signal_or_value(cmp: &Component) -> TokenStream {
+    let futures_signals = &cmp.futures_signals;
+    let module = signal_or_value_module(cmp);
+    let vis = &cmp.vis;
+
+    quote! {
+        #[doc(hidden)]
+        #vis mod #module {
+            pub struct ValueMarker;
+            pub struct SignalMarker;
+
+            /// Converts a value, or a signal of values, into a signal
+            pub trait IntoSignalOrValue<T, TMarker> {
+                type Signal: #futures_signals::signal::Signal<Item = T>;
+
+                fn into_signal(self) -> Self::Signal;
+            }
+
+            #[automatically_derived]
+            impl<T> IntoSignalOrValue<T, ValueMarker> for T {
+                type Signal = #futures_signals::signal::Always<T>;
+
+                fn into_signal(self) -> Self::Signal {
+                    #futures_signals::signal::always(self)
+                }
+            }
+
+            #[automatically_derived]
+            impl<T, TSignal: #futures_signals::signal::Signal<Item = T>> IntoSignalOrValue<T, SignalMarker> for TSignal {
+                type Signal = TSignal;
+
+                fn into_signal(self) -> Self::Signal {
+                    self
+                }
+            }
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use crate::parse::{Component, Prop, SignalType};
-use proc_macro2::Ident;
-use quote::quote;
-use syn::{Type, TypeParam, TypeParamBound};
+use convert_case::{Case, Casing};
+use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{GenericParam, Type, TypeParam, TypeParamBound};
 
 pub fn new_prop_signal_name(prop_name: &Ident) -> String {
     format!("T{}SignalNew", prop_name)
@@ -11,6 +12,139 @@ pub fn prop_signal_name(prop_name: &Ident) -> String {
     format!("T{}Signal", prop_name)
 }
 
+/// The generic param tracking whether a `#[required]` prop has been set.
+/// Like the signal params, it is spanned at the call site, so the case of the prop name isn't linted on it.
+pub fn required_param_name(prop_name: &Ident) -> Ident {
+    Ident::new(&format!("T{}Required", prop_name), Span::call_site())
+}
+
+/// Fields without a default are stored as an `Option`, apart from `#[required]` fields, which are always set once the
+/// props reach the render fn
+pub fn prop_is_optional(prop: &Prop) -> bool {
+    prop.default.is_none() && !prop.required
+}
+
+/// The name of the value setter of a prop, which is the name of its field unless it is set with `#[rename]`
+pub fn setter_name(prop: &Prop) -> &Ident {
+    prop.rename.as_ref().unwrap_or(&prop.name)
+}
+
+/// Whether one of the props has a value or signal setter named `name`, which the extra setters of signal fields, such as
+/// `_from` and `_mutable`, are left out in favor of
+pub fn is_setter_name(cmp: &Component, name: &Ident) -> bool {
+    cmp.props.iter().filter(|prop| !prop.skip).any(|prop| {
+        setter_name(prop) == name
+            || prop
+                .is_signal
+                .as_ref()
+                .is_some_and(|signal_type| signal_setter_name(cmp, prop, signal_type) == *name)
+    })
+}
+
+/// The name of the signal setter of a prop, built from the components signal suffix
+pub fn signal_setter_name(cmp: &Component, prop: &Prop, signal_type: &SignalType) -> Ident {
+    let kind_suffix = match signal_type {
+        SignalType::Item if prop.signal_ref => "_ref",
+        SignalType::Item => "",
+        SignalType::Vec => "_vec",
+    };
+
+    syn::parse_str::<Ident>(
+        format!("{}{}{}", setter_name(prop), cmp.signal_suffix, kind_suffix).as_str(),
+    )
+    .expect("failed to parse props signal fn name")
+}
+
+/// The name of the props struct, which is `{Component}Props` unless it is set with `props_name`
+pub fn props_struct_name(cmp: &Component) -> Ident {
+    cmp.props_name
+        .clone()
+        .unwrap_or_else(|| Ident::new(&format!("{}Props", cmp.name), cmp.name.span()))
+}
+
+/// The name of the props trait, which is `{Component}PropsTrait` unless it is set with `trait_name`
+pub fn props_trait_name(cmp: &Component) -> Ident {
+    cmp.trait_name
+        .clone()
+        .unwrap_or_else(|| Ident::new(&format!("{}PropsTrait", cmp.name), cmp.name.span()))
+}
+
+/// The name of the component macro, which is the snake cased component name unless it is set with `macro_name`
+pub fn component_macro_name(cmp: &Component) -> Ident {
+    cmp.macro_name
+        .clone()
+        .unwrap_or_else(|| Ident::new(&cmp.name.to_string().to_case(Case::Snake), cmp.name.span()))
+}
+
+/// The hidden module holding the `IntoSignalOrValue` trait of a `signal_or_value` component
+pub fn signal_or_value_module(cmp: &Component) -> Ident {
+    Ident::new(
+        &format!(
+            "__{}_signal_or_value",
+            cmp.name.to_string().to_case(Case::Snake)
+        ),
+        cmp.name.span(),
+    )
+}
+
+/// The hidden module holding the string pool of a component with `#[intern]` fields
+pub fn interner_module(cmp: &Component) -> Ident {
+    Ident::new(
+        &format!("__{}_interner", cmp.name.to_string().to_case(Case::Snake)),
+        cmp.name.span(),
+    )
+}
+
+/// The hidden module tracking the async defaults of a component with `#[default_async]` fields
+pub fn loading_module(cmp: &Component) -> Ident {
+    Ident::new(
+        &format!("__{}_loading", cmp.name.to_string().to_case(Case::Snake)),
+        cmp.name.span(),
+    )
+}
+
+/// Interns a `String` expression into the string pool of the component
+pub fn intern_expr(cmp: &Component, value: TokenStream) -> TokenStream {
+    let module = interner_module(cmp);
+
+    quote! {#module::intern(#value)}
+}
+
+/// The value setter of the prop accepts either a value or a signal.
+/// Generic fields are left out, as the item type of the signal could not be inferred, and so are fields whose value setter
+/// already converts its value.
+pub fn prop_is_signal_or_value(cmp: &Component, prop: &Prop) -> bool {
+    cmp.signal_or_value
+        && matches!(prop.is_signal, Some(SignalType::Item))
+        && prop.generics.is_none()
+        && !prop.into
+        && !prop.try_into
+        && prop.fallback.is_none()
+        && prop.map.is_none()
+}
+
+/// The lifetime and const params of the component, which come before all other generic params of the generated items
+pub fn leading_params(cmp: &Component) -> Vec<GenericParam> {
+    cmp.lifetimes
+        .iter()
+        .cloned()
+        .map(GenericParam::Lifetime)
+        .chain(cmp.const_params.iter().cloned().map(GenericParam::Const))
+        .collect()
+}
+
+/// The lifetimes and const params of the component, as arguments to the generated items
+pub fn leading_args(cmp: &Component) -> Vec<TokenStream> {
+    cmp.lifetimes
+        .iter()
+        .map(|l| l.lifetime.to_token_stream())
+        .chain(cmp.const_params.iter().map(|c| c.ident.to_token_stream()))
+        .collect()
+}
+
+/// The generic params of the props struct.
+/// Their order is documented on the `#[component]` macro, since hand written impls against the props struct depend on it,
+/// so it must not change.
 pub fn compute_component_generics(
     cmp: &Component,
     include_defaults: bool,
@@ -29,31 +163,24 @@ pub fn compute_component_generics(
             generics.push(param);
         }
 
-        if prop.is_signal.is_some() {
-            let ty_ = &prop.type_;
-
-            let prop_type = if prop.generics.is_some() && include_self_prefix {
-                syn::parse_str::<Type>(format!("Self::{}", quote! {#ty_}).as_str())
-                    .expect("failed to parse prop type")
+        if let Some(signal_type) = &prop.is_signal {
+            let prop_type = if include_self_prefix {
+                self_prop_type(prop)
             } else {
-                ty_.clone()
+                prop.type_.clone()
             };
 
-            let prop_signal_type = get_prop_signal_type_param(
-                prop,
-                prop.is_signal.as_ref().unwrap(),
-                &prop_type,
-                false,
-            );
-            let prop_signal_always_type =
-                get_prop_signal_always_type(prop.is_signal.as_ref().unwrap(), &prop_type);
+            let prop_signal_type =
+                get_prop_signal_type_param(cmp, prop, signal_type, &prop_type, false);
+            let prop_signal_default_type =
+                get_prop_signal_default_type(cmp, prop, signal_type, &prop_type);
 
             let param = match include_defaults {
                 true => syn::parse_str(
                     format!(
                         "{} = {}",
                         quote! {#prop_signal_type},
-                        quote! {#prop_signal_always_type}
+                        quote! {#prop_signal_default_type}
                     )
                     .as_str(),
                 )
@@ -69,43 +196,280 @@ pub fn compute_component_generics(
     generics
 }
 
-pub fn compute_prop_type_ident(prop: &Prop, include_self_prefix: bool) -> Type {
-    if prop.is_signal.is_some() {
-        let prefix = if include_self_prefix { "Self::" } else { "" };
-        syn::parse_str(format!("{}T{}Signal", prefix, prop.name).as_str())
-            .expect("failed to parse signal generic")
+/// The type of the value taken by the setter of a non-signal prop of type `ty_`
+pub fn setter_value_type(prop: &Prop, ty_: &Type) -> TokenStream {
+    if let Some(event) = &prop.emits {
+        quote! {impl Fn(#event) + 'static}
+    } else if let Some(item) = &prop.collect {
+        quote! {impl IntoIterator<Item = impl Into<#item>>}
+    } else if prop.intern {
+        quote! {impl Into<String>}
+    } else if prop.into {
+        quote! {impl Into<#ty_>}
+    } else if let Some(borrowed) = &prop.to_owned {
+        quote! {impl AsRef<#borrowed>}
+    } else if let Some(value) = &prop.observable {
+        quote! {#value}
     } else {
-        let prefix = if prop.generics.is_some() && include_self_prefix {
-            "Self::"
+        quote! {#ty_}
+    }
+}
+
+/// The generic params tracking the `#[required]` props, which come after all other generic params of the props struct.
+/// They default to the type of their field, so the props struct with its default generic params is the one with all
+/// required props set, while `new()` starts out with `()` for each of them.
+pub fn required_generics(cmp: &Component, include_defaults: bool) -> Vec<TypeParam> {
+    cmp.props
+        .iter()
+        .filter(|prop| prop.required)
+        .map(|prop| {
+            let ident = required_param_name(&prop.name);
+            let type_ = &prop.type_;
+
+            if include_defaults {
+                syn::parse_quote!(#ident = #type_)
+            } else {
+                syn::parse_quote!(#ident)
+            }
+        })
+        .collect()
+}
+
+/// The hidden field marking the lifetimes and const params of the component as used by the props struct.
+/// Params only used by `#[required]` fields only show up in the defaults of their generic params, which do not count as
+/// a use, so components with both have the marker field.
+pub fn lifetimes_marker(cmp: &Component) -> Option<Ident> {
+    ((!cmp.lifetimes.is_empty() || !cmp.const_params.is_empty())
+        && cmp.props.iter().any(|prop| prop.required))
+    .then(|| Ident::new("__lifetimes", cmp.name.span()))
+}
+
+/// The hidden field of `prop_mask` components, tracking which of the props have been set
+pub fn prop_mask_field(cmp: &Component) -> Option<Ident> {
+    cmp.prop_mask
+        .then(|| Ident::new("__set_mask", cmp.name.span()))
+}
+
+/// The hidden field of components with `#[default_async]` fields, counting the async defaults which have not resolved yet
+pub fn loading_field(cmp: &Component) -> Option<Ident> {
+    cmp.props
+        .iter()
+        .any(|prop| prop.default_async)
+        .then(|| Ident::new("__pending_defaults", cmp.name.span()))
+}
+
+/// The name of the mask type of `prop_mask` components
+pub fn prop_mask_name(props_struct_name: &Ident) -> Ident {
+    Ident::new(
+        &format!("{}Mask", props_struct_name),
+        props_struct_name.span(),
+    )
+}
+
+/// The associated const of the mask type with the bit of `prop` set
+pub fn prop_mask_flag(prop: &Prop) -> Ident {
+    Ident::new(
+        &prop.name.to_string().to_case(Case::UpperSnake),
+        prop.name.span(),
+    )
+}
+
+pub fn compute_prop_type_ident(cmp: &Component, prop: &Prop, include_self_prefix: bool) -> Type {
+    if let Some(signal_type) = &prop.is_signal {
+        let prefix = if include_self_prefix { "Self::" } else { "" };
+        let signal = syn::parse_str(format!("{}T{}Signal", prefix, prop.name).as_str())
+            .expect("failed to parse signal generic");
+        let item = if include_self_prefix {
+            self_prop_type(prop)
         } else {
-            ""
+            prop.type_.clone()
         };
 
-        let ty_ = prop.type_.clone();
-        let ty_ = quote! {#ty_}.to_string();
-        syn::parse_str(format!("{}{}", prefix, ty_).as_str()).expect("failed to parse prop type")
+        stored_signal_type(cmp, prop, signal_type, signal, item)
+    } else if include_self_prefix {
+        self_prop_type(prop)
+    } else {
+        prop.type_.clone()
+    }
+}
+
+/// The type of a prop as named by the props trait, whose generic param, if any, is the associated type of the trait
+fn self_prop_type(prop: &Prop) -> Type {
+    let ident = prop.generics.as_ref().map(|generic| &generic.param.ident);
+
+    replace_prop_generic(prop, &quote! {Self::#ident})
+}
+
+/// The item type of the signals accepted by the signal setter of a prop.
+/// Signals of fields with a fallback produce `Option`s, whose `None` is replaced by the fallback value, and signals of
+/// fields with a `map` produce the argument type of the closure mapping them.
+pub fn received_signal_item_type(prop: &Prop, prop_type: &Type) -> Type {
+    if let Some((input, _)) = &prop.map {
+        input.clone()
+    } else if prop.fallback.is_some() {
+        syn::parse_quote!(Option<#prop_type>)
+    } else {
+        prop_type.clone()
     }
 }
 
-pub fn get_prop_signal_always_type(signal_type: &SignalType, prop_type: &Type) -> Type {
+/// The type a received signal is stored as on the props struct
+pub fn stored_signal_type(
+    cmp: &Component,
+    prop: &Prop,
+    signal_type: &SignalType,
+    signal: Type,
+    item: Type,
+) -> Type {
+    let futures_signals = &cmp.futures_signals;
+    let signal: Type = if prop.fallback.is_some() {
+        syn::parse_quote!(#futures_signals::signal::Map<#signal, fn(Option<#item>) -> #item>)
+    } else if let Some((input, _)) = &prop.map {
+        syn::parse_quote!(#futures_signals::signal::Map<#signal, fn(#input) -> #item>)
+    } else {
+        signal
+    };
+
+    let signal: Type = if prop.on_change.is_some() {
+        syn::parse_quote!(#futures_signals::signal::Map<#signal, fn(#item) -> #item>)
+    } else {
+        signal
+    };
+
+    if prop.broadcast {
+        return syn::parse_quote!(#futures_signals::signal::Broadcaster<#signal>);
+    }
+
+    if !prop.as_stream {
+        return signal;
+    }
+
     match signal_type {
-        SignalType::Item => syn::parse_str(
-            format!("futures_signals::signal::Always<{}>", quote! {#prop_type}).as_str(),
-        )
-        .expect("failed to generate signal always"),
+        SignalType::Item => syn::parse_quote!(#futures_signals::signal::SignalStream<#signal>),
+        SignalType::Vec => {
+            syn::parse_quote!(#futures_signals::signal_vec::SignalVecStream<#signal>)
+        }
+    }
+}
 
-        SignalType::Vec => syn::parse_str(
-            format!(
-                "futures_signals::signal_vec::Always<{}>",
-                quote! {#prop_type}
+/// Converts a received signal expression into the form it is stored as on the props struct
+pub fn store_signal_expr(
+    cmp: &Component,
+    prop: &Prop,
+    signal_type: &SignalType,
+    signal: TokenStream,
+) -> TokenStream {
+    let futures_signals = &cmp.futures_signals;
+    let signal = if let Some(fallback) = &prop.fallback {
+        quote! {
+            #futures_signals::signal::SignalExt::map(
+                #signal,
+                (|v| match v {
+                    Some(v) => v,
+                    None => #fallback,
+                }) as fn(_) -> _,
             )
-            .as_str(),
-        )
-        .expect("failed to generate signal_vec always"),
+        }
+    } else if let Some((_, map)) = &prop.map {
+        quote! {
+            #futures_signals::signal::SignalExt::map(#signal, (#map) as fn(_) -> _)
+        }
+    } else {
+        signal
+    };
+
+    // the item type is spelled out, as the callback would otherwise infer the type of the value from its parameter
+    let signal = if let Some(on_change) = &prop.on_change {
+        let item = &prop.type_;
+
+        quote! {
+            #futures_signals::signal::SignalExt::map(
+                #signal,
+                (|v: #item| {
+                    (#on_change)(&v);
+                    v
+                }) as fn(_) -> _,
+            )
+        }
+    } else {
+        signal
+    };
+
+    if prop.broadcast {
+        return quote! {#futures_signals::signal::Broadcaster::new(#signal)};
+    }
+
+    if !prop.as_stream {
+        return signal;
+    }
+
+    match signal_type {
+        SignalType::Item => quote! {#futures_signals::signal::SignalExt::to_stream(#signal)},
+        SignalType::Vec => quote! {#futures_signals::signal_vec::SignalVecExt::to_stream(#signal)},
+    }
+}
+
+pub fn get_prop_signal_always_type(
+    cmp: &Component,
+    prop: &Prop,
+    signal_type: &SignalType,
+    prop_type: &Type,
+) -> Type {
+    let futures_signals = &cmp.futures_signals;
+    let prop_type = &received_signal_item_type(prop, prop_type);
+
+    match signal_type {
+        SignalType::Item => syn::parse_quote!(#futures_signals::signal::Always<#prop_type>),
+        SignalType::Vec => syn::parse_quote!(#futures_signals::signal_vec::Always<#prop_type>),
+    }
+}
+
+/// The default type of the signal param of a prop, which is the signal of `always()`, or the boxed signal of a
+/// `#[default_signal]` or `#[default_async]`, since its type can't be named
+pub fn get_prop_signal_default_type(
+    cmp: &Component,
+    prop: &Prop,
+    signal_type: &SignalType,
+    prop_type: &Type,
+) -> Type {
+    if !prop.default_signal && !prop.default_async {
+        return get_prop_signal_always_type(cmp, prop, signal_type, prop_type);
+    }
+
+    // the signal of an async default is the one of `from_future()`, which is `None` until the future resolves
+    let prop_type = &received_signal_item_type(prop, prop_type);
+
+    let bounds: TokenStream =
+        syn::parse_str(signal_send_bounds(cmp, prop)).expect("failed to parse signal send bounds");
+
+    let futures_signals = &cmp.futures_signals;
+
+    syn::parse_quote!(::std::pin::Pin<::std::boxed::Box<dyn #futures_signals::signal::Signal<Item = #prop_type> #bounds>>)
+}
+
+/// The thread safety bounds of the signals of a prop, as required by `#[send]`, `#[sync]` or `thread_safe`
+fn signal_send_bounds(cmp: &Component, prop: &Prop) -> &'static str {
+    match (prop_is_send(prop), prop.is_sync, cmp.thread_safe) {
+        (_, _, true) | (true, true, false) => " + Send + Sync",
+        (true, false, false) => " + Send",
+        (false, true, false) => " + Sync",
+        (false, false, false) => "",
     }
 }
 
+/// Signals must be `Send` if the field is annotated with `#[send]`, or its generic param is bound by `Send`
+pub fn prop_is_send(prop: &Prop) -> bool {
+    prop.is_send
+        || prop.generics.as_ref().is_some_and(|g| {
+            g.param.bounds.iter().any(|v| match v {
+                TypeParamBound::Trait(t) => t.path.segments.iter().any(|s| s.ident == "Send"),
+                _ => false,
+            })
+        })
+}
+
 pub fn get_prop_signal_type_param(
+    cmp: &Component,
     prop: &Prop,
     signal_type: &SignalType,
     prop_type: &Type,
@@ -117,20 +481,25 @@ pub fn get_prop_signal_type_param(
         prop_signal_name(&prop.name)
     };
 
-    let is_send = prop.is_send || prop.generics.as_ref().map_or(false, |g| {
-        g.param.bounds.iter().any(|v| match v {
-            TypeParamBound::Trait(t) => t.path.segments.iter().any(|s| s.ident == "Send"),
-            _ => false,
-        })
-    });
-
-    let send_suffix = if is_send { " + Send" } else { "" };
+    let futures_signals = &cmp.futures_signals;
+    let prop_type = &received_signal_item_type(prop, prop_type);
+    let send_suffix = signal_send_bounds(cmp, prop);
+    let static_suffix = if prop.owned { " + 'static" } else { "" };
+    let bounds = &prop.signal_bounds;
+    let bounds_suffix = quote! {#(+ #bounds)*};
+    // signals may borrow for as long as the lifetimes of the component
+    let lifetime_suffix = cmp
+        .lifetimes
+        .iter()
+        .map(|l| format!(" + {}", l.lifetime))
+        .collect::<String>();
 
     match signal_type {
         SignalType::Item => syn::parse_str(
             format!(
-                "{}: futures_signals::signal::Signal<Item={}> {send_suffix}",
+                "{}: {}::signal::Signal<Item={}> {send_suffix}{static_suffix}{lifetime_suffix} {bounds_suffix}",
                 signal_name,
+                quote! {#futures_signals},
                 quote! {#prop_type}
             )
             .as_str(),
@@ -139,8 +508,9 @@ pub fn get_prop_signal_type_param(
 
         SignalType::Vec => syn::parse_str(
             format!(
-                "{}: futures_signals::signal_vec::SignalVec<Item={}> {send_suffix}",
+                "{}: {}::signal_vec::SignalVec<Item={}> {send_suffix}{static_suffix}{lifetime_suffix} {bounds_suffix}",
                 signal_name,
+                quote! {#futures_signals},
                 quote! {#prop_type}
             )
             .as_str(),
@@ -148,3 +518,118 @@ pub fn get_prop_signal_type_param(
         .expect("failed to parse signal generic"),
     }
 }
+
+/// Prints types and expressions the way they are usually written, i.e. `Vec<String>` rather than `Vec < String >`
+pub fn tokens_to_string(tokens: &impl ToTokens) -> String {
+    let mut out = String::new();
+    print_tokens(tokens.to_token_stream(), &mut out);
+    out
+}
+
+fn print_tokens(tokens: TokenStream, out: &mut String) {
+    // words are separated by a space, while punctuation, apart from commas, is written without one
+    let mut after_word = false;
+
+    for token in tokens {
+        match token {
+            TokenTree::Ident(_) | TokenTree::Literal(_) => {
+                if after_word {
+                    out.push(' ');
+                }
+
+                out.push_str(&token.to_string());
+                after_word = true;
+            }
+            TokenTree::Punct(punct) => {
+                out.push(punct.as_char());
+
+                if punct.as_char() == ',' {
+                    out.push(' ');
+                }
+
+                after_word = false;
+            }
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace => ("{ ", " }"),
+                    Delimiter::None => ("", ""),
+                };
+
+                out.push_str(open);
+                print_tokens(group.stream(), out);
+                out.push_str(close);
+                after_word = false;
+            }
+        }
+    }
+}
+
+/// Replaces the lifetimes of the component in `tokens` by the higher-ranked `'__any`
+pub fn erase_lifetimes(cmp: &Component, tokens: TokenStream) -> TokenStream {
+    let mut out = TokenStream::new();
+    let mut tokens = tokens.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                let is_component_lifetime = matches!(
+                    tokens.peek(),
+                    Some(TokenTree::Ident(ident)) if cmp.lifetimes.iter().any(|l| l.lifetime.ident == *ident)
+                );
+
+                if is_component_lifetime {
+                    let ident = tokens.next().expect("peeked lifetime ident");
+                    out.extend([
+                        TokenTree::Punct(Punct::new('\'', Spacing::Joint)),
+                        TokenTree::Ident(Ident::new("__any", ident.span())),
+                    ]);
+                } else {
+                    out.extend([TokenTree::Punct(punct)]);
+                }
+            }
+            TokenTree::Group(group) => {
+                let mut erased =
+                    Group::new(group.delimiter(), erase_lifetimes(cmp, group.stream()));
+                erased.set_span(group.span());
+                out.extend([TokenTree::Group(erased)]);
+            }
+            token => out.extend([token]),
+        }
+    }
+
+    out
+}
+
+/// The type of a prop with its generic param replaced by `with`, i.e. `Vec<Self::T>` for a field of type `Vec<T>`
+pub fn replace_prop_generic(prop: &Prop, with: &TokenStream) -> Type {
+    let ty_ = &prop.type_;
+
+    match &prop.generics {
+        Some(generic) => syn::parse2(replace_ident(quote! {#ty_}, &generic.param.ident, with))
+            .expect("failed to parse prop type"),
+        None => ty_.clone(),
+    }
+}
+
+fn replace_ident(tokens: TokenStream, ident: &Ident, with: &TokenStream) -> TokenStream {
+    let mut out = TokenStream::new();
+
+    for token in tokens {
+        match token {
+            TokenTree::Ident(found) if found == *ident => out.extend(with.clone()),
+            TokenTree::Group(group) => {
+                let mut replaced = Group::new(
+                    group.delimiter(),
+                    replace_ident(group.stream(), ident, with),
+                );
+                replaced.set_span(group.span());
+                out.extend([TokenTree::Group(replaced)]);
+            }
+            token => out.extend([token]),
+        }
+    }
+
+    out
+}
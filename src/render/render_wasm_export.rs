@@ -0,0 +1,81 @@
+use crate::parse::{Component, SignalType};
+use crate::render::render_utils::{props_struct_name, setter_name};
+use convert_case::{Case, Casing};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::Type;
+
+const JS_FRIENDLY_TYPES: &[&str] = &[
+    "String", "bool", "f32", "f64", "i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64",
+];
+
+/// Renders the `#[wasm_bindgen]` factory function of the component, taking an optional argument per JS friendly field
+pub fn render_wasm_export(cmp: &Component, return_type: &Type) -> TokenStream {
+    let props_name = props_struct_name(cmp);
+    let fn_name = Ident::new(
+        &format!("{}_js", cmp.name.to_string().to_case(Case::Snake)),
+        cmp.name.span(),
+    );
+    let render_fn = &cmp.render_fn;
+
+    let exported_props = cmp
+        .props
+        .iter()
+        .filter(|prop| prop.generics.is_none() && !prop.skip && prop.map.is_none())
+        .filter(|prop| !matches!(prop.is_signal, Some(SignalType::Vec)))
+        .filter(|prop| {
+            JS_FRIENDLY_TYPES.iter().any(
+                |js_type| matches!(&prop.type_, Type::Path(path) if path.path.is_ident(js_type)),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let args = exported_props.iter().map(|prop| {
+        let name = &prop.name;
+        let type_ = &prop.type_;
+
+        quote! {#name: Option<#type_>}
+    });
+
+    let setters = exported_props.iter().map(|prop| {
+        let name = &prop.name;
+        let setter = setter_name(prop);
+
+        // converting a value into its own type can't fail
+        if prop.try_into {
+            return quote! {
+                if let Some(v) = #name {
+                    props = match props.#setter(v) {
+                        Ok(props) => props,
+                        Err(never) => match never {},
+                    };
+                }
+            };
+        }
+
+        quote! {
+            if let Some(v) = #name {
+                props = props.#setter(v);
+            }
+        }
+    });
+
+    let doc = format!(
+        "Builds and renders the [`{}`] component for JS callers",
+        props_name
+    );
+
+    quote! {
+        #[doc = #doc]
+        #[::wasm_bindgen::prelude::wasm_bindgen]
+        #[allow(deprecated)]
+        pub fn #fn_name(#(#args),*) -> #return_type {
+            #[allow(unused_mut)]
+            let mut props = #props_name::new();
+
+            #(#setters)*
+
+            ::core::convert::Into::into(#render_fn(props))
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use futures_signals_component_macro::component;
+
+#[component(render_fn = render_mixed)]
+struct Mixed {
+    #[signal]
+    #[signal_vec]
+    #[default(vec![])]
+    values: i32,
+}
+
+#[component(render_fn = render_defaulted)]
+struct Defaulted {
+    #[default(0)]
+    #[default(1)]
+    value: i32,
+}
+
+#[component(render_fn = render_skipped)]
+struct Skipped {
+    #[skip]
+    #[default(0)]
+    #[into]
+    value: i32,
+}
+
+fn render_mixed(_props: impl MixedPropsTrait) {}
+fn render_defaulted(_props: impl DefaultedPropsTrait) {}
+fn render_skipped(_props: impl SkippedPropsTrait) {}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use futures_signals_component_macro::component;
+
+#[component(render_fn = render_counter)]
+struct Counter {
+    #[signal]
+    #[default(0)]
+    #[default_signal(futures_signals::signal::always(1))]
+    count: i32,
+}
+
+fn render_counter(_props: impl CounterPropsTrait) {}
+
+fn main() {}
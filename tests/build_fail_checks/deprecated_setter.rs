@@ -0,0 +1,25 @@
+#![deny(deprecated)]
+
+use futures_signals_component_macro::component;
+
+#[component(render_fn = render_label)]
+struct Label {
+    #[deprecated(note = "use `text` instead")]
+    #[signal]
+    #[default("".to_string())]
+    label: String,
+    #[signal]
+    #[default("".to_string())]
+    text: String,
+}
+
+fn render_label(props: impl LabelPropsTrait) -> String {
+    let _ = props.take();
+    String::new()
+}
+
+fn main() {
+    let _ = label!({ .text("hi".to_string()) });
+    let _ = label!({ .label("hi".to_string()) });
+    let _ = LabelProps::new().label_signal(futures_signals::signal::always("hi".to_string()));
+}
@@ -0,0 +1,40 @@
+use futures_signals_component_macro::component;
+
+#[component(render_fn = render_tuple)]
+struct Tuple(i32, String);
+
+#[component(render_fn = render_unit)]
+struct Unit;
+
+#[component(render_fn = 42)]
+struct BadArgs {}
+
+#[component]
+struct NoArgs {}
+
+#[component(async_take)]
+struct NoRenderFn {}
+
+#[component(render_fn = render_take, accessors)]
+struct AccessorClash {
+    take: bool,
+}
+
+#[component(render_fn = render_undefaulted, derive(Default, Hash))]
+struct UnsupportedDerive {}
+
+#[component(render_fn = render_undefaulted, derive(Default))]
+struct UndefaultedGeneric<T: Clone> {
+    value: T,
+}
+
+#[component(render_fn = render_signal, derive(Clone))]
+struct ClonedSignal {
+    #[signal]
+    value: i32,
+}
+
+#[component(render_fn = render_named, builder, props_name = Named)]
+struct Named {}
+
+fn main() {}
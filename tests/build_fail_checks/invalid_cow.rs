@@ -0,0 +1,11 @@
+use futures_signals_component_macro::component;
+
+#[component(render_fn = render_counter)]
+struct Counter {
+    #[cow]
+    count: i32,
+}
+
+fn render_counter(_props: impl CounterPropsTrait) {}
+
+fn main() {}
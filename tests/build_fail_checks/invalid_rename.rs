@@ -0,0 +1,12 @@
+use futures_signals_component_macro::component;
+
+#[component(render_fn = render_typed)]
+struct Typed {
+    #[rename = "type"]
+    #[default(0)]
+    type_: i32,
+}
+
+fn render_typed(_props: impl TypedPropsTrait) {}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use futures_signals_component_macro::component;
+
+#[component(render_fn = render_defaulted)]
+struct Defaulted {
+    #[required]
+    #[default(1)]
+    value: i32,
+}
+
+#[component(render_fn = render_signaled)]
+struct Signaled {
+    #[signal]
+    #[required]
+    value: i32,
+}
+
+#[component(render_fn = render_generic)]
+struct Generic<T> {
+    #[required]
+    value: T,
+}
+
+fn render_defaulted(_props: impl DefaultedPropsTrait) {}
+fn render_signaled(_props: impl SignaledPropsTrait) {}
+fn render_generic(_props: impl GenericPropsTrait) {}
+
+fn main() {}
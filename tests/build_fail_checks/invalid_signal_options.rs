@@ -0,0 +1,39 @@
+use futures_signals_component_macro::component;
+
+#[component(render_fn = render_unknown)]
+struct Unknown {
+    #[signal(as_steam)]
+    value: i32,
+}
+
+#[component(render_fn = render_valueless)]
+struct Valueless {
+    #[signal(fallback)]
+    value: Option<i32>,
+}
+
+#[component(render_fn = render_unbounded)]
+struct Unbounded {
+    #[signal(bounds = "Unpin Send")]
+    value: i32,
+}
+
+#[component(render_fn = render_listed)]
+struct Listed {
+    #[signal_vec(on_change = |_: &i32| {})]
+    values: i32,
+}
+
+#[component(render_fn = render_unoptional)]
+struct Unoptional {
+    #[signal(default_none)]
+    value: i32,
+}
+
+fn render_unknown(_props: impl UnknownPropsTrait) {}
+fn render_valueless(_props: impl ValuelessPropsTrait) {}
+fn render_unbounded(_props: impl UnboundedPropsTrait) {}
+fn render_listed(_props: impl ListedPropsTrait) {}
+fn render_unoptional(_props: impl UnoptionalPropsTrait) {}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use futures_signals_component_macro::component;
+
+#[component(render_fn = render_suffixed, signal_suffix = "-sig")]
+struct Suffixed {
+    #[signal]
+    foo: i32,
+}
+
+fn render_suffixed(_props: impl SuffixedPropsTrait) {}
+
+fn main() {}
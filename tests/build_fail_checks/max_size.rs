@@ -0,0 +1,13 @@
+use futures_signals_component_macro::component;
+
+#[component(render_fn = render_bloated, max_size = 8)]
+struct Bloated {
+    #[default(0)]
+    first: u64,
+    #[default(0)]
+    second: u64,
+}
+
+fn render_bloated(_props: impl BloatedPropsTrait) {}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use futures_signals_component_macro::component;
+
+#[component(render_fn = render_value)]
+struct Value<T: Clone = i32> {
+    #[default(T::default())]
+    value: T,
+}
+
+fn render_value(_props: impl ValuePropsTrait) {}
+
+fn main() {}
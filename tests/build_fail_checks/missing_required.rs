@@ -0,0 +1,15 @@
+use futures_signals_component_macro::component;
+
+#[component(render_fn = render_labeled)]
+struct Labeled {
+    #[required]
+    label: String,
+    #[default(1)]
+    count: i32,
+}
+
+fn render_labeled(_props: impl LabeledPropsTrait) {}
+
+fn main() {
+    labeled!({.count(2)});
+}
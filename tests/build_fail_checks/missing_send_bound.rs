@@ -0,0 +1,16 @@
+use futures_signals_component_macro::component;
+
+#[component(render_fn = render_shared)]
+struct Shared<T: Clone = i32> {
+    #[signal]
+    #[send]
+    #[sync]
+    #[default(1)]
+    value: T,
+}
+
+fn render_shared(_props: impl SharedPropsTrait) {}
+
+fn main() {
+    render_shared(SharedProps::new());
+}
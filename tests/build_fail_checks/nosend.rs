@@ -13,7 +13,8 @@ struct NeedsSend<T: Send = (), TNotSend: Clone = ()> {
 fn render_send(props: impl NeedsSendPropsTrait + 'static) -> i32 {
     let NeedsSendProps {
         send_me,
-        don_not_send_me
+        don_not_send_me,
+        ..
     } = props.take();
 
     consume_send(don_not_send_me.unwrap());
@@ -0,0 +1,15 @@
+use futures_signals_component_macro::component;
+
+#[component(render_fn = render_owned)]
+struct Owned<T: ToString = i32> {
+    #[signal(owned)]
+    foo: T,
+}
+
+fn render_owned(_props: impl OwnedPropsTrait) {}
+
+fn main() {
+    let borrowed = String::from("borrowed");
+
+    render_owned(OwnedProps::new().foo(borrowed.as_str()));
+}
@@ -0,0 +1,21 @@
+mod cmp {
+    use futures_signals_component_macro::component;
+
+    #[component(render_fn = render_sealed, sealed)]
+    pub struct Sealed {
+        #[default(1)]
+        pub foo: i32,
+    }
+
+    pub fn render_sealed(_props: impl SealedPropsTrait) {}
+}
+
+struct Imposter;
+
+impl cmp::SealedPropsTrait for Imposter {
+    fn take(self) -> cmp::SealedProps {
+        cmp::SealedProps::new()
+    }
+}
+
+fn main() {}
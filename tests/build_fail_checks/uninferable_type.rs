@@ -0,0 +1,12 @@
+use futures_signals_component_macro::component;
+
+#[component(render_fn = render_uninferable)]
+struct Uninferable {
+    #[signal]
+    #[default(0)]
+    foo: _,
+}
+
+fn render_uninferable(_props: impl UninferablePropsTrait) {}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use futures_signals_component_macro::component;
+
+#[component(render_fn = render_base, extendable)]
+struct Base {
+    #[default(1)]
+    foo: i32,
+}
+
+fn render_base(_props: impl BasePropsTrait) {}
+
+#[component(render_fn = render_preset, extends = Base, override(bar = 2))]
+struct Preset {}
+
+fn render_preset(_props: impl PresetPropsTrait) {}
+
+fn main() {}
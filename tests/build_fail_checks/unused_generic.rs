@@ -0,0 +1,9 @@
+use futures_signals_component_macro::component;
+
+#[component(render_fn = render_unused)]
+struct UnusedGeneric<T: Clone = (), TUnused: Clone = ()> {
+    #[signal]
+    used: T,
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+#![deny(unused_must_use)]
+#![allow(unexpected_cfgs)]
+
+use futures_signals_component_macro::component;
+
+#[component(render_fn = render_label, must_use)]
+struct Label {
+    #[default(1)]
+    value: i32,
+}
+
+fn render_label(props: impl LabelPropsTrait) -> i32 {
+    props.take().value
+}
+
+#[component(render_fn = render_unit)]
+struct Unit {
+    #[default(1)]
+    value: i32,
+}
+
+fn render_unit(_props: impl UnitPropsTrait) {}
+
+#[component(render_fn = render_client, ssr_render_fn = render_server, must_use)]
+struct Hydrated {
+    #[default(1)]
+    value: i32,
+}
+
+fn render_client(props: impl HydratedPropsTrait) -> i32 {
+    props.take().value
+}
+
+fn render_server(props: impl HydratedPropsTrait) -> i32 {
+    -props.take().value
+}
+
+fn main() {
+    label!({ .value(2) });
+    let _ = label!({});
+    unit!({});
+    hydrated!({ .value(2) });
+}
@@ -9,6 +9,7 @@ mod test {
     use futures_signals::signal_vec::SignalVecExt;
     use futures_signals::signal_vec::VecDiff;
     use futures_signals_component_macro::component;
+    use futures_util::StreamExt;
     use num_traits::{PrimInt, ToPrimitive};
 
     #[macro_use]
@@ -17,7 +18,7 @@ mod test {
         use futures_signals_component_macro::component;
         use num_traits::PrimInt;
 
-        #[component(render_fn = some_button)]
+        #[component(render_fn = some_button, extendable)]
         pub struct SomeButton<
             FClickCallback: Fn(dominator::events::Click) + Send = fn(
                 dominator::events::Click,
@@ -91,6 +92,11 @@ mod test {
         });
     }
 
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    fn cmp_scaffold_macro_test() {
+        let _rendered: Dom = some_button_scaffold!();
+    }
+
     // just here to make sure it compiles (it's the example from the readme)
     fn _my_app(label: impl Signal<Item = String> + 'static) -> Dom {
         some_button!({
@@ -99,6 +105,294 @@ mod test {
         })
     }
 
+    #[cfg(feature = "dominator")]
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    fn apply_timing_test() {
+        use dominator::DomBuilder;
+        use web_sys::HtmlElement;
+
+        #[component(render_fn = titled_before)]
+        struct TitledBefore {}
+
+        #[component(render_fn = titled_after, apply_timing = after)]
+        struct TitledAfter {}
+
+        fn titled_before(props: impl TitledBeforePropsTrait) -> HtmlElement {
+            let TitledBeforeProps { mut apply } = props.take();
+
+            DomBuilder::<HtmlElement>::new_html("div")
+                .apply(|b| TitledBeforeProps::apply_before(&mut apply, b))
+                .attr("title", "component")
+                .apply(|b| TitledBeforeProps::apply_after(&mut apply, b))
+                .__internal_element()
+        }
+
+        fn titled_after(props: impl TitledAfterPropsTrait) -> HtmlElement {
+            let TitledAfterProps { mut apply } = props.take();
+
+            DomBuilder::<HtmlElement>::new_html("div")
+                .apply(|b| TitledAfterProps::apply_before(&mut apply, b))
+                .attr("title", "component")
+                .apply(|b| TitledAfterProps::apply_after(&mut apply, b))
+                .__internal_element()
+        }
+
+        let caller_title = |b: DomBuilder<HtmlElement>| b.attr("title", "caller");
+
+        assert_eq!(
+            titled_before!({ .apply(caller_title) }).get_attribute("title"),
+            Some("component".to_string())
+        );
+        assert_eq!(
+            titled_after!({ .apply(caller_title) }).get_attribute("title"),
+            Some("caller".to_string())
+        );
+    }
+
+    #[cfg(feature = "dominator")]
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    fn apply_element_test() {
+        use dominator::{svg, Dom, DomBuilder};
+        use web_sys::SvgElement;
+
+        #[component(render_fn = icon, apply_element = web_sys::SvgElement)]
+        struct Icon {
+            #[default(16)]
+            size: u32,
+        }
+
+        #[component(render_fn = plain, apply = false)]
+        struct Plain {
+            #[default(1)]
+            value: u32,
+        }
+
+        fn icon(props: impl IconPropsTrait) -> Dom {
+            let IconProps { size, mut apply } = props.take();
+
+            svg!("svg", {
+                .attr("width", &size.to_string())
+                .apply(|b| IconProps::apply_before(&mut apply, b))
+            })
+        }
+
+        fn plain(props: impl PlainPropsTrait) -> u32 {
+            let PlainProps { value } = props.take();
+            value
+        }
+
+        let _icon = icon!({.apply(|b: DomBuilder<SvgElement>| b.attr("fill", "none"))});
+        assert_eq!(plain!({.value(2)}), 2);
+    }
+
+    #[cfg(feature = "dominator")]
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    fn render_fn_variants_test() {
+        use dominator::DomBuilder;
+        use web_sys::HtmlElement;
+
+        #[component(render_fn = [compact, full], apply = false)]
+        struct Entry {
+            #[default("title".to_string())]
+            title: String,
+            #[default("details".to_string())]
+            details: String,
+        }
+
+        fn compact(props: impl EntryPropsTrait) -> HtmlElement {
+            let EntryProps { title, .. } = props.take();
+
+            DomBuilder::<HtmlElement>::new_html("span")
+                .text(&title)
+                .__internal_element()
+        }
+
+        fn full(props: impl EntryPropsTrait) -> HtmlElement {
+            let EntryProps { title, details } = props.take();
+
+            DomBuilder::<HtmlElement>::new_html("div")
+                .text(&title)
+                .text(&details)
+                .__internal_element()
+        }
+
+        let compact = compact!({.title("a".to_string())});
+        let full = full!({.title("a".to_string()).details("b".to_string())});
+
+        assert_eq!(compact.tag_name(), "SPAN");
+        assert_eq!(compact.text_content(), Some("a".to_string()));
+        assert_eq!(full.tag_name(), "DIV");
+        assert_eq!(full.text_content(), Some("ab".to_string()));
+        assert_eq!(entry!({}).text_content(), Some("title".to_string()));
+    }
+
+    #[cfg(feature = "dominator")]
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    fn apply_arm_test() {
+        use dominator::DomBuilder;
+        use web_sys::HtmlElement;
+
+        #[component(render_fn = badge)]
+        struct Badge {
+            #[default("badge".to_string())]
+            label: String,
+        }
+
+        fn badge(props: impl BadgePropsTrait) -> HtmlElement {
+            let BadgeProps { label, mut apply } = props.take();
+
+            DomBuilder::<HtmlElement>::new_html("span")
+                .apply(|b| BadgeProps::apply_before(&mut apply, b))
+                .text(&label)
+                .__internal_element()
+        }
+
+        let applied = badge!(apply = |b: DomBuilder<HtmlElement>| b.attr("title", "hint"), {
+            .label("new".to_string())
+        });
+        let preset = badge!(BadgeProps::new().label("preset".to_string()) => {});
+
+        assert_eq!(applied.get_attribute("title"), Some("hint".to_string()));
+        assert_eq!(applied.text_content(), Some("new".to_string()));
+        assert_eq!(preset.text_content(), Some("preset".to_string()));
+    }
+
+    #[cfg(feature = "dominator")]
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    fn slot_test() {
+        use dominator::{html, DomBuilder};
+        use web_sys::HtmlElement;
+
+        #[component(render_fn = card)]
+        struct Card {
+            #[slot]
+            header: _,
+            #[slot]
+            #[default(html!("span", { .text("footer") }))]
+            footer: _,
+            #[slot]
+            body: Dom,
+        }
+
+        fn card(props: impl CardPropsTrait) -> HtmlElement {
+            let CardProps {
+                header,
+                footer,
+                body,
+                ..
+            } = props.take();
+
+            DomBuilder::<HtmlElement>::new_html("div")
+                .children(header)
+                .children(body)
+                .child(footer)
+                .__internal_element()
+        }
+
+        let rendered = card!({
+            .header(html!("h1", { .text("title") }))
+            .body(html!("p", { .text("body") }))
+        });
+
+        assert_eq!(rendered.text_content(), Some("titlebodyfooter".to_string()));
+        assert_eq!(card_scaffold!().text_content(), Some("footer".to_string()));
+    }
+
+    #[cfg(feature = "dominator")]
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    async fn theme_test() {
+        use dominator::html;
+        use futures_signals::signal::{Mutable, SignalExt};
+        use wasm_bindgen::JsValue;
+        use wasm_bindgen_futures::{js_sys::Promise, JsFuture};
+
+        #[component(render_fn = panel)]
+        struct Panel {
+            #[signal]
+            #[theme]
+            #[default("light")]
+            theme: &'static str,
+        }
+
+        fn panel(props: impl PanelPropsTrait + 'static) -> Dom {
+            let PanelProps { theme, .. } = props.take();
+
+            html!("div", {
+                .attr("id", "themed-panel")
+                .apply(|b| PanelProps::apply_theme(theme, b))
+            })
+        }
+
+        let dark_mode = Mutable::new(false);
+        let _handle = dominator::append_dom(
+            &dominator::body(),
+            panel!({.theme_signal(dark_mode.signal().map(|dark| if dark { "dark" } else { "light" }))}),
+        );
+        let theme = || {
+            dominator::body()
+                .query_selector("#themed-panel")
+                .unwrap()
+                .unwrap()
+                .get_attribute("data-theme")
+        };
+
+        JsFuture::from(Promise::resolve(&JsValue::NULL))
+            .await
+            .unwrap();
+        assert_eq!(theme(), Some("light".to_string()));
+
+        dark_mode.set(true);
+        JsFuture::from(Promise::resolve(&JsValue::NULL))
+            .await
+            .unwrap();
+        assert_eq!(theme(), Some("dark".to_string()));
+    }
+
+    #[cfg(feature = "dominator")]
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    fn events_test() {
+        use dominator::events::{Click, MouseEnter};
+        use dominator::DomBuilder;
+        use std::cell::Cell;
+        use std::rc::Rc;
+        use web_sys::HtmlElement;
+
+        #[component(render_fn = clickable)]
+        struct Clickable {
+            #[events(click: Click, mouse_enter: MouseEnter)]
+            events: _,
+        }
+
+        fn clickable(props: impl ClickablePropsTrait) -> HtmlElement {
+            let ClickableProps {
+                on_click,
+                on_mouse_enter,
+                ..
+            } = props.take();
+
+            DomBuilder::<HtmlElement>::new_html("button")
+                .apply_if(on_click.is_some(), |b| {
+                    b.event(move |event: Click| on_click.as_ref().unwrap()(event))
+                })
+                .apply_if(on_mouse_enter.is_some(), |b| {
+                    b.event(move |event: MouseEnter| on_mouse_enter.as_ref().unwrap()(event))
+                })
+                .__internal_element()
+        }
+
+        let clicks = Rc::new(Cell::new(0));
+        let button = clickable!({
+            .on_click({
+                let clicks = clicks.clone();
+                move |_: Click| clicks.set(clicks.get() + 1)
+            })
+        });
+
+        button.click();
+        button.click();
+        assert_eq!(clicks.get(), 2);
+    }
+
     #[test]
     fn attr_cmp_test() {
         let t = SomeButtonProps::new();
@@ -114,7 +408,7 @@ mod test {
 
     #[wasm_bindgen_test::wasm_bindgen_test]
     async fn default_val_test() {
-        #[component(render_fn = _r)]
+        #[component(render_fn = _r, derive(Default))]
         struct DefaultVal<T: PrimInt = i32> {
             #[signal]
             #[default(666)]
@@ -149,6 +443,132 @@ mod test {
         }
 
         default_val!({}).await;
+
+        let DefaultValProps { bar, .. } = DefaultValProps::default().take();
+        assert_eq!(bar, 123);
+    }
+
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    async fn as_stream_test() {
+        #[component(render_fn = _r)]
+        struct AsStream {
+            #[signal(as_stream)]
+            #[default(666)]
+            foo: i32,
+
+            #[signal_vec(as_stream)]
+            #[default(vec![1, 2])]
+            bar: i32,
+        }
+
+        async fn _r(p: impl AsStreamPropsTrait) {
+            let AsStreamProps { foo, bar, .. } = p.take();
+
+            assert_eq!(foo.collect::<Vec<_>>().await, vec![42]);
+
+            let diffs = bar.collect::<Vec<_>>().await;
+            assert!(matches!(&diffs[..], [VecDiff::Replace { values }] if values == &vec![1, 2]));
+        }
+
+        as_stream!({ .foo(42) }).await;
+    }
+
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    async fn async_take_test() {
+        #[component(render_fn = _r, async_take)]
+        struct AsyncTake<T: PrimInt = i32> {
+            #[default(123)]
+            foo: T,
+
+            #[signal]
+            bar: String,
+        }
+
+        async fn _r(p: impl AsyncTakePropsTrait) -> i32 {
+            let AsyncTakeProps { foo, bar, .. } = p.take().await;
+            assert!(bar.is_some());
+
+            foo.to_i32().unwrap()
+        }
+
+        assert_eq!(async_take!({ .bar("hi".to_string()) }).await, 123);
+    }
+
+    #[test]
+    fn delta_test() {
+        #[component(render_fn = _r, delta)]
+        struct Delta<T: PartialEq = i32> {
+            #[default(1)]
+            foo: i32,
+
+            bar: T,
+
+            #[signal]
+            baz: String,
+        }
+
+        fn _r(_p: impl DeltaPropsTrait) {}
+
+        let unchanged = DeltaProps::new().diff(&DeltaProps::new());
+        assert_eq!(unchanged, DeltaPropsDelta::default());
+        assert!(!unchanged.has_changes());
+
+        let delta = DeltaProps::new()
+            .bar("a")
+            .diff(&DeltaProps::new().foo(2).bar("b").baz("hi".to_string()));
+        assert!(delta.foo && delta.bar);
+        assert!(delta.has_changes());
+    }
+
+    #[test]
+    fn delta_changes_test() {
+        #[component(render_fn = _r, delta)]
+        struct Retained<'a, 'b, T: PartialEq = i32> {
+            #[default(1)]
+            count: i32,
+            name: &'a str,
+            value: T,
+            #[signal]
+            #[default("")]
+            label: &'b str,
+        }
+
+        fn _r<'a, 'b>(_p: impl RetainedPropsTrait<'a, 'b>) {}
+
+        #[derive(Debug, Default, PartialEq)]
+        struct Element {
+            count: i32,
+            name: Option<String>,
+            updates: usize,
+        }
+
+        let mut element = Element::default();
+        let old = RetainedProps::new().value(1);
+        let new = RetainedProps::new().count(2).name("new").value(1);
+
+        for change in old.changes(new) {
+            element.updates += 1;
+
+            match change {
+                RetainedPropsChange::Count(count) => element.count = count,
+                RetainedPropsChange::Name(name) => element.name = name.map(str::to_string),
+                RetainedPropsChange::Value(_) => unreachable!("value did not change"),
+                #[cfg(feature = "dominator")]
+                RetainedPropsChange::Apply(_) => unreachable!("apply did not change"),
+            }
+        }
+
+        assert_eq!(
+            element,
+            Element {
+                count: 2,
+                name: Some("new".to_string()),
+                updates: 2
+            }
+        );
+        assert!(RetainedProps::new()
+            .changes(RetainedProps::new())
+            .is_empty());
     }
 
     #[test]
@@ -178,4 +598,3212 @@ mod test {
         #[allow(dead_code)]
         fn consume_send(_: impl Signal<Item = impl Send>) {}
     }
+
+    #[test]
+    fn signal_suffix_test() {
+        #[component(render_fn = _r, signal_suffix = "_sig")]
+        struct SignalSuffix<T: ToString = i32> {
+            #[signal]
+            foo: T,
+
+            #[signal_vec]
+            bar: i32,
+        }
+
+        fn _r(_p: impl SignalSuffixPropsTrait) {}
+
+        _r(SignalSuffixProps::new()
+            .foo_sig(always("hi"))
+            .bar_sig_vec(futures_signals::signal_vec::always(vec![42])));
+
+        let t = trybuild::TestCases::new();
+
+        t.compile_fail("tests/build_fail_checks/invalid_signal_suffix.rs");
+    }
+
+    #[test]
+    fn cow_test() {
+        use std::borrow::Cow;
+
+        #[component(render_fn = _r)]
+        struct CowLabel {
+            #[cow]
+            label: String,
+
+            #[cow]
+            #[signal]
+            #[default("default")]
+            title: String,
+        }
+
+        fn _r(p: impl CowLabelPropsTrait) -> Option<Cow<'static, str>> {
+            p.take().label
+        }
+
+        let label = cow_label!({ .label("static").title("also static") }).unwrap();
+        assert!(matches!(label, Cow::Borrowed("static")));
+
+        let label = cow_label!({ .label("owned".to_string()) }).unwrap();
+        assert!(matches!(label, Cow::Owned(_)));
+
+        assert_eq!(cow_label_scaffold!(), Some(Cow::Borrowed("")));
+
+        let t = trybuild::TestCases::new();
+
+        t.compile_fail("tests/build_fail_checks/invalid_cow.rs");
+    }
+
+    #[test]
+    fn extends_test() {
+        #[component(
+            render_fn = _r,
+            extends = SomeButton,
+            extendable,
+            override(label = "preset".to_string(), unchanging_prop = 7)
+        )]
+        struct PresetButton {
+            #[default(true)]
+            extra: bool,
+        }
+
+        fn _r(p: impl PresetButtonPropsTrait) -> (i32, bool) {
+            use futures_signals::signal::SignalExt;
+            use futures_util::FutureExt;
+
+            let PresetButtonProps {
+                label,
+                unchanging_prop,
+                extra,
+                ..
+            } = p.take();
+
+            assert_eq!(label.first().to_future().now_or_never().unwrap(), "preset");
+
+            (unchanging_prop, extra)
+        }
+
+        assert_eq!(preset_button!({}), (7, true));
+        assert_eq!(
+            preset_button!({ .unchanging_prop(1).extra(false).foo("generic") }),
+            (1, false)
+        );
+
+        // Presets can in turn be extended, calling back into `#[component]` through a re-export
+        #[component(
+            render_fn = _r2,
+            extends = PresetButton,
+            component_macro_path = crate::test::facade::futures_signals_component_macro,
+            override(extra = false)
+        )]
+        struct PresetPresetButton {}
+
+        fn _r2(p: impl PresetPresetButtonPropsTrait) -> (i32, bool) {
+            let PresetPresetButtonProps {
+                unchanging_prop,
+                extra,
+                ..
+            } = p.take();
+
+            (unchanging_prop, extra)
+        }
+
+        assert_eq!(preset_preset_button!({}), (7, false));
+    }
+
+    #[test]
+    fn owned_signal_test() {
+        #[component(render_fn = _r)]
+        struct Owned<T: ToString = i32> {
+            #[signal(owned)]
+            foo: T,
+
+            #[signal_vec(owned, as_stream)]
+            bar: i32,
+        }
+
+        fn _r(p: impl OwnedPropsTrait) -> Box<dyn std::any::Any> {
+            // retaining the signal requires it to be 'static
+            Box::new(p.take().foo)
+        }
+
+        _r(OwnedProps::new().foo("static str"));
+        _r(OwnedProps::new().foo_signal(always(42)).bar(vec![1]));
+
+        let t = trybuild::TestCases::new();
+
+        t.compile_fail("tests/build_fail_checks/owned_borrowed.rs");
+    }
+
+    #[test]
+    fn signal_bounds_test() {
+        use futures_signals::signal::{Always, Mutable, MutableSignal};
+
+        trait Marker {}
+
+        impl<T> Marker for Always<T> {}
+        impl<T: Copy> Marker for MutableSignal<T> {}
+
+        #[component(render_fn = _r)]
+        struct Bounded {
+            #[signal(owned, bounds = "+ Unpin + Marker")]
+            #[default(1)]
+            foo: i32,
+        }
+
+        fn _r(p: impl BoundedPropsTrait) -> Box<dyn std::any::Any> {
+            fn marked<T: Unpin + Marker + 'static>(v: T) -> Box<dyn std::any::Any> {
+                Box::new(v)
+            }
+
+            marked(p.take().foo)
+        }
+
+        let shared = Mutable::new(2);
+
+        _r(BoundedProps::new());
+        _r(BoundedProps::new().foo(3));
+        _r(BoundedProps::new().foo_signal(shared.signal()));
+    }
+
+    #[cfg(feature = "wasm")]
+    #[test]
+    fn wasm_export_test() {
+        #[component(render_fn = _r, wasm_export = String)]
+        struct WasmExport<T: ToString = i32> {
+            #[signal]
+            #[default("hi".to_string())]
+            label: String,
+
+            #[default(1)]
+            count: u32,
+
+            generic: T,
+        }
+
+        fn _r(p: impl WasmExportPropsTrait) -> String {
+            use futures_signals::signal::SignalExt;
+            use futures_util::FutureExt;
+
+            let WasmExportProps { label, count, .. } = p.take();
+            let label = label.first().to_future().now_or_never().unwrap();
+
+            format!("{label} {count}")
+        }
+
+        assert_eq!(wasm_export_js(None, None), "hi 1");
+        assert_eq!(
+            wasm_export_js(Some("hello".to_string()), Some(2)),
+            "hello 2"
+        );
+    }
+
+    #[cfg(feature = "proptest")]
+    #[test]
+    fn arbitrary_test() {
+        use futures_signals::signal::SignalExt;
+        use futures_util::FutureExt;
+        use proptest::prelude::*;
+
+        #[component(render_fn = _r, arbitrary)]
+        struct Fuzzed<T: ToString = i32> {
+            #[signal]
+            #[default("label".to_string())]
+            label: String,
+
+            #[default(1)]
+            count: u32,
+
+            name: String,
+            flag: bool,
+            generic: T,
+
+            #[emits(u32)]
+            on_change: _,
+        }
+
+        fn _r(p: impl FuzzedPropsTrait) -> (String, u32, Option<String>, bool) {
+            let FuzzedProps {
+                label,
+                count,
+                name,
+                flag,
+                generic,
+                on_change,
+                ..
+            } = p.take();
+
+            assert!(generic.is_none());
+            assert!(on_change.is_none());
+
+            let label = label.first().to_future().now_or_never().unwrap();
+
+            (label, count, name, flag.unwrap_or_default())
+        }
+
+        // props injected by features, such as the `apply` prop of `dominator`, are printed after the declared fields
+        assert!(format!("{:?}", FuzzedProps::new().count(2)).starts_with(
+            "FuzzedProps { label: <signal>, count: 2, name: None, flag: None, generic: None, on_change: <handler>"
+        ));
+
+        proptest!(|(props in any::<FuzzedProps>())| {
+            let (label, _count, _name, _flag) = _r(props);
+            prop_assert_eq!(label, "label");
+        });
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn deprecated_runtime_test() {
+        use std::sync::Mutex;
+
+        static WARNINGS: Mutex<Vec<String>> = Mutex::new(vec![]);
+
+        struct CapturingLogger;
+
+        impl log::Log for CapturingLogger {
+            fn enabled(&self, _metadata: &log::Metadata) -> bool {
+                true
+            }
+
+            fn log(&self, record: &log::Record) {
+                WARNINGS.lock().unwrap().push(record.args().to_string());
+            }
+
+            fn flush(&self) {}
+        }
+
+        log::set_logger(&CapturingLogger).unwrap();
+        log::set_max_level(log::LevelFilter::Warn);
+
+        #[component(render_fn = _r)]
+        struct Migrating {
+            #[signal]
+            #[deprecated_runtime = "use title instead"]
+            label: String,
+
+            #[deprecated_runtime = "sizes are computed"]
+            #[default(1)]
+            size: i32,
+
+            title: String,
+        }
+
+        fn _r(_p: impl MigratingPropsTrait) {}
+
+        _r(MigratingProps::new().title("title".to_string()));
+        assert!(WARNINGS.lock().unwrap().is_empty());
+
+        _r(MigratingProps::new().label("a".to_string()).size(2));
+        _r(MigratingProps::new()
+            .label_signal(always("b".to_string()))
+            .size(3));
+
+        assert_eq!(
+            *WARNINGS.lock().unwrap(),
+            [
+                "MigratingProps::label_signal is deprecated: use title instead",
+                "MigratingProps::size is deprecated: sizes are computed"
+            ]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn from_json_test() {
+        #[component(render_fn = _r, from_json)]
+        struct Fixture<T: ToString = i32> {
+            #[default(1)]
+            count: u32,
+            title: String,
+            tags: Vec<String>,
+            #[cow]
+            #[default("cow")]
+            text: String,
+            #[signal]
+            #[default("label".to_string())]
+            label: String,
+            generic: T,
+        }
+
+        fn _r(_p: impl FixturePropsTrait) {}
+
+        let props = FixtureProps::from_json(
+            r#"{"count": 2, "tags": ["a", "b"], "text": "json", "label": "ignored", "generic": 3, "unknown": null}"#,
+        )
+        .unwrap();
+
+        assert_eq!(props.count, 2);
+        assert_eq!(props.title, None);
+        assert_eq!(props.tags, Some(vec!["a".to_string(), "b".to_string()]));
+        assert_eq!(props.text, "json");
+        assert!(props.generic.is_none());
+
+        assert_eq!(FixtureProps::from_json("{}").unwrap().count, 1);
+        assert!(FixtureProps::from_json(r#"{"count": "two"}"#).is_err());
+        assert!(FixtureProps::from_json("[]").is_err());
+    }
+
+    #[test]
+    fn binding_test() {
+        use futures_signals::signal::{Mutable, SignalExt};
+        use futures_util::FutureExt;
+
+        #[component(render_fn = text_input)]
+        struct TextInput {
+            #[binding]
+            #[default("initial".to_string())]
+            value: String,
+            #[binding]
+            checked: bool,
+        }
+
+        fn text_input(props: impl TextInputPropsTrait) -> impl Signal<Item = String> {
+            let TextInputProps { value, checked, .. } = props.take();
+
+            if let Some(checked) = checked {
+                checked.set(!checked.get());
+            }
+
+            let signal = value.signal_cloned();
+            value.set(format!("{} edited", value.get_cloned()));
+            signal
+        }
+
+        let value = Mutable::new("text".to_string());
+        let checked = Mutable::new(false);
+        let signal = text_input!({.value(value.clone()).checked(checked.clone())});
+
+        assert_eq!(value.get_cloned(), "text edited");
+        assert!(checked.get());
+        assert_eq!(
+            signal.to_stream().next().now_or_never(),
+            Some(Some("text edited".to_string()))
+        );
+
+        assert_eq!(
+            TextInputProps::new().value.get_cloned(),
+            "initial".to_string()
+        );
+    }
+
+    mod meta {
+        pub struct ComponentMeta {
+            pub name: &'static str,
+            pub docs: &'static str,
+            pub props: &'static [PropInfo],
+        }
+
+        pub struct PropInfo {
+            pub name: &'static str,
+            pub type_name: &'static str,
+            pub is_signal: bool,
+            pub is_signal_vec: bool,
+            pub default: Option<&'static str>,
+            pub docs: &'static str,
+        }
+
+        #[cfg(feature = "inventory")]
+        inventory::collect!(ComponentMeta);
+    }
+
+    #[test]
+    fn metadata_test() {
+        /// A gallery entry
+        ///
+        /// With two paragraphs
+        #[component(render_fn = _r, metadata = meta)]
+        struct GalleryEntry<'a, T: ToString = i32> {
+            /// The title of the entry
+            #[signal]
+            #[default("a title".to_string())]
+            title: String,
+            #[signal_vec]
+            tags: String,
+            #[default(vec![1, 2])]
+            counts: Vec<u32>,
+            #[cow]
+            text: String,
+            text_ref: &'a str,
+            generic: T,
+        }
+
+        fn _r<'a>(_p: impl GalleryEntryPropsTrait<'a>) {}
+
+        let meta = GalleryEntryProps::METADATA;
+
+        assert_eq!(meta.name, "GalleryEntry");
+        assert_eq!(meta.docs, "A gallery entry\n\nWith two paragraphs");
+        assert_eq!(meta.props.len(), 6);
+
+        let props = meta
+            .props
+            .iter()
+            .map(|p| (p.name, p.type_name, p.is_signal, p.is_signal_vec, p.default))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            props,
+            vec![
+                (
+                    "title",
+                    "String",
+                    true,
+                    false,
+                    Some("\"a title\".to_string()")
+                ),
+                ("tags", "String", false, true, None),
+                ("counts", "Vec<u32>", false, false, Some("vec![1, 2]")),
+                (
+                    "text",
+                    "::std::borrow::Cow<'static, str>",
+                    false,
+                    false,
+                    None
+                ),
+                ("text_ref", "&'a str", false, false, None),
+                ("generic", "T", false, false, None),
+            ]
+        );
+        assert_eq!(meta.props[0].docs, "The title of the entry");
+        assert_eq!(meta.props[1].docs, "");
+    }
+
+    #[cfg(feature = "inventory")]
+    #[test]
+    fn metadata_inventory_test() {
+        #[component(render_fn = _r, metadata = meta)]
+        struct RegisteredEntry {
+            title: String,
+        }
+
+        fn _r(_p: impl RegisteredEntryPropsTrait) {}
+
+        assert!(inventory::iter::<meta::ComponentMeta>
+            .into_iter()
+            .any(|meta| meta.name == "RegisteredEntry" && meta.props.len() == 1));
+    }
+
+    #[test]
+    fn ssr_render_fn_test() {
+        #[component(render_fn = client_render, ssr_render_fn = server_render)]
+        struct Hydrated {
+            #[default(1)]
+            count: i32,
+        }
+
+        fn client_render(p: impl HydratedPropsTrait) -> String {
+            format!("client {}", p.take().count)
+        }
+
+        #[cfg(feature = "ssr")]
+        fn server_render(p: impl HydratedPropsTrait) -> String {
+            format!("server {}", p.take().count)
+        }
+
+        // this crate has no `ssr` feature, so the client render fn is used
+        assert_eq!(hydrated!({.count(2)}), "client 2");
+        assert_eq!(hydrated!(render = client_render, {.count(3)}), "client 3");
+    }
+
+    #[test]
+    fn props_fn_test() {
+        #[component(render_fn = list_item, builder)]
+        struct ListItem<'a> {
+            #[default("")]
+            label: &'a str,
+        }
+
+        fn list_item<'a>(p: impl ListItemPropsTrait<'a>) -> &'a str {
+            p.take().label
+        }
+
+        let labels = ["a", "b"];
+        let items = labels
+            .iter()
+            .zip(std::iter::repeat_with(list_item_props))
+            .map(|(label, props)| list_item(props.label(label)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(items, labels);
+        assert_eq!(list_item_props().label, "");
+        assert_eq!(list_item(ListItem::builder().label("c")), "c");
+    }
+
+    #[test]
+    fn signal_from_test() {
+        use futures_signals::signal::{Mutable, SignalExt};
+        use futures_util::FutureExt;
+
+        #[derive(Clone)]
+        struct State {
+            name: String,
+            count: i32,
+        }
+
+        #[component(render_fn = _r)]
+        struct Bound {
+            #[signal]
+            #[default("".to_string())]
+            name: String,
+            #[signal]
+            #[default(0)]
+            count: i32,
+            #[signal(fallback = -1)]
+            loaded: i32,
+        }
+
+        fn _r(p: impl BoundPropsTrait) -> impl Signal<Item = (String, i32, i32)> {
+            let BoundProps {
+                name,
+                count,
+                loaded,
+                ..
+            } = p.take();
+
+            futures_signals::map_ref! {
+                let name = name,
+                let count = count,
+                let loaded = loaded => (name.clone(), *count, *loaded)
+            }
+        }
+
+        let state = Mutable::new(State {
+            name: "alice".to_string(),
+            count: 2,
+        });
+        let rendered = bound!({
+            .name_from(state.signal_cloned(), |s| s.name)
+            .count_from(state.signal_cloned(), |s| s.count * 2)
+            .loaded_from(state.signal_cloned(), |s| (s.count > 2).then_some(s.count))
+        });
+
+        let mut stream = rendered.to_stream();
+
+        assert_eq!(
+            stream.next().now_or_never(),
+            Some(Some(("alice".to_string(), 4, -1)))
+        );
+    }
+
+    #[test]
+    fn extra_setter_clash_test() {
+        use futures_signals::signal::SignalExt;
+        use futures_util::FutureExt;
+
+        // the `start_from` and `start_mutable` fields keep their setters, and `start` goes without its extra setters
+        #[component(render_fn = _r)]
+        struct Range {
+            #[signal]
+            #[default(0)]
+            start: u32,
+            #[default(1)]
+            start_from: u32,
+            #[default(1)]
+            start_mutable: u32,
+        }
+
+        fn _r(p: impl RangePropsTrait) -> (u32, u32, u32) {
+            let RangeProps {
+                start,
+                start_from,
+                start_mutable,
+                ..
+            } = p.take();
+
+            (
+                start.first().to_future().now_or_never().unwrap(),
+                start_from,
+                start_mutable,
+            )
+        }
+
+        assert_eq!(
+            range!({.start(2).start_from(3).start_mutable(4)}),
+            (2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn max_size_test() {
+        #[component(render_fn = _r, max_size = 32)]
+        struct Compact<'a> {
+            #[default(0)]
+            count: u64,
+            #[default("")]
+            label: &'a str,
+        }
+
+        fn _r<'a>(_p: impl CompactPropsTrait<'a>) {}
+
+        let t = trybuild::TestCases::new();
+
+        t.compile_fail("tests/build_fail_checks/max_size.rs");
+    }
+
+    #[cfg(feature = "intern")]
+    #[test]
+    fn intern_test() {
+        use std::sync::Arc;
+
+        #[component(render_fn = tag)]
+        struct Tag {
+            #[intern]
+            label: String,
+            #[intern]
+            #[default("tag")]
+            kind: String,
+        }
+
+        fn tag(p: impl TagPropsTrait) -> (Option<Arc<str>>, Arc<str>) {
+            let TagProps { label, kind, .. } = p.take();
+            (label, kind)
+        }
+
+        let (first_label, first_kind) = tag!({.label("label")});
+        let (second_label, second_kind) = tag!({.label("label".to_string()).kind("tag")});
+        let (other_label, _) = tag!({.label("other")});
+
+        let first_label = first_label.unwrap();
+        assert_eq!(&*first_label, "label");
+        assert!(Arc::ptr_eq(&first_label, &second_label.unwrap()));
+        assert!(Arc::ptr_eq(&first_kind, &second_kind));
+        assert_eq!(&*other_label.unwrap(), "other");
+    }
+
+    #[test]
+    fn default_once_test() {
+        use futures_signals::signal::SignalExt;
+        use futures_util::FutureExt;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static EVALUATIONS: AtomicUsize = AtomicUsize::new(0);
+
+        fn expensive() -> Vec<String> {
+            EVALUATIONS.fetch_add(1, Ordering::SeqCst);
+            vec!["a".to_string(), "b".to_string()]
+        }
+
+        #[component(render_fn = _r)]
+        struct Configured {
+            #[default_once(expensive())]
+            config: Vec<String>,
+            #[signal]
+            #[default_once(expensive().join(","))]
+            joined: String,
+        }
+
+        fn _r(p: impl ConfiguredPropsTrait) -> (Vec<String>, impl Signal<Item = String>) {
+            let ConfiguredProps { config, joined, .. } = p.take();
+            (config, joined)
+        }
+
+        let (first, _) = configured!({});
+        let (second, joined) = configured!({});
+        let (overridden, _) = configured!({.config(vec![])});
+
+        assert_eq!(first, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(first, second);
+        assert!(overridden.is_empty());
+        assert_eq!(
+            joined.to_stream().next().now_or_never(),
+            Some(Some("a,b".to_string()))
+        );
+        assert_eq!(EVALUATIONS.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn target_default_test() {
+        use futures_signals::signal::SignalExt;
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r)]
+        struct Asset {
+            #[cfg_native(default = "./logo.png".to_string())]
+            #[cfg_wasm(default = "/static/logo.png".to_string())]
+            path: String,
+            #[signal]
+            #[default(1.0)]
+            #[cfg_wasm(default = 2.0)]
+            scale: f64,
+        }
+
+        fn _r(p: impl AssetPropsTrait) -> (String, f64) {
+            let AssetProps { path, scale, .. } = p.take();
+
+            (path, scale.first().to_future().now_or_never().unwrap())
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        assert_eq!(asset!({}), ("./logo.png".to_string(), 1.0));
+        #[cfg(target_arch = "wasm32")]
+        assert_eq!(asset!({}), ("/static/logo.png".to_string(), 2.0));
+
+        assert_eq!(
+            asset!({.path("a.png".to_string()).scale(3.0)}),
+            ("a.png".to_string(), 3.0)
+        );
+    }
+
+    #[test]
+    fn render_fn_path_test() {
+        #[component(render_fn = StepperProps::render)]
+        struct Stepper<T: ToString = i32> {
+            #[default(1)]
+            step: i32,
+            label: T,
+        }
+
+        impl StepperProps {
+            fn render(p: impl StepperPropsTrait) -> (i32, Option<String>) {
+                let StepperProps { step, label, .. } = p.take();
+                (step, label.map(|l| l.to_string()))
+            }
+        }
+
+        assert_eq!(stepper!({.step(2)}), (2, None));
+        assert_eq!(stepper!({.label("label")}), (1, Some("label".to_string())));
+    }
+
+    #[test]
+    fn defaults_const_test() {
+        #[component(render_fn = _r)]
+        struct DocumentedField<T: ToString = i32> {
+            #[default(-1)]
+            count: i32,
+            #[signal(fallback = "loading".to_string())]
+            name: String,
+            #[default_once(std::collections::HashMap::from([(1, 2)]))]
+            lookup: std::collections::HashMap<i32, i32>,
+            #[cow]
+            #[default("text")]
+            text: String,
+            generic: T,
+        }
+
+        fn _r(_p: impl DocumentedFieldPropsTrait) {}
+
+        assert_eq!(
+            DOCUMENTED_FIELD_DEFAULTS,
+            [
+                ("count", "-1"),
+                ("name", "\"loading\".to_string()"),
+                ("lookup", "std::collections::HashMap::from([(1, 2)])"),
+                ("text", "\"text\""),
+            ]
+        );
+    }
+
+    #[test]
+    fn collect_test() {
+        use std::collections::BTreeSet;
+
+        #[component(render_fn = tag_list)]
+        struct TagList {
+            #[collect]
+            tags: Vec<String>,
+            #[collect]
+            #[default(vec![1])]
+            sizes: Vec<u64>,
+        }
+
+        fn tag_list(p: impl TagListPropsTrait) -> (Option<Vec<String>>, Vec<u64>) {
+            let TagListProps { tags, sizes, .. } = p.take();
+            (tags, sizes)
+        }
+
+        assert_eq!(
+            tag_list!({.tags(["a", "b"])}),
+            (Some(vec!["a".to_string(), "b".to_string()]), vec![1])
+        );
+        assert_eq!(
+            tag_list!({.sizes(BTreeSet::from([3u32, 2u32])).tags(Vec::<String>::new())}),
+            (Some(vec![]), vec![2, 3])
+        );
+        assert_eq!(tag_list_scaffold!(), (Some(vec![]), vec![1]));
+    }
+
+    #[test]
+    fn on_change_test() {
+        use futures_signals::signal::{Mutable, SignalExt};
+        use futures_util::FutureExt;
+        use std::sync::Mutex;
+
+        static SEEN: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+        fn record(value: &str) {
+            SEEN.lock().unwrap().push(value.to_string());
+        }
+
+        #[component(render_fn = _r)]
+        struct Watched {
+            #[signal(on_change = record)]
+            #[default("default".to_string())]
+            label: String,
+            #[signal(fallback = 0, on_change = |v: &i32| SEEN.lock().unwrap().push(v.to_string()))]
+            count: i32,
+        }
+
+        fn _r(p: impl WatchedPropsTrait) -> impl Signal<Item = (String, i32)> {
+            let WatchedProps { label, count, .. } = p.take();
+
+            futures_signals::map_ref! {
+                let label = label,
+                let count = count => (label.clone(), *count)
+            }
+        }
+
+        let label = Mutable::new("first".to_string());
+        let mut stream = watched!({.label_signal(label.signal_cloned()).count(2)}).to_stream();
+
+        assert_eq!(
+            stream.next().now_or_never(),
+            Some(Some(("first".to_string(), 2)))
+        );
+
+        label.set("second".to_string());
+
+        assert_eq!(
+            stream.next().now_or_never(),
+            Some(Some(("second".to_string(), 2)))
+        );
+        assert_eq!(*SEEN.lock().unwrap(), vec!["first", "2", "second"]);
+    }
+
+    #[test]
+    fn required_test() {
+        #[component(render_fn = _r, delta)]
+        struct Required<'a> {
+            #[required]
+            label: &'a str,
+            #[required]
+            #[try_into]
+            count: u8,
+            #[default(true)]
+            enabled: bool,
+            hint: String,
+        }
+
+        fn _r<'a>(p: impl RequiredPropsTrait<'a>) -> (&'a str, u8, bool, Option<String>) {
+            let RequiredProps {
+                label,
+                count,
+                enabled,
+                hint,
+                ..
+            } = p.take();
+
+            (label, count, enabled, hint)
+        }
+
+        // the required props can be set in any order, along with the other props
+        assert_eq!(
+            required!({.count(3).unwrap().enabled(false).label("a")}),
+            ("a", 3, false, None)
+        );
+        assert_eq!(
+            required!({.label("b").hint("h".to_string()).count(4).unwrap()}),
+            ("b", 4, true, Some("h".to_string()))
+        );
+
+        let props = RequiredProps::new().label("c").count(5).unwrap();
+        assert_eq!(props.count_set_fields(), 0);
+        assert!(!props
+            .diff(&RequiredProps::new().label("c").count(5).unwrap())
+            .has_changes());
+
+        // `with()` takes the required props in the order they are declared
+        assert_eq!(_r(RequiredProps::with("d", 6)), ("d", 6, true, None));
+        assert_eq!(
+            _r(RequiredProps::with("e", 7).hint("h".to_string())),
+            ("e", 7, true, Some("h".to_string()))
+        );
+
+        // the expected error names the props type, which has the param of the `apply` prop with the `dominator` feature
+        #[cfg(not(feature = "dominator"))]
+        {
+            let t = trybuild::TestCases::new();
+
+            t.compile_fail("tests/build_fail_checks/missing_required.rs");
+        }
+    }
+
+    #[test]
+    fn thread_safe_test() {
+        #[component(render_fn = _r, thread_safe)]
+        struct ThreadSafe<T: ToString = i32> {
+            #[signal]
+            #[default(0)]
+            count: i32,
+            #[signal_vec]
+            #[default(vec![])]
+            items: String,
+            value: T,
+        }
+
+        fn _r(p: impl ThreadSafePropsTrait + 'static) -> std::thread::JoinHandle<Option<String>> {
+            std::thread::spawn(move || p.take().value.map(|v| v.to_string()))
+        }
+
+        fn assert_send_sync<T: Send + Sync>(_v: &T) {}
+
+        let count = futures_signals::signal::Mutable::new(1);
+        let props = ThreadSafeProps::new().count_signal(count.signal()).value(2);
+        assert_send_sync(&props);
+
+        assert_eq!(_r(props).join().unwrap(), Some("2".to_string()));
+        assert_eq!(thread_safe!({}).join().unwrap(), None);
+    }
+
+    #[test]
+    fn into_test() {
+        use futures_signals::signal::SignalExt;
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r)]
+        struct Converting {
+            #[into]
+            label: String,
+            #[into]
+            #[default(std::path::PathBuf::new())]
+            path: std::path::PathBuf,
+            #[into]
+            #[signal]
+            #[default("".to_string())]
+            title: String,
+        }
+
+        fn _r(
+            p: impl ConvertingPropsTrait,
+        ) -> impl Signal<Item = (Option<String>, std::path::PathBuf, String)> {
+            let ConvertingProps {
+                label, path, title, ..
+            } = p.take();
+
+            title.map(move |title| (label.clone(), path.clone(), title))
+        }
+
+        let rendered = converting!({.label("hi").path("/tmp").title("t".to_string())});
+        let mut stream = rendered.to_stream();
+
+        assert_eq!(
+            stream.next().now_or_never(),
+            Some(Some((
+                Some("hi".to_string()),
+                std::path::PathBuf::from("/tmp"),
+                "t".to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn signal_vec_into_test() {
+        use futures_signals::signal::SignalExt;
+        use futures_signals::signal_vec::{MutableVec, SignalVecExt};
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r)]
+        struct Values {
+            #[into]
+            #[signal_vec]
+            #[default(vec![0])]
+            values: i32,
+        }
+
+        fn _r(p: impl ValuesPropsTrait) -> Vec<i32> {
+            let ValuesProps { values, .. } = p.take();
+
+            values
+                .to_signal_cloned()
+                .first()
+                .to_future()
+                .now_or_never()
+                .unwrap()
+        }
+
+        let shared = MutableVec::new_with_values(vec![4, 5]);
+
+        assert_eq!(values!({}), vec![0]);
+        assert_eq!(values!({.values(vec![1u8, 2])}), vec![1, 2]);
+        assert_eq!(values!({.values([3i16])}), vec![3]);
+        assert_eq!(
+            values!({.values_signal_vec(shared.signal_vec())}),
+            vec![4, 5]
+        );
+    }
+
+    #[test]
+    fn macro_name_test() {
+        #[component(render_fn = render_cool_button, macro_name = cool_button)]
+        struct CoolButtonCmp {
+            #[default(1)]
+            count: i32,
+            label: String,
+        }
+
+        fn render_cool_button(p: impl CoolButtonCmpPropsTrait) -> (i32, Option<String>) {
+            let CoolButtonCmpProps { count, label, .. } = p.take();
+            (count, label)
+        }
+
+        assert_eq!(cool_button!({.count(2)}), (2, None));
+        assert_eq!(cool_button_scaffold!(), (1, Some(String::new())));
+        assert_eq!(cool_button_prop_names!()[..2], ["count", "label"]);
+    }
+
+    #[test]
+    fn eq_ignoring_signals_test() {
+        #[component(render_fn = _r)]
+        struct Memo<T: PartialEq = i32> {
+            #[signal]
+            #[default("".to_string())]
+            label: String,
+            #[default(1)]
+            count: i32,
+            value: T,
+            #[emits(i32)]
+            on_change: _,
+        }
+
+        fn _r(_p: impl MemoPropsTrait) {}
+
+        let label = futures_signals::signal::Mutable::new("a".to_string());
+        let props = MemoProps::new()
+            .label_signal(label.signal_cloned())
+            .value(2)
+            .on_change(|_| {});
+
+        assert!(props.eq_ignoring_signals(
+            &MemoProps::new()
+                .label_signal(label.signal_cloned())
+                .value(2)
+                .on_change(|_| {})
+        ));
+        assert!(!props.eq_ignoring_signals(
+            &MemoProps::new()
+                .label_signal(label.signal_cloned())
+                .count(2)
+                .value(2)
+                .on_change(|_| {})
+        ));
+        assert!(!MemoProps::new().eq_ignoring_signals(&MemoProps::new().value(1)));
+    }
+
+    #[test]
+    fn debug_test() {
+        use futures_signals::signal::always;
+
+        #[component(render_fn = _r, derive(Debug))]
+        struct Labeled<'a> {
+            #[required]
+            label: &'a str,
+            #[signal]
+            #[default(1)]
+            count: i32,
+            #[signal_vec]
+            #[default(vec![])]
+            tags: String,
+            hint: String,
+            #[emits(u8)]
+            on_pick: _,
+        }
+
+        fn _r<'a>(p: impl LabeledPropsTrait<'a>) -> &'a str {
+            p.take().label
+        }
+
+        // props injected by features, such as the `apply` prop of `dominator`, are printed after the declared fields
+        assert!(format!("{:?}", LabeledProps::new()).starts_with(
+            "LabeledProps { label: (), count: <signal>, tags: <signal_vec>, hint: None, on_pick: <handler>"
+        ));
+        assert!(format!(
+            "{:?}",
+            LabeledProps::with("a")
+                .count_signal(always(2))
+                .hint("h".to_string())
+        )
+        .starts_with(
+            "LabeledProps { label: \"a\", count: <signal>, tags: <signal_vec>, hint: Some(\"h\"), on_pick: <handler>"
+        ));
+    }
+
+    #[test]
+    fn signal_option_test() {
+        use futures_signals::signal::{Mutable, SignalExt};
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r)]
+        struct Hinted {
+            #[signal(default_none)]
+            hint: Option<String>,
+            #[signal]
+            #[default(Some(1))]
+            level: Option<u8>,
+            #[signal]
+            title: Option<String>,
+        }
+
+        fn _r(p: impl HintedPropsTrait) -> (Option<String>, Option<u8>, Option<Option<String>>) {
+            let HintedProps {
+                hint, level, title, ..
+            } = p.take();
+
+            (
+                hint.first().to_future().now_or_never().unwrap(),
+                level.first().to_future().now_or_never().unwrap(),
+                title.map(|title| title.first().to_future().now_or_never().unwrap()),
+            )
+        }
+
+        let hint = Mutable::new(Some("hint".to_string()));
+
+        assert_eq!(hinted!({}), (None, Some(1), None));
+        assert_eq!(
+            hinted!({.hint_signal(hint.signal_cloned()).level(None).title(None)}),
+            (Some("hint".to_string()), None, Some(None))
+        );
+    }
+
+    #[test]
+    fn props_receiver_arm_test() {
+        #[component(render_fn = _r)]
+        struct Tag {
+            #[default("tag".to_string())]
+            text: String,
+            #[default(false)]
+            upper: bool,
+        }
+
+        fn _r(p: impl TagPropsTrait) -> String {
+            let TagProps { text, upper, .. } = p.take();
+
+            if upper {
+                text.to_uppercase()
+            } else {
+                text
+            }
+        }
+
+        let shouting = TagProps::new().upper(true);
+
+        assert_eq!(tag!(shouting => {.text("hi".to_string())}), "HI");
+        assert_eq!(tag!(TagProps::new().text("a".to_string()) => {}), "a");
+        assert_eq!(tag!({}), "tag");
+    }
+
+    #[test]
+    fn observable_test() {
+        use futures_signals::signal::{Mutable, SignalExt};
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r)]
+        struct Stepper {
+            #[observable]
+            #[default(0)]
+            value: i32,
+            #[observable]
+            step: i32,
+        }
+
+        fn _r(p: impl StepperPropsTrait) -> (i32, i32) {
+            let StepperProps { value, step, .. } = p.take();
+            let value: Mutable<i32> = value;
+            let step = step.map_or(1, |step| step.get());
+            let observed = value.signal().first();
+
+            value.replace_with(|value| *value + step);
+
+            (observed.to_future().now_or_never().unwrap(), value.get())
+        }
+
+        assert_eq!(stepper!({}), (1, 1));
+        assert_eq!(stepper!({.value(10).step(5)}), (15, 15));
+    }
+
+    #[test]
+    fn mutable_setter_test() {
+        use futures_signals::signal::{Mutable, SignalExt};
+        use futures_util::FutureExt;
+
+        #[derive(Debug, PartialEq)]
+        struct Opaque;
+
+        #[component(render_fn = _r)]
+        struct Labeled {
+            #[signal]
+            #[default("".to_string())]
+            label: String,
+            #[signal(fallback = 0)]
+            count: u32,
+            #[signal]
+            #[default(Opaque)]
+            opaque: Opaque,
+        }
+
+        fn _r(p: impl LabeledPropsTrait) -> (String, u32, Opaque) {
+            let LabeledProps {
+                label,
+                count,
+                opaque,
+                ..
+            } = p.take();
+
+            (
+                label.first().to_future().now_or_never().unwrap(),
+                count.first().to_future().now_or_never().unwrap(),
+                opaque.first().to_future().now_or_never().unwrap(),
+            )
+        }
+
+        let label = Mutable::new("hello".to_string());
+        let count = Mutable::new(Some(3));
+
+        assert_eq!(
+            labeled!({.label_mutable(&label).count_mutable(&count)}),
+            ("hello".to_string(), 3, Opaque)
+        );
+
+        // `Opaque` is not `Clone`, which leaves `opaque_mutable()` uncallable, while the other setters still work
+        let opaque = Mutable::new(Opaque);
+        assert_eq!(
+            labeled!({.opaque_signal(opaque.signal_ref(|_| Opaque))}),
+            ("".to_string(), 0, Opaque)
+        );
+    }
+
+    #[test]
+    fn signal_map_test() {
+        use futures_signals::signal::{Mutable, SignalExt};
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r)]
+        struct Status {
+            #[signal(map = |online: bool| if online { "online" } else { "offline" }.to_string())]
+            #[default(false)]
+            label: String,
+            #[signal(map = |count: u32| count * 2, on_change = |_: &u32| {})]
+            doubled: u32,
+        }
+
+        fn _r(p: impl StatusPropsTrait) -> (String, Option<u32>) {
+            let StatusProps { label, doubled, .. } = p.take();
+
+            (
+                label.first().to_future().now_or_never().unwrap(),
+                doubled.map(|doubled| doubled.first().to_future().now_or_never().unwrap()),
+            )
+        }
+
+        let online = Mutable::new(true);
+
+        assert_eq!(status!({}), ("offline".to_string(), None));
+        assert_eq!(
+            status!({.label_signal(online.signal()).doubled(4)}),
+            ("online".to_string(), Some(8))
+        );
+        assert_eq!(
+            status!({.label(false).doubled_from(online.signal(), |online| online as u32)}),
+            ("offline".to_string(), Some(2))
+        );
+    }
+
+    #[test]
+    fn shared_signal_test() {
+        use futures_signals::signal::{always, Mutable, SignalExt};
+        use futures_util::FutureExt;
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        #[component(render_fn = _r)]
+        struct Document {
+            #[signal(shared)]
+            #[default(Arc::new("untitled".to_string()))]
+            title: Arc<String>,
+            #[signal(shared)]
+            lines: Rc<Vec<String>>,
+        }
+
+        fn _r(p: impl DocumentPropsTrait) -> (Arc<String>, Option<Rc<Vec<String>>>) {
+            let DocumentProps { title, lines, .. } = p.take();
+
+            (
+                title.first().to_future().now_or_never().unwrap(),
+                lines.map(|lines| lines.first().to_future().now_or_never().unwrap()),
+            )
+        }
+
+        let title = Arc::new("notes".to_string());
+        let shared_title = Mutable::new(title.clone());
+
+        assert_eq!(document!({}), (Arc::new("untitled".to_string()), None));
+        assert_eq!(
+            document!({.title("draft".to_string()).lines(vec!["a".to_string()])}),
+            (
+                Arc::new("draft".to_string()),
+                Some(Rc::new(vec!["a".to_string()]))
+            )
+        );
+
+        let (rendered, _) = document!({.title(title.clone())});
+        assert!(Arc::ptr_eq(&rendered, &title));
+
+        let (rendered, _) = document!({.title_signal(shared_title.signal_cloned())});
+        assert!(Arc::ptr_eq(&rendered, &title));
+
+        let (rendered, _) = document!({.title_signal(always(Arc::new("copy".to_string())))});
+        assert_eq!(*rendered, "copy");
+    }
+
+    #[test]
+    fn accessors_test() {
+        use futures_signals::signal::{always, SignalExt};
+        use futures_util::FutureExt;
+        use std::borrow::Cow;
+
+        #[component(render_fn = _r, accessors)]
+        struct Gauge {
+            #[default(10)]
+            unchanging_prop: u32,
+            #[signal]
+            #[default(0)]
+            level: u32,
+            #[cow]
+            label: String,
+            #[required]
+            unit: &'static str,
+        }
+
+        fn _r(p: impl GaugePropsTrait) -> (u32, Option<String>, &'static str, u32) {
+            let max = *p.unchanging_prop();
+            let label = p.label().map(|label| label.to_string());
+            let unit = *p.unit();
+            let level = p.take().level.first().to_future().now_or_never().unwrap();
+
+            (max, label, unit, level.min(max))
+        }
+
+        let props = GaugeProps::with("%").level_signal(always(20));
+        assert_eq!(GaugePropsTrait::label(&props), None::<&Cow<'static, str>>);
+
+        assert_eq!(_r(props), (10, None, "%", 10));
+        assert_eq!(
+            gauge!({.unit("kb").unchanging_prop(100).level(5).label("disk")}),
+            (100, Some("disk".to_string()), "kb", 5)
+        );
+    }
+
+    #[test]
+    fn path_attribute_test() {
+        // attributes named by a path rather than an ident are passed over when collecting the docs
+        #[component(render_fn = _r)]
+        /// A component with path attributes
+        #[rustfmt::skip]
+        struct Skipped {
+            /// A field with a path attribute
+            #[rustfmt::skip]
+            #[default(1)]
+            value: i32,
+        }
+
+        fn _r(p: impl SkippedPropsTrait) -> i32 {
+            p.take().value
+        }
+
+        assert_eq!(skipped!({.value(2)}), 2);
+    }
+
+    #[test]
+    fn field_order_test() {
+        use futures_signals::signal::{always, SignalExt};
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r, derive(Debug))]
+        struct Interleaved {
+            first: u8,
+            #[signal]
+            #[default(2)]
+            second: u8,
+            #[required]
+            third: u8,
+            #[default(4)]
+            fourth: u8,
+            #[signal_vec]
+            #[default(vec![])]
+            fifth: u8,
+        }
+
+        fn _r(p: impl InterleavedPropsTrait) -> (Option<u8>, u8, u8, u8) {
+            let InterleavedProps {
+                first,
+                second,
+                third,
+                fourth,
+                ..
+            } = p.take();
+
+            (
+                first,
+                second.first().to_future().now_or_never().unwrap(),
+                third,
+                fourth,
+            )
+        }
+
+        assert_eq!(interleaved!({.third(3).first(1)}), (Some(1), 2, 3, 4));
+        // props injected by features, such as the `apply` prop of `dominator`, come after the declared fields
+        assert!(format!(
+            "{:?}",
+            InterleavedProps::with(3)
+                .second_signal(always(2))
+                .first(1)
+        )
+        .starts_with(
+            "InterleavedProps { first: Some(1), second: <signal>, third: 3, fourth: 4, fifth: <signal_vec>"
+        ));
+    }
+
+    #[test]
+    fn clone_plain_props_test() {
+        #[component(render_fn = _r, derive(Clone))]
+        struct Badge<T: Clone = i32> {
+            #[default("new".to_string())]
+            text: String,
+            #[default(1)]
+            count: i32,
+            value: T,
+        }
+
+        fn _r(p: impl BadgePropsTrait) -> (String, i32) {
+            let BadgeProps { text, count, .. } = p.take();
+            (text, count)
+        }
+
+        // a field without `Clone` only leaves the props without the impl
+        #[component(render_fn = _r2, derive(Clone))]
+        struct Unclonable {
+            handle: std::sync::Mutex<i32>,
+        }
+
+        fn _r2(_p: impl UnclonablePropsTrait) {}
+
+        let base = BadgeProps::new().text("sale".to_string()).value(2);
+
+        assert_eq!(_r(base.clone().count(2)), ("sale".to_string(), 2));
+        assert_eq!(_r(base), ("sale".to_string(), 1));
+        _r2(UnclonableProps::new());
+    }
+
+    #[test]
+    fn props_and_trait_name_test() {
+        #[component(
+            render_fn = _r,
+            props_name = ListItemConfig,
+            trait_name = ListItem,
+            delta,
+            dyn_trait
+        )]
+        struct Item {
+            #[signal]
+            #[default(0)]
+            index: usize,
+            #[default(false)]
+            selected: bool,
+        }
+
+        fn _r(p: impl ListItem) -> bool {
+            let ListItemConfig { selected, .. } = p.take();
+            selected
+        }
+
+        assert!(item!({.selected(true)}));
+        assert!(
+            ListItemConfig::new()
+                .diff(&ListItemConfig::new().selected(true))
+                .selected
+        );
+
+        let boxed: Box<dyn DynListItem> = Box::new(ListItemConfig::new().selected(true));
+        assert!(_r(boxed));
+        assert!(!_r(item_props()));
+    }
+
+    #[test]
+    fn duration_ms_test() {
+        use std::time::Duration;
+
+        #[component(render_fn = _r)]
+        struct Delayed {
+            #[duration_ms]
+            #[default(Duration::ZERO)]
+            delay: Duration,
+            #[duration_ms]
+            #[required]
+            timeout: std::time::Duration,
+        }
+
+        fn _r(p: impl DelayedPropsTrait) -> (Duration, Duration) {
+            let DelayedProps { delay, timeout, .. } = p.take();
+            (delay, timeout)
+        }
+
+        assert_eq!(
+            delayed!({.delay_ms(200).timeout_ms(1000)}),
+            (Duration::from_millis(200), Duration::from_secs(1))
+        );
+        assert_eq!(
+            delayed!({.timeout(Duration::from_secs(2))}),
+            (Duration::ZERO, Duration::from_secs(2))
+        );
+    }
+
+    #[test]
+    fn signal_ref_test() {
+        use futures_signals::map_ref;
+        use futures_signals::signal::{Mutable, SignalExt};
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r)]
+        struct Summary {
+            #[signal_ref]
+            #[default(vec![])]
+            items: Vec<String>,
+            #[signal_ref]
+            title: String,
+        }
+
+        fn _r(p: impl SummaryPropsTrait) -> (usize, Option<usize>) {
+            let SummaryProps { items, title, .. } = p.take();
+            let total_len = items
+                .signal_ref(|items| items.iter().map(String::len).sum::<usize>())
+                .first()
+                .to_future()
+                .now_or_never()
+                .unwrap();
+            let title_len = title.map(|title| {
+                title
+                    .signal_ref(String::len)
+                    .first()
+                    .to_future()
+                    .now_or_never()
+                    .unwrap()
+            });
+
+            (total_len, title_len)
+        }
+
+        let items = Mutable::new(vec!["ab".to_string(), "c".to_string()]);
+        let prefix = Mutable::new("x".to_string());
+        let prefixed = map_ref! {
+            let items = items.signal_cloned(),
+            let prefix = prefix.signal_cloned() =>
+            items.iter().map(|item| format!("{prefix}{item}")).collect::<Vec<_>>()
+        };
+
+        assert_eq!(summary!({.items_signal_ref(prefixed)}), (5, None));
+        assert_eq!(
+            summary!({.items(vec!["abc".to_string()]).title("title".to_string())}),
+            (3, Some(5))
+        );
+    }
+
+    #[test]
+    fn generic_default_test() {
+        use futures_signals::signal::SignalExt;
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r)]
+        struct Measured<
+            'a,
+            T: Default + Into<i64> = u16,
+            U: From<u8> + Into<i64> = u32,
+            const N: usize,
+        > {
+            #[default(T::default())]
+            value: T,
+            #[signal]
+            #[default(U::from(3))]
+            other: U,
+            #[default(std::mem::size_of::<T>() * N)]
+            size: usize,
+            #[default([0; N])]
+            buffer: [u8; N],
+            #[default("")]
+            label: &'a str,
+        }
+
+        fn _r<'a, const N: usize>(p: impl MeasuredPropsTrait<'a, N>) -> (i64, i64, usize) {
+            let MeasuredProps {
+                value, other, size, ..
+            } = p.take();
+
+            (
+                value.into(),
+                other.first().to_future().now_or_never().unwrap().into(),
+                size,
+            )
+        }
+
+        assert_eq!(_r(MeasuredProps::<3>::new()), (0, 3, 6));
+        assert_eq!(_r(MeasuredProps::<1>::new().value(7i8)), (7, 3, 2));
+    }
+
+    #[test]
+    fn default_signal_test() {
+        use futures_signals::signal::{Mutable, SignalExt};
+        use futures_util::FutureExt;
+
+        let shared = Mutable::new(2);
+
+        #[component(render_fn = _r)]
+        struct Scaled {
+            #[signal]
+            #[default_signal(futures_signals::signal::always(3).map(|v| v * 10))]
+            factor: i32,
+            #[default(1)]
+            value: i32,
+        }
+
+        fn _r(p: impl ScaledPropsTrait) -> i32 {
+            let ScaledProps { factor, value, .. } = p.take();
+            factor.first().to_future().now_or_never().unwrap() * value
+        }
+
+        assert_eq!(scaled!({}), 30);
+        assert_eq!(scaled!({.factor(4).value(2)}), 8);
+        assert_eq!(scaled!({.factor_signal(shared.signal())}), 2);
+    }
+
+    mod facade {
+        pub use futures_signals;
+        pub use futures_signals_component_macro;
+    }
+
+    #[test]
+    fn futures_signals_path_test() {
+        use futures_signals::signal::{Mutable, SignalExt};
+        use futures_signals::signal_vec::{MutableVec, SignalVecExt};
+        use futures_util::FutureExt;
+
+        #[component(
+            render_fn = _r,
+            futures_signals_path = crate::test::facade::futures_signals,
+            signal_or_value
+        )]
+        struct Tally {
+            #[signal]
+            #[default(1)]
+            step: i32,
+            #[signal_vec]
+            #[default(vec![])]
+            counts: i32,
+            #[binding]
+            #[default(0)]
+            total: i32,
+        }
+
+        fn _r(p: impl TallyPropsTrait) -> i32 {
+            let TallyProps {
+                step,
+                counts,
+                total,
+                ..
+            } = p.take();
+            let counts = counts
+                .to_signal_cloned()
+                .first()
+                .to_future()
+                .now_or_never()
+                .unwrap();
+            total
+                .set(step.first().to_future().now_or_never().unwrap() * counts.iter().sum::<i32>());
+            total.get()
+        }
+
+        let step = Mutable::new(3);
+        let counts = MutableVec::new_with_values(vec![1, 2]);
+        let total = Mutable::new(0);
+
+        assert_eq!(tally!({.counts(vec![4])}), 4);
+        assert_eq!(
+            tally!({.step(step.signal()).counts_signal_vec(counts.signal_vec()).total(total.clone())}),
+            9
+        );
+        assert_eq!(total.get(), 9);
+    }
+
+    #[test]
+    fn default_async_test() {
+        use futures_signals::signal::SignalExt;
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r)]
+        struct Fetched {
+            #[signal(fallback = 0)]
+            #[default_async(async { 5 })]
+            count: i32,
+            #[signal(fallback = 1)]
+            #[default_async(futures_util::future::pending())]
+            total: i32,
+        }
+
+        // the pending `total` default is dropped along with its signal, so `after` is read before it
+        fn _r(p: impl FetchedPropsTrait) -> (bool, i32, bool, i32) {
+            let props = p.take();
+            let (before, after) = (props.loading_signal(), props.loading_signal());
+            let FetchedProps { count, total, .. } = props;
+
+            (
+                before.first().to_future().now_or_never().unwrap(),
+                count.first().to_future().now_or_never().unwrap(),
+                after.first().to_future().now_or_never().unwrap(),
+                total.first().to_future().now_or_never().unwrap(),
+            )
+        }
+
+        assert_eq!(fetched!({}), (true, 5, true, 1));
+        assert_eq!(fetched!({.total(2)}), (true, 5, false, 2));
+        assert_eq!(fetched!({.count(3).total(2)}), (false, 3, false, 2));
+    }
+
+    #[test]
+    fn skip_test() {
+        use futures_signals::signal::SignalExt;
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r)]
+        struct Tracked {
+            #[skip]
+            #[default(vec![0])]
+            history: Vec<i32>,
+            #[skip]
+            #[signal]
+            #[default(7)]
+            revision: i32,
+            #[default(1)]
+            count: i32,
+        }
+
+        fn _r(p: impl TrackedPropsTrait) -> (Vec<i32>, i32) {
+            let TrackedProps {
+                mut history,
+                revision,
+                count,
+                ..
+            } = p.take();
+            history.push(count);
+            (
+                history,
+                revision.first().to_future().now_or_never().unwrap(),
+            )
+        }
+
+        assert_eq!(tracked!({.count(2)}), (vec![0, 2], 7));
+    }
+
+    #[test]
+    fn const_generics_test() {
+        #[component(render_fn = _r, delta)]
+        struct Grid<'a, const N: usize> {
+            #[default([0; N])]
+            cells: [i32; N],
+            #[signal]
+            #[default(["", ""])]
+            labels: [&'a str; 2],
+            #[required]
+            highlighted: [bool; N],
+        }
+
+        fn _r<'a, const N: usize>(p: impl GridPropsTrait<'a, N>) -> ([i32; N], [bool; N]) {
+            let GridProps {
+                cells, highlighted, ..
+            } = p.take();
+            (cells, highlighted)
+        }
+
+        assert_eq!(
+            grid!({.cells([1, 2, 3]).highlighted([true, false, true])}),
+            ([1, 2, 3], [true, false, true])
+        );
+        assert_eq!(grid!({.highlighted([false; 4])}), ([0; 4], [false; 4]));
+
+        let changes = GridProps::new()
+            .highlighted([false])
+            .changes(GridProps::new().cells([1]).highlighted([true]));
+        assert_eq!(changes.len(), 2);
+    }
+
+    #[test]
+    fn newtype_test() {
+        use futures_signals::signal::{Always, Mutable, SignalExt};
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r, newtype)]
+        struct Counter {
+            #[signal]
+            #[default(1)]
+            count: i32,
+        }
+
+        fn _r(p: impl CounterPropsTrait) -> CounterPropsSignal<impl Signal<Item = i32>> {
+            p.take().into_signal()
+        }
+
+        assert_eq!(
+            std::mem::size_of::<CounterPropsSignal<Always<i32>>>(),
+            std::mem::size_of::<Always<i32>>()
+        );
+
+        let value = Mutable::new(5);
+        let signal = counter!({.count_signal(value.signal())});
+        assert_eq!(signal.first().to_future().now_or_never().unwrap(), 5);
+
+        let defaulted = counter!({});
+        assert_eq!(
+            defaulted
+                .into_inner()
+                .first()
+                .to_future()
+                .now_or_never()
+                .unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn borrowed_fields_test() {
+        use futures_signals::signal::SignalExt;
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r)]
+        struct Viewer<'a> {
+            #[signal]
+            #[default("")]
+            title: &'a str,
+            data: &'a str,
+        }
+
+        // the destructured fields still borrow for `'a`, outliving the props
+        fn _r<'a>(p: impl ViewerPropsTrait<'a>) -> (&'a str, Option<&'a str>) {
+            let ViewerProps { title, data, .. } = p.take();
+            (title.first().to_future().now_or_never().unwrap(), data)
+        }
+
+        let text = "borrowed text".to_string();
+        let (title, data) = viewer!({.title(&text[..8]).data(&text[9..])});
+
+        assert_eq!(title, "borrowed");
+        assert_eq!(data, Some("text"));
+    }
+
+    #[test]
+    fn where_clause_test() {
+        #[component(render_fn = _r)]
+        struct Bounded<'a, 'b, T = i32>
+        where
+            'b: 'a,
+            T: Clone + std::fmt::Debug,
+        {
+            value: T,
+            #[default("")]
+            short: &'a str,
+            #[default("")]
+            long: &'b str,
+        }
+
+        fn _r<'a, 'b: 'a, P: BoundedPropsTrait<'a, 'b>>(p: P) -> String {
+            let BoundedProps { value, long, .. } = p.take();
+            let short: &'a str = long;
+
+            format!("{:?}{}", value.clone(), short)
+        }
+
+        assert_eq!(bounded!({.value("v").long("!")}), "Some(\"v\")!");
+
+        let text = "text".to_string();
+        assert!(BoundedProps::new()
+            .long(&text)
+            .eq_ignoring_signals(&BoundedProps::new().long(&text)));
+    }
+
+    #[test]
+    fn to_owned_test() {
+        use std::ffi::{OsStr, OsString};
+        use std::path::{Path, PathBuf};
+
+        #[component(render_fn = _r)]
+        struct Owning {
+            #[to_owned]
+            path: PathBuf,
+            #[to_owned]
+            name: String,
+            #[to_owned]
+            #[default(OsString::new())]
+            os: OsString,
+            #[to_owned]
+            #[default(vec![])]
+            bytes: Vec<u8>,
+        }
+
+        fn _r(p: impl OwningPropsTrait) -> (Option<PathBuf>, Option<String>, OsString, Vec<u8>) {
+            let OwningProps {
+                path,
+                name,
+                os,
+                bytes,
+                ..
+            } = p.take();
+            (path, name, os, bytes)
+        }
+
+        let name = String::from("owned");
+        assert_eq!(
+            owning!({.path("/etc").name(&name).os(OsStr::new("os")).bytes([1u8, 2])}),
+            (
+                Some(PathBuf::from("/etc")),
+                Some(name.clone()),
+                OsString::from("os"),
+                vec![1, 2]
+            )
+        );
+        assert_eq!(
+            owning!({.path(Path::new("/usr")).name("borrowed")}).0,
+            Some(PathBuf::from("/usr"))
+        );
+    }
+
+    #[test]
+    fn sync_test() {
+        use futures_signals::signal::Mutable;
+        use futures_signals::signal_vec::MutableVec;
+
+        #[component(render_fn = _r)]
+        struct Shared {
+            #[signal]
+            #[sync]
+            #[default(0)]
+            synced: i32,
+            #[signal]
+            #[send]
+            #[sync]
+            #[default(0)]
+            both: i32,
+            #[signal_vec]
+            #[sync]
+            #[default(vec![])]
+            items: i32,
+        }
+
+        fn _r(p: impl SharedPropsTrait) {
+            fn sync<T: Sync>(_: &T) {}
+            fn send_sync<T: Send + Sync>(_: &T) {}
+
+            let SharedProps {
+                synced,
+                both,
+                items,
+                ..
+            } = p.take();
+            sync(&synced);
+            send_sync(&both);
+            sync(&items);
+        }
+
+        let value = Mutable::new(1);
+        let items = MutableVec::<i32>::new();
+        shared!({
+            .synced_signal(value.signal())
+            .both_signal(value.signal())
+            .items_signal_vec(items.signal_vec())
+        });
+    }
+
+    #[test]
+    fn prop_mask_test() {
+        #[component(render_fn = _r, prop_mask, clone_signals)]
+        struct Masked {
+            #[signal]
+            #[default(0)]
+            value: i32,
+            #[try_into]
+            #[default(0)]
+            small: u8,
+            label: String,
+            #[required]
+            size: u32,
+        }
+
+        fn _r(p: impl MaskedPropsTrait) -> MaskedPropsMask {
+            p.take().set_mask()
+        }
+
+        assert!(MaskedProps::new().set_mask().is_empty());
+
+        let props = MaskedProps::new().value(1).size(2);
+        assert_eq!(
+            props.clone().set_mask(),
+            MaskedPropsMask::VALUE | MaskedPropsMask::SIZE
+        );
+        assert_eq!(
+            _r(props.small(3).unwrap()),
+            MaskedPropsMask::VALUE | MaskedPropsMask::SMALL | MaskedPropsMask::SIZE
+        );
+
+        let mask = masked!({.label("a".to_string()).size(1)});
+        assert!(mask.contains(MaskedPropsMask::LABEL));
+        assert!(!mask.contains(MaskedPropsMask::all()));
+        assert_eq!(mask.bits(), 0b1100);
+    }
+
+    #[test]
+    fn unbounded_generic_test() {
+        use futures_signals::signal::SignalExt;
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r)]
+        struct Unbounded<T = i32, U = i32> {
+            #[signal]
+            #[default(1)]
+            value: T,
+            #[default(2)]
+            plain: U,
+        }
+
+        fn _r<P: UnboundedPropsTrait<T = &'static str>>(p: P) -> &'static str {
+            let UnboundedProps { value, .. } = p.take();
+            value.first().to_future().now_or_never().unwrap()
+        }
+
+        assert_eq!(unbounded!({.value("unbounded").plain(())}), "unbounded");
+    }
+
+    #[test]
+    fn nested_generic_test() {
+        use futures_signals::signal::SignalExt;
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r)]
+        struct Nested<T: Clone = i32, U: Clone + 'static = u8> {
+            #[default(vec![])]
+            items: Vec<Option<T>>,
+            #[signal]
+            #[default(vec![])]
+            pairs: Vec<(U, [U; 2])>,
+        }
+
+        fn _r<P: NestedPropsTrait<T = &'static str, U = char>>(
+            p: P,
+        ) -> (Vec<Option<&'static str>>, [char; 2]) {
+            let NestedProps { items, pairs, .. } = p.take();
+            let pairs = pairs.first().to_future().now_or_never().unwrap();
+
+            (items, pairs[0].1)
+        }
+
+        assert_eq!(
+            nested!({.items(vec![Some("a"), None]).pairs(vec![('b', ['c', 'd'])])}),
+            (vec![Some("a"), None], ['c', 'd'])
+        );
+    }
+
+    #[test]
+    fn rename_test() {
+        use futures_signals::signal::{Mutable, SignalExt};
+        use futures_util::FutureExt;
+        use std::time::Duration;
+
+        #[component(render_fn = _r)]
+        struct Renamed {
+            #[rename = "ty"]
+            #[signal]
+            #[default(0)]
+            type_: i32,
+            #[rename = "items"]
+            #[signal_vec]
+            #[default(vec![])]
+            items_: i32,
+            #[rename = "wait"]
+            #[duration_ms]
+            #[default(Duration::ZERO)]
+            delay: Duration,
+            #[rename = "kind"]
+            #[required]
+            kind_: String,
+        }
+
+        fn _r(p: impl RenamedPropsTrait) -> (i32, Duration, String) {
+            let RenamedProps {
+                type_,
+                delay,
+                kind_,
+                ..
+            } = p.take();
+            (
+                type_.first().to_future().now_or_never().unwrap(),
+                delay,
+                kind_,
+            )
+        }
+
+        assert_eq!(
+            renamed!({.ty(1).wait_ms(5).kind("a".to_string())}),
+            (1, Duration::from_millis(5), "a".to_string())
+        );
+
+        let value = Mutable::new(2);
+        let props = RenamedProps::new()
+            .ty_signal(value.signal())
+            .items_signal_vec(futures_signals::signal_vec::always(vec![1]))
+            .wait(Duration::from_secs(1))
+            .kind("b".to_string());
+        assert_eq!(_r(props), (2, Duration::from_secs(1), "b".to_string()));
+
+        let props = RenamedProps::new()
+            .ty_from(value.signal(), |v| v * 2)
+            .items(vec![1])
+            .kind("c".to_string());
+        assert_eq!(_r(props).0, 4);
+    }
+
+    #[test]
+    fn dyn_trait_test() {
+        use futures_signals::signal::SignalExt;
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r, dyn_trait)]
+        struct Stored<T: ToString + Clone + 'static = i32, TSend: Send + 'static = i32> {
+            #[signal]
+            label: T,
+
+            #[signal]
+            #[default(1)]
+            send_me: TSend,
+
+            #[signal_vec]
+            #[default(vec![1, 2])]
+            items: i32,
+
+            #[default(3)]
+            plain: i32,
+        }
+
+        fn _r(p: impl StoredPropsTrait) -> (Option<String>, i32) {
+            let StoredProps { label, plain, .. } = p.take();
+            let label = label.map(|label| {
+                label
+                    .map(|v| v.to_string())
+                    .first()
+                    .to_future()
+                    .now_or_never()
+                    .unwrap()
+            });
+
+            (label, plain)
+        }
+
+        let stored: Vec<Box<dyn DynStoredPropsTrait>> = vec![
+            Box::new(StoredProps::new()),
+            Box::new(StoredProps::new().label(42).plain(4)),
+            Box::new(StoredProps::new().label_signal(always(1).map(|v| v + 1))),
+        ];
+
+        let rendered = stored.into_iter().map(_r).collect::<Vec<_>>();
+        assert_eq!(
+            rendered,
+            vec![
+                (None, 3),
+                (Some("42".to_string()), 4),
+                (Some("2".to_string()), 3)
+            ]
+        );
+
+        let boxed: Box<dyn DynStoredPropsTrait<String>> =
+            Box::new(StoredProps::new().label("hi".to_string()));
+        let StoredProps { send_me, .. } = boxed.take_boxed();
+
+        fn consume_send(_: impl futures_signals::signal::Signal + Send) {}
+        consume_send(send_me);
+    }
+
+    #[test]
+    fn boxed_test() {
+        use futures_signals::signal::{Mutable, SignalExt};
+        use futures_signals::signal_vec::SignalVecExt;
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r, boxed = true)]
+        struct Row<T: ToString + 'static = i32> {
+            #[signal]
+            #[default(0)]
+            count: i32,
+
+            #[signal_vec]
+            #[default(vec![])]
+            cells: String,
+
+            #[default("row".to_string())]
+            title: String,
+
+            tag: T,
+        }
+
+        fn _r(p: impl RowPropsTrait) -> (i32, usize, String, Option<String>) {
+            let RowProps {
+                count,
+                cells,
+                title,
+                tag,
+                ..
+            } = p.take();
+            let count = count.first().to_future().now_or_never().unwrap();
+            let cells = cells
+                .to_signal_cloned()
+                .first()
+                .to_future()
+                .now_or_never()
+                .unwrap();
+
+            (count, cells.len(), title, tag.map(|tag| tag.to_string()))
+        }
+
+        let shared = Mutable::new(5);
+        let rows: Vec<DynRowProps> = vec![
+            RowProps::new().into(),
+            RowProps::new().count(2).tag(7).into(),
+            RowProps::new()
+                .count_signal(shared.signal().map(|v| v * 2))
+                .cells(vec!["a".to_string()])
+                .title("t".to_string())
+                .into(),
+        ];
+
+        assert_eq!(
+            rows.into_iter().map(_r).collect::<Vec<_>>(),
+            vec![
+                (0, 0, "row".to_string(), None),
+                (2, 0, "row".to_string(), Some("7".to_string())),
+                (10, 1, "t".to_string(), None)
+            ]
+        );
+
+        let labeled: DynRowProps<&'static str> = RowProps::new().tag("label").into();
+        assert_eq!(_r(labeled).3, Some("label".to_string()));
+    }
+
+    mod scoped {
+        use futures_signals_component_macro::component;
+
+        #[component(render_fn = badge, vis = pub(super), builder)]
+        struct Badge {
+            #[default(0)]
+            count: u32,
+        }
+
+        pub(super) fn badge(props: impl BadgePropsTrait) -> u32 {
+            props.take().count
+        }
+    }
+
+    #[test]
+    fn vis_test() {
+        let props: scoped::BadgeProps = scoped::Badge::builder().count(3);
+
+        assert_eq!(scoped::badge(props), 3);
+        assert_eq!(scoped::badge(scoped::badge_props()), 0);
+    }
+
+    #[test]
+    fn clone_signals_test() {
+        use futures_signals::signal::{Mutable, SignalExt};
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r, clone_signals)]
+        struct Cloned<T: Clone = i32> {
+            #[signal]
+            label: String,
+
+            #[signal]
+            #[default(1)]
+            count: T,
+
+            #[default(2)]
+            plain: i32,
+        }
+
+        fn _r(p: impl ClonedPropsTrait) -> (Option<String>, i32) {
+            let ClonedProps {
+                label,
+                count: _,
+                plain,
+                ..
+            } = p.take();
+            let label = label.map(|label| {
+                label
+                    .signal_cloned()
+                    .first()
+                    .to_future()
+                    .now_or_never()
+                    .unwrap()
+            });
+
+            (label, plain)
+        }
+
+        let state = Mutable::new("first".to_string());
+        let props = ClonedProps::new()
+            .label_signal(state.signal_cloned())
+            .plain(3);
+
+        let copy = props.clone();
+        assert_eq!(_r(props), (Some("first".to_string()), 3));
+
+        state.set("second".to_string());
+        assert_eq!(_r(copy), (Some("second".to_string()), 3));
+    }
+
+    #[test]
+    fn emits_test() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        #[derive(Debug, PartialEq)]
+        enum Event {
+            Clicked(i32),
+        }
+
+        #[component(render_fn = _r)]
+        struct Emitter {
+            #[emits(Event)]
+            on_event: _,
+        }
+
+        fn _r(p: impl EmitterPropsTrait) -> bool {
+            let EmitterProps { on_event, .. } = p.take();
+
+            match &on_event {
+                Some(emit) => {
+                    emit(Event::Clicked(1));
+                    emit(Event::Clicked(2));
+                    true
+                }
+                None => false,
+            }
+        }
+
+        let received = Rc::new(RefCell::new(vec![]));
+        let sink = received.clone();
+
+        assert!(emitter!({ .on_event(move |event| sink.borrow_mut().push(event)) }));
+        assert_eq!(
+            *received.borrow(),
+            vec![Event::Clicked(1), Event::Clicked(2)]
+        );
+
+        assert!(!emitter!({}));
+        assert!(emitter_scaffold!());
+    }
+
+    #[test]
+    fn try_into_test() {
+        use futures_signals::signal::SignalExt;
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r)]
+        struct Port {
+            #[try_into]
+            port: u16,
+
+            #[try_into]
+            #[signal]
+            #[default(1)]
+            retries: u8,
+        }
+
+        fn _r(p: impl PortPropsTrait) -> (Option<u16>, u8) {
+            let PortProps { port, retries, .. } = p.take();
+
+            (port, retries.first().to_future().now_or_never().unwrap())
+        }
+
+        fn configure(
+            port: i64,
+            retries: i32,
+        ) -> Result<(Option<u16>, u8), std::num::TryFromIntError> {
+            Ok(port!({ .port(port)?.retries(retries)? }))
+        }
+
+        assert_eq!(configure(8080, 3), Ok((Some(8080), 3)));
+        assert!(configure(70000, 3).is_err());
+        assert!(configure(8080, -1).is_err());
+
+        assert_eq!(port_scaffold!(), (Some(0), 1));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn validate_test() {
+        fn in_range(v: &u8) -> Result<(), String> {
+            if (1..=10).contains(v) {
+                Ok(())
+            } else {
+                Err(format!("{v} is not in 1..=10"))
+            }
+        }
+
+        #[component(render_fn = _r)]
+        struct Rating {
+            #[validate(in_range)]
+            #[default(5)]
+            stars: u8,
+
+            #[validate(in_range)]
+            #[try_into]
+            #[default(1)]
+            min: u8,
+        }
+
+        fn _r(p: impl RatingPropsTrait) -> (u8, u8) {
+            let RatingProps { stars, min, .. } = p.take();
+            (stars, min)
+        }
+
+        assert_eq!(rating!({.stars(7)}), (7, 1));
+        assert_eq!(rating!({.min(2i32).unwrap()}), (5, 2));
+
+        let panic = std::panic::catch_unwind(|| rating!({.stars(11)})).unwrap_err();
+        assert_eq!(
+            panic.downcast_ref::<String>().map(String::as_str),
+            Some("invalid value passed to `stars()` of `Rating`: \"11 is not in 1..=10\"")
+        );
+        assert!(std::panic::catch_unwind(|| rating!({.min(0i32).unwrap()})).is_err());
+    }
+
+    // release builds skip the check, but still use the validator
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn validate_release_test() {
+        #[deny(dead_code)]
+        fn in_range(v: &u8) -> Result<(), String> {
+            if (1..=10).contains(v) {
+                Ok(())
+            } else {
+                Err(format!("{v} is not in 1..=10"))
+            }
+        }
+
+        #[component(render_fn = _r)]
+        struct Rating {
+            #[validate(in_range)]
+            #[default(5)]
+            stars: u8,
+        }
+
+        fn _r(p: impl RatingPropsTrait) -> u8 {
+            p.take().stars
+        }
+
+        assert_eq!(rating!({.stars(11)}), 11);
+    }
+
+    #[test]
+    fn with_helpers_test() {
+        use futures_signals::signal::SignalExt;
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r, with_helpers)]
+        struct Helpers<T: ToString = i32> {
+            #[signal]
+            label: T,
+
+            count: i32,
+
+            #[default(1)]
+            with_default: i32,
+        }
+
+        fn _r(p: impl HelpersPropsTrait) -> (Option<String>, Option<i32>) {
+            let mut props = p.take();
+
+            let label = props.with_label(|label| {
+                label
+                    .map(|v| v.to_string())
+                    .first()
+                    .to_future()
+                    .now_or_never()
+                    .unwrap()
+            });
+            let count = props.with_count(|count| count * 2);
+
+            // the value has been taken out by the first call
+            assert!(props.with_count(|count| count).is_none());
+
+            (label, count)
+        }
+
+        assert_eq!(helpers!({}), (None, None));
+        assert_eq!(
+            helpers!({ .label("hi").count(2) }),
+            (Some("hi".to_string()), Some(4))
+        );
+    }
+
+    #[test]
+    fn generic_order_test() {
+        use futures_signals::signal::Signal;
+        use futures_signals::signal_vec::SignalVec;
+
+        #[component(render_fn = _r)]
+        struct Ordered<T: ToString = i32, U: Clone = i32> {
+            #[default(1)]
+            plain: i32,
+
+            #[signal]
+            first: T,
+
+            #[signal_vec]
+            #[default(vec![1])]
+            second: i32,
+
+            generic: U,
+        }
+
+        fn _r(_p: impl OrderedPropsTrait) {}
+
+        // Hand written impls rely on the documented generic order
+        impl<
+                T: ToString,
+                TfirstSignal: Signal<Item = T>,
+                TsecondSignal: SignalVec<Item = i32>,
+                U: Clone,
+            > OrderedProps<T, TfirstSignal, TsecondSignal, U>
+        {
+            fn plain_doubled(&self) -> i32 {
+                self.plain * 2
+            }
+        }
+
+        assert_eq!(OrderedProps::new().plain(2).generic("u").plain_doubled(), 4);
+    }
+
+    #[test]
+    fn scoped_signal_test() {
+        use futures_signals::signal::SignalExt;
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r, with_helpers, clone_signals)]
+        struct Scoped<'a, T: ToString + 'a = i32> {
+            #[signal]
+            label: &'a str,
+
+            #[signal]
+            #[default(1)]
+            count: T,
+
+            prefix: &'a str,
+        }
+
+        fn _r<'a>(p: impl ScopedPropsTrait<'a>) -> String {
+            let mut props = p.take();
+            let prefix = props.with_prefix(|prefix| prefix).unwrap_or_default();
+            let label = props
+                .with_label(|label| label.signal().first().to_future().now_or_never().unwrap())
+                .unwrap_or_default();
+
+            format!("{prefix}{label}")
+        }
+
+        let text = "scoped text".to_string();
+        let props = ScopedProps::new()
+            .prefix(&text[..1])
+            .label_signal(always(6).map(|end| &text[..end]))
+            .count("generic");
+
+        assert_eq!(_r(props.clone()), "sscoped");
+        assert_eq!(_r(props), "sscoped");
+    }
+
+    #[test]
+    fn snapshot_test() {
+        use futures_signals::signal::Mutable;
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r, snapshot)]
+        struct Snapshot<T: ToString = i32> {
+            #[signal]
+            label: String,
+
+            #[signal]
+            #[default(1)]
+            count: T,
+
+            #[signal_vec]
+            #[default(vec![1, 2])]
+            items: i32,
+
+            #[default(true)]
+            flag: bool,
+        }
+
+        fn _r(_p: impl SnapshotPropsTrait) {}
+
+        let state = Mutable::new("current".to_string());
+        let SnapshotPropsSnapshot {
+            label,
+            count,
+            items,
+            flag,
+            ..
+        } = SnapshotProps::new()
+            .label_signal(state.signal_cloned())
+            .count("generic")
+            .snapshot()
+            .now_or_never()
+            .unwrap();
+
+        assert_eq!(label, Some("current".to_string()));
+        assert_eq!(count, "generic");
+        assert_eq!(items, vec![1, 2]);
+        assert!(flag);
+
+        let defaults = SnapshotProps::new().snapshot().now_or_never().unwrap();
+        assert_eq!(defaults.label, None);
+        assert_eq!(defaults.count, 1);
+    }
+
+    #[test]
+    fn impl_trait_test() {
+        trait Element {
+            fn into_element(self) -> String;
+        }
+
+        trait Render {
+            fn render(self) -> String;
+        }
+
+        #[component(
+            render_fn = label,
+            impl_trait(Element, fn into_element(self) -> String),
+            impl_trait(Render, fn render(self) -> String),
+        )]
+        struct Label<T: ToString = &'static str> {
+            #[default("label")]
+            text: T,
+        }
+
+        fn label(props: impl LabelPropsTrait) -> String {
+            props.take().text.to_string()
+        }
+
+        assert_eq!(LabelProps::new().into_element(), "label");
+        assert_eq!(LabelProps::new().text(42).into_element(), "42");
+        assert_eq!(LabelProps::new().render(), "label");
+    }
+
+    #[test]
+    fn signal_fallback_test() {
+        use futures_signals::signal::{Mutable, SignalExt};
+        use futures_util::FutureExt;
+
+        #[component(render_fn = user)]
+        struct User {
+            #[signal(fallback = "loading".to_string())]
+            name: String,
+
+            #[signal(fallback = 0)]
+            #[default(7)]
+            age: i32,
+        }
+
+        fn user(
+            props: impl UserPropsTrait,
+        ) -> (impl Signal<Item = String>, impl Signal<Item = i32>) {
+            let UserProps { name, age, .. } = props.take();
+
+            (name, age)
+        }
+
+        let (name, age) = user(UserProps::new());
+        assert_eq!(
+            name.first().to_future().now_or_never(),
+            Some("loading".to_string())
+        );
+        assert_eq!(age.first().to_future().now_or_never(), Some(7));
+
+        let loaded = Mutable::new(None);
+        let (name, age) = user(UserProps::new().name_signal(loaded.signal_cloned()).age(30));
+        let mut name = Box::pin(name.to_stream());
+
+        assert_eq!(
+            name.next().now_or_never(),
+            Some(Some("loading".to_string()))
+        );
+        loaded.set(Some("alice".to_string()));
+        assert_eq!(name.next().now_or_never(), Some(Some("alice".to_string())));
+        assert_eq!(age.first().to_future().now_or_never(), Some(30));
+    }
+
+    #[test]
+    fn count_set_fields_test() {
+        #[component(render_fn = _r)]
+        struct Counted<T: ToString = i32> {
+            #[signal]
+            label: T,
+            #[signal_vec]
+            items: i32,
+            name: String,
+            #[default(true)]
+            flag: bool,
+        }
+
+        fn _r(_p: impl CountedPropsTrait) {}
+
+        assert_eq!(CountedProps::new().count_set_fields(), 0);
+        assert_eq!(CountedProps::new().flag(false).count_set_fields(), 0);
+        assert_eq!(
+            CountedProps::new()
+                .label("generic")
+                .items(vec![1])
+                .count_set_fields(),
+            2
+        );
+        assert_eq!(
+            CountedProps::new()
+                .label_signal(always(1))
+                .items(vec![])
+                .name("n".to_string())
+                .count_set_fields(),
+            3
+        );
+    }
+
+    // the injected `apply` prop is not a field of the component, so setting it is not counted
+    #[cfg(feature = "dominator")]
+    #[test]
+    fn count_set_fields_apply_test() {
+        use dominator::DomBuilder;
+        use web_sys::HtmlElement;
+
+        #[component(render_fn = _r)]
+        struct Applied {
+            name: String,
+        }
+
+        fn _r(_p: impl AppliedPropsTrait) {}
+
+        assert_eq!(
+            AppliedProps::new()
+                .apply(|b: DomBuilder<HtmlElement>| b)
+                .count_set_fields(),
+            0
+        );
+        assert_eq!(
+            AppliedProps::new()
+                .apply(|b: DomBuilder<HtmlElement>| b)
+                .name("n".to_string())
+                .count_set_fields(),
+            1
+        );
+    }
+
+    #[test]
+    fn render_override_test() {
+        #[component(render_fn = badge)]
+        struct Badge<T: ToString = i32> {
+            #[default(1)]
+            count: T,
+        }
+
+        fn badge(p: impl BadgePropsTrait) -> String {
+            p.take().count.to_string()
+        }
+
+        fn badge_with_brackets(p: impl BadgePropsTrait) -> String {
+            format!("[{}]", p.take().count.to_string())
+        }
+
+        assert_eq!(badge!({.count(2)}), "2");
+        assert_eq!(badge!(render = badge_with_brackets, {.count(2)}), "[2]");
+        assert_eq!(badge!(render = badge_with_brackets, {}), "[1]");
+        assert_eq!(
+            badge!(render = |p: BadgeProps<&str>| p.count.len(), {.count("four")}),
+            4
+        );
+    }
+
+    #[test]
+    fn inferred_type_test() {
+        use futures_signals::signal::SignalExt;
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r)]
+        struct Inferred {
+            #[signal]
+            #[default(-1i64)]
+            offset: _,
+            #[default(0.5f32)]
+            ratio: _,
+            #[default('x')]
+            marker: _,
+            #[default("name")]
+            name: _,
+        }
+
+        fn _r(p: impl InferredPropsTrait) -> (i64, f32, char, &'static str) {
+            let InferredProps {
+                offset,
+                ratio,
+                marker,
+                name,
+                ..
+            } = p.take();
+
+            (
+                offset.first().to_future().now_or_never().unwrap(),
+                ratio,
+                marker,
+                name,
+            )
+        }
+
+        assert_eq!(_r(InferredProps::new()), (-1, 0.5, 'x', "name"));
+        assert_eq!(
+            _r(InferredProps::new().offset(2).ratio(1.0).name("other")),
+            (2, 1.0, 'x', "other")
+        );
+
+        let t = trybuild::TestCases::new();
+
+        t.compile_fail("tests/build_fail_checks/uninferable_type.rs");
+    }
+
+    #[test]
+    fn prop_names_test() {
+        #[component(render_fn = _r)]
+        struct Named<T: ToString = i32> {
+            #[signal]
+            label: String,
+            #[signal_vec]
+            items: i32,
+            generic: T,
+        }
+
+        fn _r(_p: impl NamedPropsTrait) {}
+
+        const NAMES: &[&str] = &named_prop_names!();
+
+        macro_rules! prop_checker {
+            ($($name:ident),*) => {
+                |name: &str| [$(stringify!($name)),*].contains(&name)
+            };
+        }
+
+        let is_prop = named_prop_names!(prop_checker);
+
+        assert_eq!(NAMES[..3], ["label", "items", "generic"]);
+        assert!(is_prop("items"));
+        assert!(!is_prop("missing"));
+    }
+
+    #[test]
+    fn dynamic_test() {
+        use std::collections::HashMap;
+
+        #[component(render_fn = _r, dynamic)]
+        struct Configured<T: ToString = i32> {
+            #[default(1)]
+            count: u32,
+            retries: u32,
+            title: String,
+            tags: Vec<String>,
+            #[cow]
+            #[default("cow")]
+            text: String,
+            #[signal]
+            label: String,
+            generic: T,
+            #[emits(u32)]
+            on_change: _,
+        }
+
+        fn _r(_p: impl ConfiguredPropsTrait) {}
+
+        let props = ConfiguredProps::from_dynamic(HashMap::from([
+            ("count".to_string(), ConfiguredDynValue::U32(2)),
+            ("retries".to_string(), 3u32.into()),
+            (
+                "tags".to_string(),
+                ConfiguredDynValue::VecString(vec!["a".to_string()]),
+            ),
+            (
+                "text".to_string(),
+                ConfiguredDynValue::CowStr("dynamic".into()),
+            ),
+        ]))
+        .unwrap();
+
+        assert_eq!(props.count, 2);
+        assert_eq!(props.retries, Some(3));
+        assert_eq!(props.title, None);
+        assert_eq!(props.tags, Some(vec!["a".to_string()]));
+        assert_eq!(props.text, "dynamic");
+
+        let error = |key: &str, value: ConfiguredDynValue| {
+            ConfiguredProps::from_dynamic(HashMap::from([(key.to_string(), value)])).err()
+        };
+
+        assert_eq!(
+            error("missing", 1u32.into()),
+            Some(ConfiguredDynError::UnknownProp("missing".to_string()))
+        );
+        assert_eq!(
+            error("title", 1u32.into()),
+            Some(ConfiguredDynError::TypeMismatch("title".to_string()))
+        );
+        assert_eq!(
+            error("label", "label".to_string().into()),
+            Some(ConfiguredDynError::UnsupportedProp("label".to_string()))
+        );
+        assert_eq!(
+            error("generic", 1u32.into()),
+            Some(ConfiguredDynError::UnsupportedProp("generic".to_string()))
+        );
+        assert_eq!(
+            ConfiguredDynError::TypeMismatch("title".to_string()).to_string(),
+            "mismatched type for prop `title`"
+        );
+    }
+
+    #[test]
+    fn macro_module_test() {
+        #[component(render_fn = tile, macro_module = tiles)]
+        struct Tile {
+            #[default(1)]
+            size: i32,
+            name: String,
+        }
+
+        fn tile(p: impl TilePropsTrait) -> (i32, Option<String>) {
+            let TileProps { size, name, .. } = p.take();
+
+            (size, name)
+        }
+
+        // the macros are not in scope outside their module
+        macro_rules! tile {
+            ($($t:tt)*) => {
+                "not the component macro"
+            };
+        }
+
+        assert_eq!(tile!(), "not the component macro");
+        assert_eq!(tiles::tile!({.size(2)}), (2, None));
+        assert_eq!(tiles::tile_scaffold!(), (1, Some(String::new())));
+        assert_eq!(tiles::tile_prop_names!()[..2], ["size", "name"]);
+    }
+
+    #[test]
+    fn sealed_test() {
+        #[component(render_fn = _r, sealed)]
+        struct Sealed<'a, T: ToString = i32> {
+            #[signal]
+            label: T,
+            name: &'a str,
+        }
+
+        fn _r<'a>(p: impl SealedPropsTrait<'a>) -> Option<&'a str> {
+            p.take().name
+        }
+
+        assert_eq!(
+            _r(SealedProps::new().label(1).name("sealed")),
+            Some("sealed")
+        );
+
+        // the props trait has an associated type for the `apply` prop with the `dominator` feature, which the imposter
+        // would have to implement as well
+        #[cfg(not(feature = "dominator"))]
+        {
+            let t = trybuild::TestCases::new();
+
+            t.compile_fail("tests/build_fail_checks/sealed_impl.rs");
+        }
+    }
+
+    #[test]
+    fn signal_or_value_test() {
+        use futures_signals::signal::{Mutable, SignalExt};
+        use futures_util::FutureExt;
+
+        #[component(render_fn = _r, signal_or_value)]
+        struct Flexible<T: ToString = i32> {
+            #[signal]
+            #[default(1)]
+            count: i32,
+            #[signal]
+            #[send]
+            label: String,
+            #[signal]
+            #[default(2)]
+            generic: T,
+        }
+
+        fn _r(p: impl FlexiblePropsTrait) -> (i32, Option<String>, String) {
+            let FlexibleProps {
+                count,
+                label,
+                generic,
+                ..
+            } = p.take();
+
+            (
+                count.first().to_future().now_or_never().unwrap(),
+                label.map(|label| label.first().to_future().now_or_never().unwrap()),
+                generic
+                    .first()
+                    .to_future()
+                    .now_or_never()
+                    .unwrap()
+                    .to_string(),
+            )
+        }
+
+        let state = Mutable::new(5);
+        let label = Mutable::new("signal".to_string());
+
+        assert_eq!(_r(FlexibleProps::new()), (1, None, "2".to_string()));
+        assert_eq!(
+            _r(FlexibleProps::new()
+                .count(3)
+                .label("value".to_string())
+                .generic("g")),
+            (3, Some("value".to_string()), "g".to_string())
+        );
+        assert_eq!(
+            _r(FlexibleProps::new()
+                .count(state.signal())
+                .label(label.signal_cloned())),
+            (5, Some("signal".to_string()), "2".to_string())
+        );
+        assert_eq!(flexible!({.count(state.signal_ref(|v| v * 2))}).0, 10);
+    }
+
+    #[test]
+    fn fn_local_cmp_test() {
+        #[component(render_fn = _r)]
+        struct Local {
+            #[default(1)]
+            foo: i32,
+        }
+
+        fn _r(p: impl LocalPropsTrait) -> i32 {
+            p.take().foo
+        }
+
+        assert_eq!(local!({ .foo(2) }), 2);
+        assert_eq!(local_scaffold!(), 1);
+    }
+
+    // A component with the same name as the one in `fn_local_cmp_test`, which must not clash with it
+    #[test]
+    fn fn_local_cmp_same_name_test() {
+        #[component(render_fn = _r)]
+        struct Local {
+            #[signal]
+            #[default("hi".to_string())]
+            foo: String,
+        }
+
+        fn _r(p: impl LocalPropsTrait) -> String {
+            use futures_signals::signal::SignalExt;
+            use futures_util::FutureExt;
+
+            p.take().foo.first().to_future().now_or_never().unwrap()
+        }
+
+        assert_eq!(local!({}), "hi");
+    }
+
+    #[test]
+    fn verify_unused_generic_is_rejected() {
+        let t = trybuild::TestCases::new();
+
+        t.compile_fail("tests/build_fail_checks/unused_generic.rs");
+    }
+
+    #[test]
+    fn verify_unused_render_result_warns() {
+        let t = trybuild::TestCases::new();
+
+        t.compile_fail("tests/build_fail_checks/unused_render.rs");
+    }
+
+    #[test]
+    fn verify_missing_send_bound_is_rejected() {
+        let t = trybuild::TestCases::new();
+
+        t.compile_fail("tests/build_fail_checks/missing_send_bound.rs");
+    }
+
+    #[test]
+    fn verify_invalid_rename_is_rejected() {
+        let t = trybuild::TestCases::new();
+
+        t.compile_fail("tests/build_fail_checks/invalid_rename.rs");
+    }
+
+    // the expected warnings name the props type, which has the param of the `apply` prop with the `dominator` feature
+    #[cfg(not(feature = "dominator"))]
+    #[test]
+    fn verify_deprecated_setters_warn() {
+        let t = trybuild::TestCases::new();
+
+        t.compile_fail("tests/build_fail_checks/deprecated_setter.rs");
+    }
+
+    #[test]
+    fn verify_invalid_components_are_rejected() {
+        let t = trybuild::TestCases::new();
+
+        t.compile_fail("tests/build_fail_checks/invalid_component.rs");
+    }
+
+    #[test]
+    fn verify_missing_default_bound_is_reported() {
+        let t = trybuild::TestCases::new();
+
+        t.compile_fail("tests/build_fail_checks/missing_default_bound.rs");
+    }
+
+    #[test]
+    fn verify_default_signal_with_default_is_rejected() {
+        let t = trybuild::TestCases::new();
+
+        t.compile_fail("tests/build_fail_checks/default_signal_with_default.rs");
+    }
+
+    #[test]
+    fn verify_invalid_signal_options_are_rejected() {
+        let t = trybuild::TestCases::new();
+
+        t.compile_fail("tests/build_fail_checks/invalid_signal_options.rs");
+    }
+
+    #[test]
+    fn verify_invalid_required_fields_are_rejected() {
+        let t = trybuild::TestCases::new();
+
+        t.compile_fail("tests/build_fail_checks/invalid_required.rs");
+    }
+
+    #[test]
+    fn verify_conflicting_field_attributes_are_rejected() {
+        let t = trybuild::TestCases::new();
+
+        t.compile_fail("tests/build_fail_checks/conflicting_field_attributes.rs");
+    }
+
+    #[test]
+    fn verify_unknown_override_is_rejected() {
+        let t = trybuild::TestCases::new();
+
+        t.compile_fail("tests/build_fail_checks/unknown_override.rs");
+    }
 }